@@ -0,0 +1,375 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::PathBuf,
+    time::Duration,
+};
+
+use clap::{Args, ValueHint};
+use error_stack::{Report, ResultExt};
+use futures::executor;
+use plan::{Action, Change, Status, Step};
+use rdkafka::{
+    admin::{AdminClient, AdminOptions, NewPartitions, NewTopic, TopicReplication, TopicResult},
+    client::DefaultClientContext,
+    config::RDKafkaLogLevel,
+};
+use tabled::{
+    settings::{object::Rows, Color, Panel, Style},
+    Table,
+};
+
+use crate::{
+    config::{clusters::NamedCluster, Context},
+    error::cli::apply::ApplyError,
+    io::{
+        admin::{
+            acl::{create_topic_acls, delete_topic_acls, describe_topic_acls, TopicAcl},
+            incremental_alter_configs, AlterConfigOp, ConfigAlteration, ConfigResource,
+        },
+        output::Output,
+        spec::ClusterSpec,
+    },
+};
+
+use super::{topic::TopicFilter, util::get_user_input_confirmation, GlobalArgs, Invoke};
+
+mod plan;
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Args)]
+pub(super) struct ApplyCommand {
+    #[arg(
+        index = 1,
+        value_hint = ValueHint::FilePath,
+        help = "YAML, TOML or JSON spec declaring the topics."
+    )]
+    file: PathBuf,
+    #[arg(short, long, help = "Target cluster to apply the spec to.")]
+    cluster: Option<String>,
+    #[arg(long, help = "Only show the changes needed to match the spec.")]
+    plan: bool,
+    #[arg(long, help = "Delete topics and ACLs missing from the spec.")]
+    allow_delete: bool,
+    #[arg(short, long, help = "Skip the confirmation prompt.")]
+    yes: bool,
+    #[command(flatten)]
+    filter: TopicFilter,
+}
+
+impl Invoke for ApplyCommand {
+    type E = ApplyError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), ApplyError> {
+        let Self {
+            file,
+            cluster,
+            plan,
+            allow_delete,
+            yes,
+            filter,
+        } = self;
+
+        let is_match = filter.matcher().change_context(ApplyError::Filter)?;
+
+        let mut target = ClusterSpec::read(&file)
+            .change_context(ApplyError::ReadSpec(file.display().to_string()))?;
+
+        target.retain(&is_match);
+
+        let NamedCluster(cluster_name, cluster_config) = ctx
+            .clusters
+            .cluster_config_or_default_or_select(cluster.as_deref())
+            .change_context(ApplyError::FetchCluster)?;
+
+        let admin_client = cluster_config
+            .client_config()
+            .set_log_level(RDKafkaLogLevel::Emerg)
+            .create::<AdminClient<DefaultClientContext>>()
+            .change_context(ApplyError::CreateClient)?;
+
+        let current = ClusterSpec::from_cluster(admin_client.inner(), &is_match, REQUEST_TIMEOUT)
+            .change_context(ApplyError::ReadCluster(cluster_name.clone()))?;
+
+        // Describing ACLs fails without an authorizer, so only do it when the
+        // spec manages some.
+        let mut acls = BTreeMap::<String, BTreeSet<TopicAcl>>::new();
+
+        if target.topics.values().any(|t| t.acls.is_some()) {
+            for acl in describe_topic_acls(admin_client.inner(), REQUEST_TIMEOUT)
+                .change_context(ApplyError::DescribeAcls)?
+            {
+                acls.entry(acl.topic.clone()).or_default().insert(acl);
+            }
+        }
+
+        let mut steps = plan::plan(&current, &target, &acls, allow_delete);
+
+        if steps.is_empty() {
+            println!("Cluster '{}' already matches the spec.", cluster_name);
+            return Ok(());
+        }
+
+        let planned = steps.iter().filter(|s| s.status == Status::Planned).count();
+
+        // Blocked steps are only reported, the rest of the plan still applies.
+        if plan || planned == 0 {
+            println!("{}", display_steps(&steps, &cluster_name, global_args)?);
+
+            return Ok(());
+        }
+
+        if !yes {
+            if let Output::Human = global_args.out {
+                println!("{}", display_steps(&steps, &cluster_name, global_args)?);
+            }
+
+            if !get_user_input_confirmation(&format!(
+                "Are you sure you want to apply {} changes to '{}'?",
+                planned, cluster_name
+            ))
+            .change_context(ApplyError::InputError("confirmation"))?
+            {
+                return Ok(());
+            }
+        }
+
+        execute(&admin_client, &mut steps)?;
+
+        println!("{}", display_steps(&steps, &cluster_name, global_args)?);
+
+        let failed = steps.iter().filter(|s| s.status == Status::Failed).count();
+
+        if failed > 0 {
+            Err(Report::new(ApplyError::ChangesFailed(failed)))?
+        }
+
+        Ok(())
+    }
+}
+
+fn display_steps(
+    steps: &[Step],
+    cluster_name: &str,
+    global_args: &GlobalArgs,
+) -> error_stack::Result<String, ApplyError> {
+    Ok(match global_args.out {
+        Output::Human => {
+            let count = |action: Action| steps.iter().filter(|s| s.action == action).count();
+
+            let mut table = Table::new(steps);
+
+            table
+                .with(Style::modern_rounded())
+                .with(Panel::footer(format!(
+                    "Creates: {}, Updates: {}, Deletes: {}, Cluster: {}",
+                    count(Action::Create),
+                    count(Action::Update),
+                    count(Action::Delete),
+                    cluster_name
+                )));
+
+            // Row 0 is the header.
+            for (index, _) in steps
+                .iter()
+                .enumerate()
+                .filter(|(_, s)| matches!(s.status, Status::Blocked | Status::Failed))
+            {
+                table.modify(Rows::single(index + 1), Color::FG_RED);
+            }
+
+            table.to_string()
+        }
+        out => out
+            .output_string(&steps)
+            .change_context(ApplyError::Output)?,
+    })
+}
+
+fn topic_errors(results: Vec<TopicResult>) -> BTreeMap<String, Option<String>> {
+    results
+        .into_iter()
+        .map(|result| match result {
+            Ok(topic) => (topic, None),
+            Err((topic, code)) => (topic, Some(code.to_string())),
+        })
+        .collect()
+}
+
+/// Topics are created first so their configs and ACLs can follow, and
+/// deleted last. Blocked steps are skipped.
+fn execute(
+    admin_client: &AdminClient<DefaultClientContext>,
+    steps: &mut [Step],
+) -> error_stack::Result<(), ApplyError> {
+    let options = AdminOptions::new().request_timeout(Some(REQUEST_TIMEOUT));
+    let planned = |s: &&Step| s.status == Status::Planned;
+
+    let new_topics = steps
+        .iter()
+        .filter(planned)
+        .filter_map(|s| match (&s.action, &s.change) {
+            (Action::Create, Change::Topic(spec)) => Some(spec.configs.iter().fold(
+                NewTopic::new(
+                    &s.topic,
+                    spec.partitions,
+                    TopicReplication::Fixed(spec.replication_factor),
+                ),
+                |topic, (name, value)| topic.set(name, value),
+            )),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    if !new_topics.is_empty() {
+        let errors = topic_errors(
+            executor::block_on(admin_client.create_topics(&new_topics, &options))
+                .change_context(ApplyError::Admin("CreateTopics"))?,
+        );
+
+        settle(steps, errors, |s| match (&s.action, &s.change) {
+            (Action::Create, Change::Topic(_)) => Some(s.topic.clone()),
+            _ => None,
+        });
+    }
+
+    let new_partitions = steps
+        .iter()
+        .filter(planned)
+        .filter_map(|s| match &s.change {
+            Change::Partitions { to, .. } => Some(NewPartitions::new(&s.topic, *to as usize)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    if !new_partitions.is_empty() {
+        let errors = topic_errors(
+            executor::block_on(admin_client.create_partitions(&new_partitions, &options))
+                .change_context(ApplyError::Admin("CreatePartitions"))?,
+        );
+
+        settle(steps, errors, |s| match &s.change {
+            Change::Partitions { .. } => Some(s.topic.clone()),
+            _ => None,
+        });
+    }
+
+    let mut alterations = BTreeMap::<&str, Vec<_>>::new();
+
+    for step in steps.iter().filter(planned) {
+        if let Change::Config { name, to, .. } = &step.change {
+            alterations.entry(&step.topic).or_default().push((
+                name.clone(),
+                match to {
+                    Some(value) => AlterConfigOp::Set(value.clone()),
+                    None => AlterConfigOp::Delete,
+                },
+            ));
+        }
+    }
+
+    if !alterations.is_empty() {
+        let alterations = alterations
+            .into_iter()
+            .map(|(topic, entries)| ConfigAlteration {
+                resource: ConfigResource::Topic(topic.to_owned()),
+                entries,
+            })
+            .collect::<Vec<_>>();
+
+        let errors = incremental_alter_configs(admin_client.inner(), &alterations, REQUEST_TIMEOUT)
+            .change_context(ApplyError::Admin("IncrementalAlterConfigs"))?
+            .into_iter()
+            .filter_map(|result| match result.resource {
+                ConfigResource::Topic(topic) => Some((topic, result.error)),
+                _ => None,
+            })
+            .collect();
+
+        settle(steps, errors, |s| match &s.change {
+            Change::Config { .. } => Some(s.topic.clone()),
+            _ => None,
+        });
+    }
+
+    for action in [Action::Create, Action::Delete] {
+        let acls = steps
+            .iter()
+            .filter(planned)
+            .filter(|s| s.action == action)
+            .filter_map(|s| match &s.change {
+                Change::Acl(acl) => Some(acl.clone()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        if acls.is_empty() {
+            continue;
+        }
+
+        let results = match action {
+            Action::Create => create_topic_acls(admin_client.inner(), &acls, REQUEST_TIMEOUT)
+                .change_context(ApplyError::Admin("CreateAcls"))?,
+            _ => delete_topic_acls(admin_client.inner(), &acls, REQUEST_TIMEOUT)
+                .change_context(ApplyError::Admin("DeleteAcls"))?,
+        };
+
+        let errors = results
+            .into_iter()
+            .map(|result| (result.acl, result.error))
+            .collect();
+
+        settle(steps, errors, |s| match &s.change {
+            Change::Acl(acl) if s.action == action => Some(acl.clone()),
+            _ => None,
+        });
+    }
+
+    let deleted_topics = steps
+        .iter()
+        .filter(planned)
+        .filter_map(|s| match (&s.action, &s.change) {
+            (Action::Delete, Change::Topic(_)) => Some(s.topic.as_str()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    if !deleted_topics.is_empty() {
+        let errors = topic_errors(
+            executor::block_on(admin_client.delete_topics(&deleted_topics, &options))
+                .change_context(ApplyError::Admin("DeleteTopics"))?,
+        );
+
+        settle(steps, errors, |s| match (&s.action, &s.change) {
+            (Action::Delete, Change::Topic(_)) => Some(s.topic.clone()),
+            _ => None,
+        });
+    }
+
+    Ok(())
+}
+
+/// Records the outcome of the planned steps `key` picks out. Steps without a
+/// result are treated as failed, as the cluster never answered for them.
+fn settle<K>(
+    steps: &mut [Step],
+    errors: BTreeMap<K, Option<String>>,
+    key: impl Fn(&Step) -> Option<K>,
+) where
+    K: Ord,
+{
+    for step in steps.iter_mut().filter(|s| s.status == Status::Planned) {
+        if let Some(key) = key(step) {
+            step.settle(
+                errors
+                    .get(&key)
+                    .cloned()
+                    .unwrap_or(Some("no result returned".to_owned())),
+            );
+        }
+    }
+}
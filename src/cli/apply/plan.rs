@@ -0,0 +1,480 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Display,
+};
+
+use regex::Regex;
+use serde::Serialize;
+use tabled::Tabled;
+
+use crate::{
+    cli::topic::INTERNAL_TOPIC_REGEX,
+    io::{
+        admin::acl::TopicAcl,
+        spec::{ClusterSpec, SpecField, TopicChange, TopicSpec},
+    },
+};
+
+const DEFAULT_VALUE: &str = "<default>";
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(super) enum Action {
+    Create,
+    Update,
+    Delete,
+}
+
+impl Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Create => f.write_str("create"),
+            Self::Update => f.write_str("update"),
+            Self::Delete => f.write_str("delete"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "snake_case", tag = "kind")]
+pub(super) enum Change {
+    Topic(TopicSpec),
+    Partitions {
+        from: i32,
+        to: i32,
+    },
+    ReplicationFactor {
+        from: i32,
+        to: i32,
+    },
+    Config {
+        name: String,
+        from: Option<String>,
+        to: Option<String>,
+    },
+    Acl(TopicAcl),
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Topic(spec) => write!(
+                f,
+                "topic (partitions: {}, replication_factor: {}, configs: {})",
+                spec.partitions,
+                spec.replication_factor,
+                spec.configs.len()
+            ),
+            Self::Partitions { from, to } => write!(f, "partitions: {} -> {}", from, to),
+            Self::ReplicationFactor { from, to } => {
+                write!(f, "replication_factor: {} -> {}", from, to)
+            }
+            Self::Config { name, from, to } => write!(
+                f,
+                "configs.{}: {} -> {}",
+                name,
+                from.as_deref().unwrap_or(DEFAULT_VALUE),
+                to.as_deref().unwrap_or(DEFAULT_VALUE)
+            ),
+            Self::Acl(acl) => write!(f, "acl: {}", acl),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(super) enum Status {
+    Planned,
+    Blocked,
+    Applied,
+    Failed,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Planned => f.write_str("planned"),
+            Self::Blocked => f.write_str("blocked"),
+            Self::Applied => f.write_str("applied"),
+            Self::Failed => f.write_str("failed"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Tabled)]
+pub(super) struct Step {
+    pub(super) topic: String,
+    pub(super) action: Action,
+    pub(super) change: Change,
+    pub(super) status: Status,
+    #[tabled(display_with = "display_reason")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) reason: Option<String>,
+}
+
+fn display_reason(reason: &Option<String>) -> String {
+    reason.clone().unwrap_or_default()
+}
+
+impl Step {
+    fn new(topic: &str, action: Action, change: Change) -> Self {
+        Self {
+            topic: topic.to_owned(),
+            action,
+            change,
+            status: Status::Planned,
+            reason: None,
+        }
+    }
+
+    fn block(mut self, reason: &str) -> Self {
+        self.status = Status::Blocked;
+        self.reason = Some(reason.to_owned());
+        self
+    }
+
+    pub(super) fn settle(&mut self, error: Option<String>) {
+        self.status = match error {
+            None => Status::Applied,
+            Some(_) => Status::Failed,
+        };
+        self.reason = error;
+    }
+}
+
+/// Steps taking the cluster from `current` to `target`. Internal topics are
+/// never managed, and topics missing from the spec are only deleted with
+/// `allow_delete`. Steps breaking the safety rules are kept in the plan but
+/// marked as blocked.
+pub(super) fn plan(
+    current: &ClusterSpec,
+    target: &ClusterSpec,
+    acls: &BTreeMap<String, BTreeSet<TopicAcl>>,
+    allow_delete: bool,
+) -> Vec<Step> {
+    let internal_topic_regex =
+        Regex::new(INTERNAL_TOPIC_REGEX).expect("Failed to compile inbuilt regex");
+
+    let mut current = current.clone();
+
+    current.retain(|topic| {
+        !internal_topic_regex.is_match(topic) && (allow_delete || target.topics.contains_key(topic))
+    });
+
+    let mut steps = Vec::new();
+
+    for (topic, change) in current.diff(target) {
+        match change {
+            TopicChange::Added(spec) => {
+                let step = Step::new(&topic, Action::Create, Change::Topic(spec));
+
+                steps.push(match internal_topic_regex.is_match(&topic) {
+                    true => step.block("internal topics are not managed"),
+                    false => step,
+                });
+            }
+            TopicChange::Removed(spec) => {
+                steps.push(Step::new(&topic, Action::Delete, Change::Topic(spec)))
+            }
+            TopicChange::Changed(fields) => {
+                for field in fields {
+                    let step = match field.field {
+                        SpecField::Partitions => {
+                            let from = field.from.and_then(|p| p.parse().ok()).unwrap_or_default();
+                            let to = field.to.and_then(|p| p.parse().ok()).unwrap_or_default();
+                            let step =
+                                Step::new(&topic, Action::Update, Change::Partitions { from, to });
+
+                            match to < from {
+                                true => step.block("partitions cannot be decreased"),
+                                false => step,
+                            }
+                        }
+                        SpecField::ReplicationFactor => Step::new(
+                            &topic,
+                            Action::Update,
+                            Change::ReplicationFactor {
+                                from: field.from.and_then(|r| r.parse().ok()).unwrap_or_default(),
+                                to: field.to.and_then(|r| r.parse().ok()).unwrap_or_default(),
+                            },
+                        )
+                        .block("replication factor changes need a reassignment"),
+                        SpecField::Config(name) => Step::new(
+                            &topic,
+                            Action::Update,
+                            Change::Config {
+                                name,
+                                from: field.from,
+                                to: field.to,
+                            },
+                        ),
+                    };
+
+                    steps.push(step);
+                }
+            }
+        }
+    }
+
+    for (topic, spec) in &target.topics {
+        let Some(declared) = &spec.acls else {
+            continue;
+        };
+
+        let declared = declared
+            .iter()
+            .map(|acl| acl.topic_acl(topic))
+            .collect::<BTreeSet<_>>();
+        let existing = acls.get(topic).cloned().unwrap_or_default();
+
+        for acl in declared.difference(&existing) {
+            steps.push(Step::new(topic, Action::Create, Change::Acl(acl.clone())));
+        }
+
+        for acl in existing.difference(&declared) {
+            let step = Step::new(topic, Action::Delete, Change::Acl(acl.clone()));
+
+            steps.push(match allow_delete {
+                true => step,
+                false => step.block("deletions need --allow-delete"),
+            });
+        }
+    }
+
+    steps
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, BTreeSet};
+
+    use serde_json::json;
+
+    use super::{plan, Action, Change, Status, Step};
+    use crate::io::{
+        admin::acl::{AclOperation, AclPermission, TopicAcl},
+        spec::ClusterSpec,
+    };
+
+    fn spec(value: serde_json::Value) -> ClusterSpec {
+        serde_json::from_value(json!({ "topics": value })).unwrap()
+    }
+
+    fn acl(topic: &str, principal: &str, operation: AclOperation) -> TopicAcl {
+        TopicAcl {
+            topic: topic.to_owned(),
+            principal: principal.to_owned(),
+            host: "*".to_owned(),
+            operation,
+            permission: AclPermission::Allow,
+        }
+    }
+
+    fn summary(steps: &[Step]) -> Vec<(String, Action, String, Status)> {
+        steps
+            .iter()
+            .map(|s| (s.topic.clone(), s.action, s.change.to_string(), s.status))
+            .collect()
+    }
+
+    fn step(
+        topic: &str,
+        action: Action,
+        change: &str,
+        status: Status,
+    ) -> (String, Action, String, Status) {
+        (topic.to_owned(), action, change.to_owned(), status)
+    }
+
+    #[test]
+    fn matching_specs_need_no_steps() {
+        let current = spec(json!({ "orders": { "partitions": 3, "replication_factor": 3 } }));
+
+        assert!(plan(&current, &current, &BTreeMap::new(), true).is_empty());
+    }
+
+    #[test]
+    fn new_topics_are_created_and_fields_updated() {
+        let current = spec(json!({
+            "orders": {
+                "partitions": 3,
+                "replication_factor": 3,
+                "configs": { "retention.ms": 1000, "cleanup.policy": "delete" },
+            },
+        }));
+        let target = spec(json!({
+            "orders": {
+                "partitions": 6,
+                "replication_factor": 3,
+                "configs": { "retention.ms": "2000", "min.insync.replicas": 2 },
+            },
+            "payments": { "partitions": 1, "replication_factor": 1 },
+        }));
+
+        let steps = plan(&current, &target, &BTreeMap::new(), false);
+
+        assert_eq!(
+            summary(&steps),
+            [
+                step(
+                    "orders",
+                    Action::Update,
+                    "partitions: 3 -> 6",
+                    Status::Planned
+                ),
+                step(
+                    "orders",
+                    Action::Update,
+                    "configs.cleanup.policy: delete -> <default>",
+                    Status::Planned
+                ),
+                step(
+                    "orders",
+                    Action::Update,
+                    "configs.min.insync.replicas: <default> -> 2",
+                    Status::Planned
+                ),
+                step(
+                    "orders",
+                    Action::Update,
+                    "configs.retention.ms: 1000 -> 2000",
+                    Status::Planned
+                ),
+                step(
+                    "payments",
+                    Action::Create,
+                    "topic (partitions: 1, replication_factor: 1, configs: 0)",
+                    Status::Planned
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn unsafe_changes_are_blocked() {
+        let current = spec(json!({ "orders": { "partitions": 6, "replication_factor": 3 } }));
+        let target = spec(json!({ "orders": { "partitions": 3, "replication_factor": 2 } }));
+
+        let steps = plan(&current, &target, &BTreeMap::new(), true);
+
+        assert_eq!(steps.len(), 2);
+        assert!(steps.iter().all(|s| s.status == Status::Blocked));
+        assert!(matches!(
+            steps[0].change,
+            Change::Partitions { from: 6, to: 3 }
+        ));
+        assert!(matches!(
+            steps[1].change,
+            Change::ReplicationFactor { from: 3, to: 2 }
+        ));
+        assert!(steps.iter().all(|s| s.reason.is_some()));
+    }
+
+    #[test]
+    fn undeclared_topics_are_only_deleted_with_allow_delete() {
+        let current = spec(json!({
+            "orders": { "partitions": 1, "replication_factor": 1 },
+            "legacy": { "partitions": 1, "replication_factor": 1 },
+        }));
+        let target = spec(json!({ "orders": { "partitions": 1, "replication_factor": 1 } }));
+
+        assert!(plan(&current, &target, &BTreeMap::new(), false).is_empty());
+
+        let steps = plan(&current, &target, &BTreeMap::new(), true);
+
+        assert_eq!(steps.len(), 1);
+        assert_eq!(steps[0].topic, "legacy");
+        assert_eq!(steps[0].action, Action::Delete);
+        assert_eq!(steps[0].status, Status::Planned);
+    }
+
+    #[test]
+    fn internal_topics_are_never_managed() {
+        let current = spec(json!({
+            "__consumer_offsets": { "partitions": 50, "replication_factor": 3 },
+        }));
+
+        assert!(plan(&current, &ClusterSpec::default(), &BTreeMap::new(), true).is_empty());
+
+        let target = spec(json!({
+            "__transaction_state": { "partitions": 50, "replication_factor": 3 },
+        }));
+        let steps = plan(&ClusterSpec::default(), &target, &BTreeMap::new(), true);
+
+        assert_eq!(steps.len(), 1);
+        assert_eq!(steps[0].action, Action::Create);
+        assert_eq!(steps[0].status, Status::Blocked);
+    }
+
+    #[test]
+    fn declared_acls_are_reconciled() {
+        let current = spec(json!({ "orders": { "partitions": 1, "replication_factor": 1 } }));
+        let target = spec(json!({
+            "orders": {
+                "partitions": 1,
+                "replication_factor": 1,
+                "acls": [
+                    { "principal": "User:app", "operation": "read" },
+                    { "principal": "User:app", "operation": "write" },
+                ],
+            },
+        }));
+        let acls = BTreeMap::from([(
+            "orders".to_owned(),
+            BTreeSet::from([
+                acl("orders", "User:app", AclOperation::Read),
+                acl("orders", "User:old", AclOperation::Read),
+            ]),
+        )]);
+
+        let steps = plan(&current, &target, &acls, false);
+
+        assert_eq!(steps.len(), 2);
+        assert!(matches!(
+            &steps[0].change,
+            Change::Acl(a) if *a == acl("orders", "User:app", AclOperation::Write)
+        ));
+        assert_eq!(
+            (steps[0].action, steps[0].status),
+            (Action::Create, Status::Planned)
+        );
+        assert!(matches!(
+            &steps[1].change,
+            Change::Acl(a) if *a == acl("orders", "User:old", AclOperation::Read)
+        ));
+        assert_eq!(
+            (steps[1].action, steps[1].status),
+            (Action::Delete, Status::Blocked)
+        );
+
+        let steps = plan(&current, &target, &acls, true);
+
+        assert_eq!(steps[1].status, Status::Planned);
+    }
+
+    #[test]
+    fn undeclared_acls_are_left_alone() {
+        let current = spec(json!({ "orders": { "partitions": 1, "replication_factor": 1 } }));
+        let acls = BTreeMap::from([(
+            "orders".to_owned(),
+            BTreeSet::from([acl("orders", "User:app", AclOperation::Read)]),
+        )]);
+
+        assert!(plan(&current, &current, &acls, true).is_empty());
+    }
+
+    #[test]
+    fn settle_records_the_outcome() {
+        let current = spec(json!({}));
+        let target = spec(json!({ "orders": { "partitions": 1, "replication_factor": 1 } }));
+        let mut steps = plan(&current, &target, &BTreeMap::new(), false);
+
+        steps[0].settle(Some("broker unavailable".to_owned()));
+        assert_eq!(steps[0].status, Status::Failed);
+        assert_eq!(steps[0].reason.as_deref(), Some("broker unavailable"));
+
+        steps[0].settle(None);
+        assert_eq!(steps[0].status, Status::Applied);
+        assert_eq!(steps[0].reason, None);
+    }
+}
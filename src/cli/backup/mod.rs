@@ -0,0 +1,241 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs::{create_dir_all, exists},
+    path::PathBuf,
+    time::Duration,
+};
+
+use chrono::Local;
+use clap::{Args, ValueHint};
+use error_stack::{Report, ResultExt};
+use log::info;
+use rdkafka::{
+    config::RDKafkaLogLevel,
+    consumer::{BaseConsumer, Consumer},
+    error::KafkaError,
+    message::Headers,
+    Message, Offset, TopicPartitionList,
+};
+use uuid::Uuid;
+
+use crate::{
+    config::{clusters::NamedCluster, Context},
+    error::cli::backup::BackupError,
+    io::backup::{
+        BackupHeader, BackupManifest, BackupRecord, BackupWriter, PartitionManifest,
+        FORMAT_VERSION, MANIFEST_FILE,
+    },
+};
+
+use super::{util::get_user_input_confirmation, GlobalArgs, Invoke};
+
+const OVERWRITE_PROMPT: &str = "A backup already exists in this directory, overwrite it?";
+const CLIENT_TIMEOUT: Duration = Duration::from_millis(2500);
+
+#[derive(Args, Debug)]
+pub(super) struct BackupCommand {
+    #[arg(index = 1, help = "Topic to back up.")]
+    topic: String,
+    #[arg(short, long, help = "Target cluster to back up from.")]
+    cluster: Option<String>,
+    #[arg(short, long, value_hint = ValueHint::DirPath, help = "Directory to write the backup to.")]
+    to: PathBuf,
+    #[arg(
+        short,
+        long,
+        value_delimiter = ',',
+        help = "Partitions to back up, defaults to all partitions."
+    )]
+    partitions: Vec<i32>,
+}
+
+impl Invoke for BackupCommand {
+    type E = BackupError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), BackupError> {
+        let Self {
+            topic,
+            cluster,
+            to,
+            partitions,
+        } = self;
+
+        let NamedCluster(cluster_name, cluster_config) = ctx
+            .clusters
+            .cluster_config_or_default_or_select(cluster.as_deref())
+            .change_context(BackupError::FetchCluster)?;
+
+        let consumer = cluster_config
+            .client_config()
+            .set("group.id", Uuid::new_v4().to_string())
+            .set("enable.auto.commit", "false")
+            .set("enable.partition.eof", "true")
+            .set_log_level(RDKafkaLogLevel::Emerg)
+            .create::<BaseConsumer>()
+            .change_context(BackupError::CreateConsumer)?;
+
+        let metadata = consumer
+            .fetch_metadata(Some(&topic), CLIENT_TIMEOUT)
+            .change_context(BackupError::Metadata(topic.clone()))?;
+
+        let topic_metadata = metadata
+            .topics()
+            .iter()
+            .find(|t| t.name() == topic && t.error().is_none())
+            .ok_or(Report::new(BackupError::TopicNotExists(topic.clone())))?;
+
+        let available = topic_metadata
+            .partitions()
+            .iter()
+            .map(|p| p.id())
+            .collect::<Vec<_>>();
+
+        let partitions = if partitions.is_empty() {
+            available
+        } else {
+            if let Some(missing) = partitions.iter().find(|p| !available.contains(p)) {
+                Err(Report::new(BackupError::PartitionNotExists(*missing)))?
+            }
+
+            partitions
+        };
+
+        if exists(to.join(MANIFEST_FILE))
+            .change_context(BackupError::CreateDirectory(to.display().to_string()))?
+            && !get_user_input_confirmation(OVERWRITE_PROMPT)
+                .change_context(BackupError::InputError("overwrite"))?
+        {
+            Err(Report::new(BackupError::AlreadyExists(
+                to.display().to_string(),
+            )))?
+        }
+
+        create_dir_all(&to)
+            .change_context(BackupError::CreateDirectory(to.display().to_string()))?;
+
+        let mut writers = HashMap::new();
+        let mut manifests = HashMap::new();
+        let mut remaining = HashSet::new();
+        let mut tpl = TopicPartitionList::new();
+
+        for partition in partitions {
+            let (low, high) = consumer
+                .fetch_watermarks(&topic, partition, CLIENT_TIMEOUT)
+                .change_context(BackupError::Metadata(topic.clone()))?;
+
+            let file = BackupManifest::partition_file(partition);
+
+            let writer =
+                BackupWriter::create(&to.join(&file)).change_context(BackupError::Write)?;
+
+            writers.insert(partition, writer);
+
+            manifests.insert(
+                partition,
+                PartitionManifest {
+                    partition,
+                    file,
+                    start_offset: low,
+                    end_offset: high,
+                    records: 0,
+                },
+            );
+
+            if high > low {
+                tpl.add_partition_offset(&topic, partition, Offset::Offset(low))
+                    .change_context(BackupError::ConsumerFailure)?;
+
+                remaining.insert(partition);
+            }
+        }
+
+        consumer
+            .assign(&tpl)
+            .change_context(BackupError::ConsumerFailure)?;
+
+        while !remaining.is_empty() {
+            match consumer.poll(Duration::from_secs(1)) {
+                None => (),
+                Some(Err(KafkaError::PartitionEOF(partition))) => {
+                    remaining.remove(&partition);
+                }
+                Some(Err(e)) => Err(e).change_context(BackupError::ConsumerFailure)?,
+                Some(Ok(message)) => {
+                    let partition = message.partition();
+                    let manifest = manifests
+                        .get_mut(&partition)
+                        .expect("Received message for unassigned partition.");
+
+                    if message.offset() >= manifest.end_offset {
+                        remaining.remove(&partition);
+                        continue;
+                    }
+
+                    let record = BackupRecord {
+                        offset: message.offset(),
+                        timestamp: message.timestamp(),
+                        key: message.key().map(<[u8]>::to_vec),
+                        value: message.payload().map(<[u8]>::to_vec),
+                        headers: message
+                            .headers()
+                            .map(|headers| {
+                                headers
+                                    .iter()
+                                    .map(|h| BackupHeader {
+                                        key: h.key.as_bytes().to_vec(),
+                                        value: h.value.map(<[u8]>::to_vec),
+                                    })
+                                    .collect()
+                            })
+                            .unwrap_or_default(),
+                    };
+
+                    writers
+                        .get_mut(&partition)
+                        .expect("Writer exists for every assigned partition.")
+                        .write(&record)
+                        .change_context(BackupError::Write)?;
+
+                    manifest.records += 1;
+
+                    if message.offset() >= manifest.end_offset - 1 {
+                        info!("Finished backing up partition: {}", partition);
+
+                        remaining.remove(&partition);
+                    }
+                }
+            }
+        }
+
+        for writer in writers.into_values() {
+            writer.finish().change_context(BackupError::Write)?;
+        }
+
+        let mut partitions = manifests.into_values().collect::<Vec<_>>();
+
+        partitions.sort_by_key(|p| p.partition);
+
+        let manifest = BackupManifest {
+            format_version: FORMAT_VERSION,
+            topic,
+            cluster: cluster_name,
+            created_at: Local::now().to_rfc3339(),
+            partitions,
+        };
+
+        manifest.write(&to).change_context(BackupError::Write)?;
+
+        let display = global_args
+            .out
+            .output_string(&manifest)
+            .change_context(BackupError::Output)?;
+
+        println!("{}", display);
+
+        Ok(())
+    }
+}
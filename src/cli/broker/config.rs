@@ -0,0 +1,340 @@
+use clap::{ArgGroup, Args, Subcommand};
+use error_stack::{Report, ResultExt};
+use rdkafka::{
+    config::RDKafkaLogLevel,
+    consumer::{BaseConsumer, Consumer},
+};
+use serde::Serialize;
+use tabled::{
+    settings::{Panel, Style},
+    Table, Tabled,
+};
+use uuid::Uuid;
+
+use crate::{
+    cli::{
+        util::{get_user_input_confirmation, parse_key_value},
+        GlobalArgs, Invoke,
+    },
+    config::{clusters::NamedCluster, Context},
+    error::cli::{broker::BrokerError, ExecutionError},
+    io::{
+        admin::{incremental_alter_configs, AlterConfigOp, ConfigAlteration, ConfigResource},
+        output::Output,
+    },
+};
+
+use super::{describe_resource, REQUEST_TIMEOUT};
+
+#[derive(Args, Debug)]
+pub(super) struct BrokerConfigCommand {
+    #[command(subcommand)]
+    command: BrokerConfigSubCommand,
+}
+
+#[derive(Subcommand, Debug)]
+enum BrokerConfigSubCommand {
+    #[command(about = "Remove dynamic config overrides.")]
+    Delete(BrokerConfigDelete),
+    #[command(about = "Show config values for a broker or the cluster-wide defaults.")]
+    Get(BrokerConfigGet),
+    #[command(about = "Set dynamic config overrides.")]
+    Set(BrokerConfigSet),
+}
+
+impl Invoke for BrokerConfigCommand {
+    type E = ExecutionError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), ExecutionError> {
+        match self.command {
+            BrokerConfigSubCommand::Delete(command) => command
+                .invoke(ctx, global_args)
+                .change_context(ExecutionError::ExecutionFailed("broker config delete")),
+            BrokerConfigSubCommand::Get(command) => command
+                .invoke(ctx, global_args)
+                .change_context(ExecutionError::ExecutionFailed("broker config get")),
+            BrokerConfigSubCommand::Set(command) => command
+                .invoke(ctx, global_args)
+                .change_context(ExecutionError::ExecutionFailed("broker config set")),
+        }
+    }
+}
+
+#[derive(Debug, Args)]
+#[command(group(
+    ArgGroup::new("target")
+        .required(true)
+        .args(["broker", "cluster_default"])
+))]
+struct ConfigTarget {
+    #[arg(short, long, help = "Target cluster.")]
+    cluster: Option<String>,
+    #[arg(short, long, help = "Broker id to target.")]
+    broker: Option<i32>,
+    #[arg(
+        long,
+        help = "Target the cluster-wide default applied to every broker."
+    )]
+    cluster_default: bool,
+}
+
+impl ConfigTarget {
+    fn resource(&self) -> ConfigResource {
+        match self.broker {
+            Some(id) => ConfigResource::Broker(id),
+            None => ConfigResource::BrokerDefault,
+        }
+    }
+
+    fn consumer(&self, ctx: &Context) -> error_stack::Result<(String, BaseConsumer), BrokerError> {
+        let NamedCluster(cluster_name, cluster_config) = ctx
+            .clusters
+            .cluster_config_or_default_or_select(self.cluster.as_deref())
+            .change_context(BrokerError::FetchCluster)?;
+
+        let consumer = cluster_config
+            .client_config()
+            .set("group.id", Uuid::new_v4().to_string())
+            .set_log_level(RDKafkaLogLevel::Emerg)
+            .create::<BaseConsumer>()
+            .change_context(BrokerError::CreateClient)?;
+
+        Ok((cluster_name, consumer))
+    }
+
+    /// Applies `entries` and fails if the broker rejected the alteration.
+    fn alter(
+        &self,
+        consumer: &BaseConsumer,
+        entries: Vec<(String, AlterConfigOp)>,
+    ) -> error_stack::Result<(), BrokerError> {
+        let resource = self.resource();
+
+        let results = incremental_alter_configs(
+            consumer.client(),
+            &[ConfigAlteration {
+                resource: resource.clone(),
+                entries,
+            }],
+            REQUEST_TIMEOUT,
+        )
+        .change_context(BrokerError::AlterConfigs)?;
+
+        if let Some(error) = results.into_iter().find_map(|r| r.error) {
+            Err(Report::new(BrokerError::Rejected(
+                resource.to_string(),
+                error,
+            )))?
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize, Tabled)]
+struct AlterRow {
+    name: String,
+    #[tabled(display_with = "display_value")]
+    value: Option<String>,
+}
+
+fn display_value(value: &Option<String>) -> String {
+    value.clone().unwrap_or_else(|| "<deleted>".to_owned())
+}
+
+fn print_alterations(
+    out: Output,
+    rows: &[AlterRow],
+    resource: &ConfigResource,
+    cluster_name: &str,
+) -> error_stack::Result<(), BrokerError> {
+    let display = match out {
+        Output::Human => Table::new(rows)
+            .with(Style::modern_rounded())
+            .with(Panel::footer(format!(
+                "Resource: {}, Configs: {}, Cluster: {}",
+                resource,
+                rows.len(),
+                cluster_name
+            )))
+            .to_string(),
+        out => out
+            .output_string(&rows)
+            .change_context(BrokerError::Output)?,
+    };
+
+    println!("{}", display);
+
+    Ok(())
+}
+
+#[derive(Debug, Args)]
+struct BrokerConfigGet {
+    #[command(flatten)]
+    target: ConfigTarget,
+    #[arg(
+        index = 1,
+        help = "Config names to show, or every config when omitted."
+    )]
+    names: Vec<String>,
+}
+
+impl Invoke for BrokerConfigGet {
+    type E = BrokerError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), BrokerError> {
+        let Self { target, names } = self;
+
+        let (cluster_name, consumer) = target.consumer(ctx)?;
+        let resource = target.resource();
+
+        let mut rows = describe_resource(consumer.client(), resource.clone())?;
+
+        if !names.is_empty() {
+            rows.retain(|r| names.contains(&r.name));
+        }
+
+        let display = match global_args.out {
+            Output::Human => Table::new(&rows)
+                .with(Style::modern_rounded())
+                .with(Panel::footer(format!(
+                    "Resource: {}, Configs: {}, Cluster: {}",
+                    resource,
+                    rows.len(),
+                    cluster_name
+                )))
+                .to_string(),
+            out => out
+                .output_string(&rows)
+                .change_context(BrokerError::Output)?,
+        };
+
+        println!("{}", display);
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Args)]
+struct BrokerConfigSet {
+    #[command(flatten)]
+    target: ConfigTarget,
+    #[arg(
+        index = 1,
+        required = true,
+        value_parser = parse_key_value,
+        help = "Configs to set as key=value."
+    )]
+    configs: Vec<(String, String)>,
+    #[arg(short, long, help = "Skip the confirmation prompt.")]
+    yes: bool,
+}
+
+impl Invoke for BrokerConfigSet {
+    type E = BrokerError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), BrokerError> {
+        let Self {
+            target,
+            configs,
+            yes,
+        } = self;
+
+        let (cluster_name, consumer) = target.consumer(ctx)?;
+        let resource = target.resource();
+
+        if !yes
+            && !get_user_input_confirmation(&format!(
+                "Set {} configs on {} in '{}'?",
+                configs.len(),
+                resource,
+                cluster_name
+            ))
+            .change_context(BrokerError::InputError("confirmation"))?
+        {
+            return Ok(());
+        }
+
+        target.alter(
+            &consumer,
+            configs
+                .iter()
+                .map(|(k, v)| (k.to_owned(), AlterConfigOp::Set(v.to_owned())))
+                .collect(),
+        )?;
+
+        let rows = configs
+            .into_iter()
+            .map(|(name, value)| AlterRow {
+                name,
+                value: Some(value),
+            })
+            .collect::<Vec<_>>();
+
+        print_alterations(global_args.out, &rows, &resource, &cluster_name)
+    }
+}
+
+#[derive(Debug, Args)]
+struct BrokerConfigDelete {
+    #[command(flatten)]
+    target: ConfigTarget,
+    #[arg(index = 1, required = true, help = "Config names to remove.")]
+    names: Vec<String>,
+    #[arg(short, long, help = "Skip the confirmation prompt.")]
+    yes: bool,
+}
+
+impl Invoke for BrokerConfigDelete {
+    type E = BrokerError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), BrokerError> {
+        let Self { target, names, yes } = self;
+
+        let (cluster_name, consumer) = target.consumer(ctx)?;
+        let resource = target.resource();
+
+        if !yes
+            && !get_user_input_confirmation(&format!(
+                "Delete {} configs from {} in '{}'?",
+                names.len(),
+                resource,
+                cluster_name
+            ))
+            .change_context(BrokerError::InputError("confirmation"))?
+        {
+            return Ok(());
+        }
+
+        target.alter(
+            &consumer,
+            names
+                .iter()
+                .map(|n| (n.to_owned(), AlterConfigOp::Delete))
+                .collect(),
+        )?;
+
+        let rows = names
+            .into_iter()
+            .map(|name| AlterRow { name, value: None })
+            .collect::<Vec<_>>();
+
+        print_alterations(global_args.out, &rows, &resource, &cluster_name)
+    }
+}
@@ -0,0 +1,99 @@
+use clap::Args;
+use error_stack::{Report, ResultExt};
+use rdkafka::{
+    config::RDKafkaLogLevel,
+    consumer::{BaseConsumer, Consumer},
+};
+use tabled::{
+    settings::{Panel, Style},
+    Table,
+};
+use uuid::Uuid;
+
+use crate::{
+    cli::{GlobalArgs, Invoke},
+    config::{clusters::NamedCluster, Context},
+    error::cli::broker::BrokerError,
+    io::{
+        admin::{ConfigResource, ConfigSource},
+        output::Output,
+    },
+};
+
+use super::{describe_resource, CLIENT_TIMEOUT};
+
+#[derive(Debug, Args)]
+pub(super) struct BrokerDescribe {
+    #[arg(index = 1, help = "Broker id.")]
+    id: i32,
+    #[arg(short, long, help = "Target cluster.")]
+    cluster: Option<String>,
+    #[arg(
+        long,
+        help = "Only show entries that differ from the built-in default."
+    )]
+    non_default: bool,
+}
+
+impl Invoke for BrokerDescribe {
+    type E = BrokerError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), BrokerError> {
+        let Self {
+            id,
+            cluster,
+            non_default,
+        } = self;
+
+        let NamedCluster(cluster_name, cluster_config) = ctx
+            .clusters
+            .cluster_config_or_default_or_select(cluster.as_deref())
+            .change_context(BrokerError::FetchCluster)?;
+
+        let consumer = cluster_config
+            .client_config()
+            .set("group.id", Uuid::new_v4().to_string())
+            .set_log_level(RDKafkaLogLevel::Emerg)
+            .create::<BaseConsumer>()
+            .change_context(BrokerError::CreateClient)?;
+
+        let metadata = consumer
+            .fetch_metadata(None, CLIENT_TIMEOUT)
+            .change_context(BrokerError::Metadata)?;
+
+        let Some(broker) = metadata.brokers().iter().find(|b| b.id() == id) else {
+            Err(Report::new(BrokerError::BrokerNotExists(id)))?
+        };
+
+        let mut rows = describe_resource(consumer.client(), ConfigResource::Broker(id))?;
+
+        if non_default {
+            rows.retain(|r| r.source != ConfigSource::Default);
+        }
+
+        let display = match global_args.out {
+            Output::Human => Table::new(&rows)
+                .with(Style::modern_rounded())
+                .with(Panel::footer(format!(
+                    "Broker: {} ({}:{}), Configs: {}, Cluster: {}",
+                    id,
+                    broker.host(),
+                    broker.port(),
+                    rows.len(),
+                    cluster_name
+                )))
+                .to_string(),
+            out => out
+                .output_string(&rows)
+                .change_context(BrokerError::Output)?,
+        };
+
+        println!("{}", display);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,118 @@
+use std::collections::BTreeMap;
+
+use clap::Args;
+use error_stack::ResultExt;
+use rdkafka::{
+    config::RDKafkaLogLevel,
+    consumer::{BaseConsumer, Consumer},
+};
+use serde::Serialize;
+use tabled::{
+    settings::{Panel, Style},
+    Table, Tabled,
+};
+use uuid::Uuid;
+
+use crate::{
+    cli::{GlobalArgs, Invoke},
+    config::{clusters::NamedCluster, Context},
+    error::cli::broker::BrokerError,
+    io::output::Output,
+};
+
+use super::CLIENT_TIMEOUT;
+
+#[derive(Debug, Args)]
+pub(super) struct BrokerList {
+    #[arg(short, long, help = "Target cluster.")]
+    cluster: Option<String>,
+}
+
+#[derive(Debug, Serialize, Tabled)]
+struct BrokerRow {
+    id: i32,
+    host: String,
+    port: i32,
+    controller: bool,
+    leaders: usize,
+    replicas: usize,
+}
+
+impl Invoke for BrokerList {
+    type E = BrokerError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), BrokerError> {
+        let Self { cluster } = self;
+
+        let NamedCluster(cluster_name, cluster_config) = ctx
+            .clusters
+            .cluster_config_or_default_or_select(cluster.as_deref())
+            .change_context(BrokerError::FetchCluster)?;
+
+        let consumer = cluster_config
+            .client_config()
+            .set("group.id", Uuid::new_v4().to_string())
+            .set_log_level(RDKafkaLogLevel::Emerg)
+            .create::<BaseConsumer>()
+            .change_context(BrokerError::CreateClient)?;
+
+        let metadata = consumer
+            .fetch_metadata(None, CLIENT_TIMEOUT)
+            .change_context(BrokerError::Metadata)?;
+
+        let controller = unsafe {
+            rdkafka_sys::rd_kafka_controllerid(
+                consumer.client().native_ptr(),
+                CLIENT_TIMEOUT.as_millis() as i32,
+            )
+        };
+
+        let mut leaders = BTreeMap::<i32, usize>::new();
+        let mut replicas = BTreeMap::<i32, usize>::new();
+
+        for partition in metadata.topics().iter().flat_map(|t| t.partitions()) {
+            *leaders.entry(partition.leader()).or_default() += 1;
+
+            for replica in partition.replicas() {
+                *replicas.entry(*replica).or_default() += 1;
+            }
+        }
+
+        let mut rows = metadata
+            .brokers()
+            .iter()
+            .map(|b| BrokerRow {
+                id: b.id(),
+                host: b.host().to_owned(),
+                port: b.port(),
+                controller: b.id() == controller,
+                leaders: leaders.get(&b.id()).copied().unwrap_or_default(),
+                replicas: replicas.get(&b.id()).copied().unwrap_or_default(),
+            })
+            .collect::<Vec<_>>();
+
+        rows.sort_by_key(|r| r.id);
+
+        let display = match global_args.out {
+            Output::Human => Table::new(&rows)
+                .with(Style::modern_rounded())
+                .with(Panel::footer(format!(
+                    "Count: {}, Cluster: {}",
+                    rows.len(),
+                    cluster_name
+                )))
+                .to_string(),
+            out => out
+                .output_string(&rows)
+                .change_context(BrokerError::Output)?,
+        };
+
+        println!("{}", display);
+
+        Ok(())
+    }
+}
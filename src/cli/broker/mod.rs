@@ -0,0 +1,120 @@
+use std::time::Duration;
+
+use clap::{Args, Subcommand};
+use config::BrokerConfigCommand;
+use describe::BrokerDescribe;
+use error_stack::{Report, ResultExt};
+use list::BrokerList;
+use rdkafka::{client::Client, ClientContext};
+use serde::Serialize;
+use tabled::Tabled;
+
+use crate::{
+    config::Context,
+    error::cli::{broker::BrokerError, ExecutionError},
+    io::admin::{describe_configs, ConfigEntry, ConfigResource, ConfigSource},
+};
+
+use super::{GlobalArgs, Invoke};
+
+mod config;
+mod describe;
+mod list;
+
+const CLIENT_TIMEOUT: Duration = Duration::from_millis(2500);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Args, Debug)]
+pub(super) struct BrokerCommand {
+    #[command(subcommand)]
+    command: BrokerSubCommand,
+}
+
+#[derive(Subcommand, Debug)]
+enum BrokerSubCommand {
+    #[command(about = "Get, set or delete dynamic broker configs.")]
+    Config(BrokerConfigCommand),
+    #[command(about = "Show every config entry of a broker and where it comes from.")]
+    Describe(BrokerDescribe),
+    #[command(about = "List brokers in the cluster.")]
+    List(BrokerList),
+}
+
+impl Invoke for BrokerCommand {
+    type E = ExecutionError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), ExecutionError> {
+        match self.command {
+            BrokerSubCommand::Config(command) => command.invoke(ctx, global_args),
+            BrokerSubCommand::Describe(command) => command
+                .invoke(ctx, global_args)
+                .change_context(ExecutionError::ExecutionFailed("broker describe")),
+            BrokerSubCommand::List(command) => command
+                .invoke(ctx, global_args)
+                .change_context(ExecutionError::ExecutionFailed("broker list")),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Tabled)]
+struct ConfigRow {
+    name: String,
+    #[tabled(display_with = "display_value")]
+    value: Option<String>,
+    source: ConfigSource,
+    sensitive: bool,
+    read_only: bool,
+}
+
+impl From<ConfigEntry> for ConfigRow {
+    fn from(entry: ConfigEntry) -> Self {
+        Self {
+            name: entry.name,
+            value: entry.value,
+            source: entry.source,
+            sensitive: entry.sensitive,
+            read_only: entry.read_only,
+        }
+    }
+}
+
+fn display_value(value: &Option<String>) -> String {
+    value.clone().unwrap_or_else(|| "None".to_owned())
+}
+
+/// Describes a single resource, surfacing a per-resource error from the broker
+/// as a failure rather than an empty entry list.
+fn describe_resource<C>(
+    client: &Client<C>,
+    resource: ConfigResource,
+) -> error_stack::Result<Vec<ConfigRow>, BrokerError>
+where
+    C: ClientContext,
+{
+    let description = describe_configs(client, std::slice::from_ref(&resource), REQUEST_TIMEOUT)
+        .change_context(BrokerError::DescribeConfigs)?
+        .into_iter()
+        .next()
+        .ok_or(BrokerError::DescribeConfigs)?;
+
+    if let Some(error) = description.error {
+        Err(Report::new(BrokerError::Rejected(
+            resource.to_string(),
+            error,
+        )))?
+    }
+
+    let mut rows = description
+        .entries
+        .into_iter()
+        .map(ConfigRow::from)
+        .collect::<Vec<_>>();
+
+    rows.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Ok(rows)
+}
@@ -0,0 +1,263 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use clap::Args;
+use error_stack::{Report, ResultExt};
+use serde::Serialize;
+use tabled::{
+    settings::{object::Rows, Color, Panel, Style},
+    Table, Tabled,
+};
+
+use crate::{
+    cli::{GlobalArgs, Invoke},
+    config::{clusters::NamedCluster, Context},
+    error::cli::cluster::ClusterError,
+    io::{
+        output::Output,
+        protocol::{
+            api_versions::{api_name, ApiVersionsRequest},
+            error_string, ProtocolClient,
+        },
+    },
+};
+
+use super::REQUEST_TIMEOUT;
+
+/// librdkafka enables these features once every API they need is supported
+/// at or above the given version, see `rdkafka_feature.c`.
+const LIBRDKAFKA_FEATURES: &[(&str, &[(i16, i16)])] = &[
+    ("ApiVersion", &[(18, 0)]),
+    ("BrokerGroupCoordinator", &[(10, 0)]),
+    (
+        "BrokerBalancedConsumer",
+        &[(10, 0), (8, 1), (9, 1), (11, 0), (14, 0), (12, 0), (13, 0)],
+    ),
+    ("ThrottleTime", &[(0, 1), (1, 1)]),
+    ("MsgVer1", &[(0, 2), (1, 2)]),
+    ("MsgVer2", &[(0, 3), (1, 4)]),
+    ("OffsetTime", &[(2, 1)]),
+    ("SaslHandshake", &[(17, 0)]),
+    ("SaslAuthReq", &[(17, 1), (36, 0)]),
+    ("IdempotentProducer", &[(22, 0)]),
+    (
+        "Transactions",
+        &[(22, 0), (24, 0), (25, 0), (26, 0), (28, 0)],
+    ),
+    ("ZSTD", &[(0, 7), (1, 10)]),
+    ("ConsumerGroupProtocol", &[(68, 0), (69, 0)]),
+];
+
+#[derive(Debug, Args)]
+pub(super) struct ClusterApiVersions {
+    #[arg(short, long, help = "Target cluster.")]
+    cluster: Option<String>,
+    #[arg(
+        short,
+        long,
+        help = "Only show APIs whose versions differ between brokers."
+    )]
+    mismatches_only: bool,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd, Serialize)]
+struct VersionRange {
+    min: i16,
+    max: i16,
+}
+
+#[derive(Debug, Serialize, Tabled)]
+struct ApiRow {
+    api: String,
+    key: i16,
+    #[tabled(display_with = "display_brokers")]
+    brokers: BTreeMap<i32, Option<VersionRange>>,
+    mismatch: bool,
+}
+
+#[derive(Debug, Serialize, Tabled)]
+struct FeatureRow {
+    feature: &'static str,
+    usable: bool,
+    #[tabled(display_with = "display_missing")]
+    missing_on: Vec<i32>,
+}
+
+#[derive(Debug, Serialize)]
+struct ApiVersionsReport {
+    apis: Vec<ApiRow>,
+    librdkafka_features: Vec<FeatureRow>,
+}
+
+fn display_range(range: &Option<VersionRange>) -> String {
+    match range {
+        Some(range) => format!("{}-{}", range.min, range.max),
+        None => "unsupported".to_owned(),
+    }
+}
+
+fn display_brokers(brokers: &BTreeMap<i32, Option<VersionRange>>) -> String {
+    let distinct = brokers.values().collect::<BTreeSet<_>>();
+
+    match distinct.len() {
+        1 => display_range(distinct.first().expect("Length checked.")),
+        _ => brokers
+            .iter()
+            .map(|(broker, range)| format!("{}: {}", broker, display_range(range)))
+            .collect::<Vec<_>>()
+            .join("\n"),
+    }
+}
+
+fn display_missing(brokers: &[i32]) -> String {
+    brokers
+        .iter()
+        .map(i32::to_string)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+impl Invoke for ClusterApiVersions {
+    type E = ClusterError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), ClusterError> {
+        let Self {
+            cluster,
+            mismatches_only,
+        } = self;
+
+        let NamedCluster(cluster_name, cluster_config) = ctx
+            .clusters
+            .cluster_config_or_default_or_select(cluster.as_deref())
+            .change_context(ClusterError::FetchCluster)?;
+
+        let mut client = ProtocolClient::connect(cluster_config, REQUEST_TIMEOUT)
+            .change_context(ClusterError::Protocol)?;
+
+        let brokers = client.brokers().keys().copied().collect::<Vec<_>>();
+        let mut versions = BTreeMap::<i32, BTreeMap<i16, VersionRange>>::new();
+
+        for broker in &brokers {
+            let response = client
+                .send(*broker, &ApiVersionsRequest)
+                .change_context(ClusterError::Protocol)
+                .attach_printable_lazy(|| format!("Broker: {}", broker))?;
+
+            if let Some(error) = error_string(response.error_code) {
+                Err(Report::new(ClusterError::Rejected(error)))
+                    .attach_printable(format!("Broker: {}", broker))?
+            }
+
+            versions.insert(
+                *broker,
+                response
+                    .api_keys
+                    .into_iter()
+                    .map(|k| {
+                        (
+                            k.api_key,
+                            VersionRange {
+                                min: k.min_version,
+                                max: k.max_version,
+                            },
+                        )
+                    })
+                    .collect(),
+            );
+        }
+
+        let api_keys = versions
+            .values()
+            .flat_map(|v| v.keys().copied())
+            .collect::<BTreeSet<_>>();
+
+        let apis = api_keys
+            .into_iter()
+            .map(|key| {
+                let ranges = versions
+                    .iter()
+                    .map(|(broker, v)| (*broker, v.get(&key).copied()))
+                    .collect::<BTreeMap<_, _>>();
+
+                ApiRow {
+                    api: api_name(key)
+                        .map(str::to_owned)
+                        .unwrap_or(format!("Unknown({})", key)),
+                    key,
+                    mismatch: ranges.values().collect::<BTreeSet<_>>().len() > 1,
+                    brokers: ranges,
+                }
+            })
+            .filter(|row| !mismatches_only || row.mismatch)
+            .collect::<Vec<_>>();
+
+        let librdkafka_features = LIBRDKAFKA_FEATURES
+            .iter()
+            .map(|(feature, requirements)| {
+                let missing_on = versions
+                    .iter()
+                    .filter(|(_, v)| {
+                        !requirements.iter().all(|(key, version)| {
+                            v.get(key)
+                                .is_some_and(|r| r.min <= *version && *version <= r.max)
+                        })
+                    })
+                    .map(|(broker, _)| *broker)
+                    .collect::<Vec<_>>();
+
+                FeatureRow {
+                    feature,
+                    usable: missing_on.is_empty(),
+                    missing_on,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let report = ApiVersionsReport {
+            apis,
+            librdkafka_features,
+        };
+
+        let display = match global_args.out {
+            Output::Human => {
+                let mismatches = report.apis.iter().filter(|a| a.mismatch).count();
+                let mut apis = Table::new(&report.apis);
+
+                apis.with(Style::modern_rounded());
+
+                // Row 0 is the header.
+                for (index, _) in report.apis.iter().enumerate().filter(|(_, a)| a.mismatch) {
+                    apis.modify(Rows::single(index + 1), Color::FG_RED);
+                }
+
+                apis.with(Panel::footer(format!(
+                    "APIs: {}, Mismatches: {}, Brokers: {}, Cluster: {}",
+                    report.apis.len(),
+                    mismatches,
+                    brokers.len(),
+                    cluster_name
+                )));
+
+                let features = Table::new(&report.librdkafka_features)
+                    .with(Style::modern_rounded())
+                    .with(Panel::footer(format!(
+                        "librdkafka {}",
+                        rdkafka::util::get_rdkafka_version().1
+                    )))
+                    .to_string();
+
+                format!("{}\n{}", apis, features)
+            }
+            out => out
+                .output_string(&report)
+                .change_context(ClusterError::Output)?,
+        };
+
+        println!("{}", display);
+
+        Ok(())
+    }
+}
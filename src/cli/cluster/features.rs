@@ -0,0 +1,368 @@
+use std::collections::BTreeMap;
+
+use clap::{Args, Subcommand};
+use error_stack::{Report, ResultExt};
+use serde::Serialize;
+use tabled::{
+    settings::{Panel, Style},
+    Table, Tabled,
+};
+
+use crate::{
+    cli::{
+        util::{get_user_input_confirmation, parse_key_value},
+        GlobalArgs, Invoke,
+    },
+    config::{clusters::NamedCluster, Context},
+    error::cli::{cluster::ClusterError, ExecutionError},
+    io::{
+        output::Output,
+        protocol::{
+            api_versions::ApiVersionsRequest,
+            error_string,
+            features::{FeatureUpgradeType, UpdateFeaturesRequest},
+            ProtocolClient,
+        },
+    },
+};
+
+use super::REQUEST_TIMEOUT;
+
+#[derive(Args, Debug)]
+pub(super) struct FeaturesCommand {
+    #[command(subcommand)]
+    command: FeaturesSubCommand,
+}
+
+#[derive(Subcommand, Debug)]
+enum FeaturesSubCommand {
+    #[command(about = "Show finalized feature levels and the range every broker supports.")]
+    Describe(FeaturesDescribe),
+    #[command(about = "Lower finalized feature levels.")]
+    Downgrade(FeaturesDowngrade),
+    #[command(about = "Raise finalized feature levels.")]
+    Upgrade(FeaturesUpgrade),
+}
+
+impl Invoke for FeaturesCommand {
+    type E = ExecutionError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), ExecutionError> {
+        match self.command {
+            FeaturesSubCommand::Describe(command) => command
+                .invoke(ctx, global_args)
+                .change_context(ExecutionError::ExecutionFailed("cluster features describe")),
+            FeaturesSubCommand::Downgrade(command) => command
+                .invoke(ctx, global_args)
+                .change_context(ExecutionError::ExecutionFailed(
+                    "cluster features downgrade",
+                )),
+            FeaturesSubCommand::Upgrade(command) => command
+                .invoke(ctx, global_args)
+                .change_context(ExecutionError::ExecutionFailed("cluster features upgrade")),
+        }
+    }
+}
+
+#[derive(Debug, Args)]
+struct FeaturesDescribe {
+    #[arg(short, long, help = "Target cluster.")]
+    cluster: Option<String>,
+}
+
+#[derive(Debug, Serialize, Tabled)]
+struct FeatureRow {
+    feature: String,
+    #[tabled(display_with = "display_level")]
+    finalized: Option<i16>,
+    min_supported: i16,
+    max_supported: i16,
+    #[tabled(display_with = "display_level")]
+    upgradable_to: Option<i16>,
+}
+
+fn display_level(level: &Option<i16>) -> String {
+    level.map(|l| l.to_string()).unwrap_or_default()
+}
+
+impl Invoke for FeaturesDescribe {
+    type E = ClusterError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), ClusterError> {
+        let NamedCluster(cluster_name, cluster_config) = ctx
+            .clusters
+            .cluster_config_or_default_or_select(self.cluster.as_deref())
+            .change_context(ClusterError::FetchCluster)?;
+
+        let mut client = ProtocolClient::connect(cluster_config, REQUEST_TIMEOUT)
+            .change_context(ClusterError::Protocol)?;
+
+        let brokers = client.brokers().keys().copied().collect::<Vec<_>>();
+        let mut supported = BTreeMap::<String, (i16, i16)>::new();
+        let mut finalized = (None, Vec::new());
+
+        // A feature is only usable up to the level every broker supports.
+        for broker in &brokers {
+            let response = client
+                .send(*broker, &ApiVersionsRequest)
+                .change_context(ClusterError::Protocol)
+                .attach_printable_lazy(|| format!("Broker: {}", broker))?;
+
+            if let Some(error) = error_string(response.error_code) {
+                Err(Report::new(ClusterError::Rejected(error)))
+                    .attach_printable(format!("Broker: {}", broker))?
+            }
+
+            for feature in response.supported_features {
+                supported
+                    .entry(feature.name)
+                    .and_modify(|(min, max)| {
+                        *min = feature.min_version.max(*min);
+                        *max = feature.max_version.min(*max);
+                    })
+                    .or_insert((feature.min_version, feature.max_version));
+            }
+
+            if response.finalized_features_epoch > finalized.0 {
+                finalized = (
+                    response.finalized_features_epoch,
+                    response.finalized_features,
+                );
+            }
+        }
+
+        let (epoch, finalized) = finalized;
+
+        let rows = supported
+            .into_iter()
+            .map(|(feature, (min_supported, max_supported))| {
+                let level = finalized
+                    .iter()
+                    .find(|f| f.name == feature)
+                    .map(|f| f.max_version_level);
+
+                FeatureRow {
+                    feature,
+                    finalized: level,
+                    min_supported,
+                    max_supported,
+                    upgradable_to: (level.unwrap_or_default() < max_supported)
+                        .then_some(max_supported),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let display = match global_args.out {
+            Output::Human => Table::new(&rows)
+                .with(Style::modern_rounded())
+                .with(Panel::footer(format!(
+                    "Epoch: {}, Brokers: {}, Cluster: {}",
+                    epoch.map(|e| e.to_string()).unwrap_or("none".to_owned()),
+                    brokers.len(),
+                    cluster_name
+                )))
+                .to_string(),
+            out => out
+                .output_string(&rows)
+                .change_context(ClusterError::Output)?,
+        };
+
+        println!("{}", display);
+
+        Ok(())
+    }
+}
+
+fn parse_feature_level(s: &str) -> Result<(String, i16), String> {
+    let (feature, level) = parse_key_value(s)?;
+
+    level
+        .trim()
+        .parse::<i16>()
+        .map(|level| (feature, level))
+        .map_err(|_| format!("expected a numeric feature level, got: {level}"))
+}
+
+#[derive(Debug, Args)]
+struct FeatureUpdateArgs {
+    #[arg(short, long, help = "Target cluster.")]
+    cluster: Option<String>,
+    #[arg(
+        short,
+        long = "feature",
+        required = true,
+        value_parser = parse_feature_level,
+        help = "Feature and level as 'name=level', can be repeated."
+    )]
+    features: Vec<(String, i16)>,
+    #[arg(long, help = "Validate the update without applying it.")]
+    dry_run: bool,
+    #[arg(short, long, help = "Skip the confirmation prompt.")]
+    yes: bool,
+}
+
+#[derive(Debug, Args)]
+struct FeaturesUpgrade {
+    #[command(flatten)]
+    args: FeatureUpdateArgs,
+}
+
+#[derive(Debug, Args)]
+struct FeaturesDowngrade {
+    #[command(flatten)]
+    args: FeatureUpdateArgs,
+    #[arg(
+        long,
+        help = "Allow downgrades that may lose metadata, which brokers otherwise refuse."
+    )]
+    r#unsafe: bool,
+}
+
+#[derive(Debug, Serialize, Tabled)]
+struct UpdateRow {
+    feature: String,
+    level: i16,
+    update: String,
+    result: String,
+}
+
+impl Invoke for FeaturesUpgrade {
+    type E = ClusterError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), ClusterError> {
+        update_features(ctx, global_args, self.args, FeatureUpgradeType::Upgrade)
+    }
+}
+
+impl Invoke for FeaturesDowngrade {
+    type E = ClusterError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), ClusterError> {
+        let upgrade_type = match self.r#unsafe {
+            true => FeatureUpgradeType::UnsafeDowngrade,
+            false => FeatureUpgradeType::SafeDowngrade,
+        };
+
+        update_features(ctx, global_args, self.args, upgrade_type)
+    }
+}
+
+fn update_features(
+    ctx: &Context,
+    global_args: &GlobalArgs,
+    args: FeatureUpdateArgs,
+    upgrade_type: FeatureUpgradeType,
+) -> error_stack::Result<(), ClusterError> {
+    let FeatureUpdateArgs {
+        cluster,
+        features,
+        dry_run,
+        yes,
+    } = args;
+
+    let NamedCluster(cluster_name, cluster_config) = ctx
+        .clusters
+        .cluster_config_or_default_or_select(cluster.as_deref())
+        .change_context(ClusterError::FetchCluster)?;
+
+    if !yes
+        && !dry_run
+        && !get_user_input_confirmation(&format!(
+            "Apply {} of {} in '{}'?",
+            upgrade_type,
+            features
+                .iter()
+                .map(|(f, l)| format!("{}={}", f, l))
+                .collect::<Vec<_>>()
+                .join(", "),
+            cluster_name
+        ))
+        .change_context(ClusterError::InputError("confirmation"))?
+    {
+        return Ok(());
+    }
+
+    let mut client = ProtocolClient::connect(cluster_config, REQUEST_TIMEOUT)
+        .change_context(ClusterError::Protocol)?;
+
+    let response = client
+        .send_to_controller(&UpdateFeaturesRequest {
+            timeout: REQUEST_TIMEOUT,
+            updates: features
+                .iter()
+                .map(|(feature, level)| (feature.clone(), *level, upgrade_type))
+                .collect(),
+            validate_only: dry_run,
+        })
+        .change_context(ClusterError::Protocol)?;
+
+    if let Some(error) = error_string(response.error_code) {
+        Err(Report::new(ClusterError::Rejected(
+            response.error_message.unwrap_or(error),
+        )))?
+    }
+
+    let mut failed = Vec::new();
+
+    let rows = features
+        .into_iter()
+        .map(|(feature, level)| {
+            let error = response
+                .results
+                .iter()
+                .find(|r| r.feature == feature)
+                .and_then(|r| {
+                    error_string(r.error_code).map(|e| r.error_message.clone().unwrap_or(e))
+                });
+
+            if error.is_some() {
+                failed.push(feature.clone());
+            }
+
+            UpdateRow {
+                feature,
+                level,
+                update: upgrade_type.to_string(),
+                result: match (error, dry_run) {
+                    (Some(error), _) => error,
+                    (None, true) => "valid".to_owned(),
+                    (None, false) => "updated".to_owned(),
+                },
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let display = match global_args.out {
+        Output::Human => Table::new(&rows)
+            .with(Style::modern_rounded())
+            .with(Panel::footer(format!("Cluster: {}", cluster_name)))
+            .to_string(),
+        out => out
+            .output_string(&rows)
+            .change_context(ClusterError::Output)?,
+    };
+
+    println!("{}", display);
+
+    if !failed.is_empty() {
+        Err(Report::new(ClusterError::FeaturesFailed(failed.join(", "))))?
+    }
+
+    Ok(())
+}
@@ -0,0 +1,441 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    fmt::Display,
+    time::Duration,
+};
+
+use clap::Args;
+use error_stack::ResultExt;
+use rdkafka::{
+    config::RDKafkaLogLevel,
+    consumer::{BaseConsumer, Consumer},
+    metadata::Metadata,
+    Offset, TopicPartitionList,
+};
+use serde::Serialize;
+use tabled::{
+    settings::{Panel, Style},
+    Table, Tabled,
+};
+use uuid::Uuid;
+
+use crate::{
+    cli::{GlobalArgs, Invoke},
+    config::{
+        clusters::{ClusterConfig, NamedCluster},
+        profiles::group::GroupSetting,
+        Context,
+    },
+    error::{cli::cluster::ClusterError, io::ProtocolError},
+    io::{
+        admin::{describe_configs, list_consumer_group_offsets, ConfigResource},
+        output::Output,
+        protocol::{api_versions::ApiVersionsRequest, ProtocolClient},
+    },
+};
+
+use super::REQUEST_TIMEOUT;
+
+const CLIENT_TIMEOUT: Duration = Duration::from_secs(10);
+const MIN_INSYNC_REPLICAS: &str = "min.insync.replicas";
+const MAX_LISTED: usize = 10;
+
+#[derive(Debug, Args)]
+pub(super) struct ClusterHealth {
+    #[arg(short, long, help = "Target cluster.")]
+    cluster: Option<String>,
+    #[arg(
+        short,
+        long = "group",
+        help = "Consumer group to check lag of, defaults to groups set in profiles."
+    )]
+    groups: Vec<String>,
+    #[arg(
+        long,
+        default_value_t = 1000,
+        help = "Total lag above which a group fails the check."
+    )]
+    max_lag: i64,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum Status {
+    Pass,
+    Warn,
+    Fail,
+}
+
+impl Status {
+    /// Exit codes leave 1 to errors running the command itself.
+    fn exit_code(&self) -> i32 {
+        match self {
+            Self::Pass => 0,
+            Self::Warn => 2,
+            Self::Fail => 3,
+        }
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pass => f.write_str("pass"),
+            Self::Warn => f.write_str("warn"),
+            Self::Fail => f.write_str("fail"),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Tabled)]
+struct CheckRow {
+    check: String,
+    status: Status,
+    detail: String,
+}
+
+impl CheckRow {
+    fn new(check: &str, status: Status, detail: String) -> Self {
+        Self {
+            check: check.to_owned(),
+            status,
+            detail,
+        }
+    }
+
+    /// Fails or warns with `problems` listed, passing when there are none.
+    fn from_problems(check: &str, status: Status, problems: Vec<String>) -> Self {
+        match problems.len() {
+            0 => Self::new(check, Status::Pass, "none".to_owned()),
+            count => Self::new(
+                check,
+                status,
+                format!("{}: {}", count, list_truncated(&problems)),
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct HealthReport {
+    cluster: String,
+    status: Status,
+    checks: Vec<CheckRow>,
+}
+
+fn list_truncated(items: &[String]) -> String {
+    match items.len() > MAX_LISTED {
+        true => format!(
+            "{}, ... {} more",
+            items[..MAX_LISTED].join(", "),
+            items.len() - MAX_LISTED
+        ),
+        false => items.join(", "),
+    }
+}
+
+impl Invoke for ClusterHealth {
+    type E = ClusterError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), ClusterError> {
+        let Self {
+            cluster,
+            groups,
+            max_lag,
+        } = self;
+
+        let groups = match groups.is_empty() {
+            true => ctx
+                .profiles
+                .profile_configs
+                .values()
+                .filter_map(|p| match &p.group {
+                    GroupSetting::Group(group) => Some(group.clone()),
+                    GroupSetting::Never => None,
+                })
+                .collect::<BTreeSet<_>>(),
+            false => groups.into_iter().collect(),
+        };
+
+        let NamedCluster(cluster_name, cluster_config) = ctx
+            .clusters
+            .cluster_config_or_default_or_select(cluster.as_deref())
+            .change_context(ClusterError::FetchCluster)?;
+
+        let consumer = create_consumer(cluster_config, &Uuid::new_v4().to_string())?;
+
+        let checks = match consumer.fetch_metadata(None, CLIENT_TIMEOUT) {
+            Ok(metadata) => {
+                let mut checks = vec![
+                    check_brokers(cluster_config, &metadata),
+                    check_controller(&consumer),
+                ];
+
+                checks.extend(check_partitions(&consumer, &metadata));
+
+                if !groups.is_empty() {
+                    let end_offsets = end_offsets(&consumer, &metadata);
+
+                    for group in &groups {
+                        checks.push(check_group(&consumer, &end_offsets, group, max_lag));
+                    }
+                }
+
+                checks
+            }
+            Err(e) => vec![CheckRow::new(
+                "metadata",
+                Status::Fail,
+                format!("cluster unreachable: {}", e),
+            )],
+        };
+
+        let report = HealthReport {
+            cluster: cluster_name,
+            status: checks
+                .iter()
+                .map(|c| c.status)
+                .max()
+                .unwrap_or(Status::Pass),
+            checks,
+        };
+
+        let display = match global_args.out {
+            Output::Human => Table::new(&report.checks)
+                .with(Style::modern_rounded())
+                .with(Panel::footer(format!(
+                    "Status: {}, Cluster: {}",
+                    report.status, report.cluster
+                )))
+                .to_string(),
+            out => out
+                .output_string(&report)
+                .change_context(ClusterError::Output)?,
+        };
+
+        println!("{}", display);
+
+        ctx.exit_code = report.status.exit_code();
+
+        Ok(())
+    }
+}
+
+fn create_consumer(
+    cluster_config: &ClusterConfig,
+    group_id: &str,
+) -> error_stack::Result<BaseConsumer, ClusterError> {
+    cluster_config
+        .client_config()
+        .set("group.id", group_id)
+        .set("enable.auto.commit", "false")
+        .set_log_level(RDKafkaLogLevel::Emerg)
+        .create::<BaseConsumer>()
+        .change_context(ClusterError::CreateClient)
+}
+
+/// Metadata only lists registered brokers, so each is also contacted directly.
+/// Clusters the protocol client cannot authenticate with only warn, as metadata
+/// was already fetched through them.
+fn check_brokers(cluster_config: &ClusterConfig, metadata: &Metadata) -> CheckRow {
+    const CHECK: &str = "brokers reachable";
+
+    let mut client = match ProtocolClient::connect(cluster_config, REQUEST_TIMEOUT) {
+        Ok(client) => client,
+        Err(e) => {
+            let status = match e.current_context() {
+                ProtocolError::UnsupportedAuth(_) => Status::Warn,
+                _ => Status::Fail,
+            };
+
+            return CheckRow::new(CHECK, status, e.current_context().to_string());
+        }
+    };
+
+    let unreachable = metadata
+        .brokers()
+        .iter()
+        .filter(|b| client.send(b.id(), &ApiVersionsRequest).is_err())
+        .map(|b| format!("{} ({}:{})", b.id(), b.host(), b.port()))
+        .collect::<Vec<_>>();
+
+    match unreachable.is_empty() {
+        true => CheckRow::new(
+            CHECK,
+            Status::Pass,
+            format!("{} brokers", metadata.brokers().len()),
+        ),
+        false => CheckRow::from_problems(CHECK, Status::Fail, unreachable),
+    }
+}
+
+fn check_controller(consumer: &BaseConsumer) -> CheckRow {
+    let controller = unsafe {
+        rdkafka_sys::rd_kafka_controllerid(
+            consumer.client().native_ptr(),
+            CLIENT_TIMEOUT.as_millis() as i32,
+        )
+    };
+
+    match controller {
+        id if id >= 0 => CheckRow::new("controller", Status::Pass, format!("broker {}", id)),
+        _ => CheckRow::new(
+            "controller",
+            Status::Fail,
+            "no active controller".to_owned(),
+        ),
+    }
+}
+
+fn check_partitions(consumer: &BaseConsumer, metadata: &Metadata) -> Vec<CheckRow> {
+    let topics = metadata
+        .topics()
+        .iter()
+        .filter(|t| t.error().is_none())
+        .collect::<Vec<_>>();
+
+    let mut offline = Vec::new();
+    let mut under_replicated = Vec::new();
+
+    for topic in &topics {
+        for partition in topic.partitions() {
+            if partition.leader() < 0 {
+                offline.push(format!("{}-{}", topic.name(), partition.id()));
+            } else if partition.isr().len() < partition.replicas().len() {
+                under_replicated.push(format!("{}-{}", topic.name(), partition.id()));
+            }
+        }
+    }
+
+    let resources = topics
+        .iter()
+        .map(|t| ConfigResource::Topic(t.name().to_owned()))
+        .collect::<Vec<_>>();
+
+    let descriptions = match resources.is_empty() {
+        true => Ok(Vec::new()),
+        false => describe_configs(consumer.client(), &resources, REQUEST_TIMEOUT),
+    };
+
+    let min_isr = match descriptions {
+        Ok(descriptions) => {
+            let min_isr = descriptions
+                .into_iter()
+                .filter_map(|d| {
+                    let value = d
+                        .entries
+                        .iter()
+                        .find(|e| e.name == MIN_INSYNC_REPLICAS)
+                        .and_then(|e| e.value.as_deref())
+                        .and_then(|v| v.parse::<usize>().ok())?;
+
+                    match d.resource {
+                        ConfigResource::Topic(topic) => Some((topic, value)),
+                        _ => None,
+                    }
+                })
+                .collect::<HashMap<_, _>>();
+
+            let below = topics
+                .iter()
+                .flat_map(|t| t.partitions().iter().map(move |p| (t.name(), p)))
+                .filter(|(topic, p)| {
+                    min_isr
+                        .get(*topic)
+                        .is_some_and(|min| p.leader() >= 0 && p.isr().len() < *min)
+                })
+                .map(|(topic, p)| format!("{}-{}", topic, p.id()))
+                .collect::<Vec<_>>();
+
+            CheckRow::from_problems("below min.insync.replicas", Status::Fail, below)
+        }
+        Err(e) => CheckRow::new(
+            "below min.insync.replicas",
+            Status::Fail,
+            format!("failed to describe topic configs: {}", e.current_context()),
+        ),
+    };
+
+    vec![
+        CheckRow::from_problems("offline partitions", Status::Fail, offline),
+        CheckRow::from_problems(
+            "under-replicated partitions",
+            Status::Warn,
+            under_replicated,
+        ),
+        min_isr,
+    ]
+}
+
+/// End offsets of every partition, looked up once for all groups.
+fn end_offsets(
+    consumer: &BaseConsumer,
+    metadata: &Metadata,
+) -> error_stack::Result<HashMap<(String, i32), i64>, ClusterError> {
+    let mut tpl = TopicPartitionList::new();
+
+    for topic in metadata.topics().iter().filter(|t| t.error().is_none()) {
+        for partition in topic.partitions() {
+            tpl.add_partition_offset(topic.name(), partition.id(), Offset::End)
+                .change_context(ClusterError::EndOffsets)?;
+        }
+    }
+
+    if tpl.count() == 0 {
+        return Ok(HashMap::new());
+    }
+
+    Ok(consumer
+        .offsets_for_times(tpl, CLIENT_TIMEOUT)
+        .change_context(ClusterError::EndOffsets)?
+        .elements()
+        .iter()
+        .filter_map(|e| match e.offset() {
+            Offset::Offset(offset) => Some(((e.topic().to_owned(), e.partition()), offset)),
+            _ => None,
+        })
+        .collect())
+}
+
+fn check_group(
+    consumer: &BaseConsumer,
+    end_offsets: &error_stack::Result<HashMap<(String, i32), i64>, ClusterError>,
+    group: &str,
+    max_lag: i64,
+) -> CheckRow {
+    let check = format!("group lag: {}", group);
+
+    let lag = end_offsets
+        .as_ref()
+        .map_err(|e| e.current_context().to_string())
+        .and_then(|end_offsets| {
+            let committed =
+                list_consumer_group_offsets(consumer.client(), group, CLIENT_TIMEOUT)
+                    .map_err(|_| ClusterError::CommittedOffsets(group.to_owned()).to_string())?;
+
+            Ok(committed
+                .iter()
+                .filter(|p| p.error.is_none() && p.offset >= 0)
+                .filter_map(|p| {
+                    end_offsets
+                        .get(&(p.topic.clone(), p.partition))
+                        .map(|high| (high - p.offset).max(0))
+                })
+                .reduce(|total, lag| total + lag))
+        });
+
+    match lag {
+        Ok(Some(lag)) if lag > max_lag => CheckRow::new(
+            &check,
+            Status::Fail,
+            format!("{} above threshold of {}", lag, max_lag),
+        ),
+        Ok(Some(lag)) => CheckRow::new(&check, Status::Pass, lag.to_string()),
+        Ok(None) => CheckRow::new(&check, Status::Warn, "no committed offsets".to_owned()),
+        Err(e) => CheckRow::new(&check, Status::Fail, e),
+    }
+}
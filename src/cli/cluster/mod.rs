@@ -0,0 +1,62 @@
+use std::time::Duration;
+
+use api_versions::ClusterApiVersions;
+use clap::{Args, Subcommand};
+use error_stack::ResultExt;
+use features::FeaturesCommand;
+use health::ClusterHealth;
+use quorum::ClusterQuorum;
+
+use crate::{config::Context, error::cli::ExecutionError};
+
+use super::{GlobalArgs, Invoke};
+
+mod api_versions;
+mod features;
+mod health;
+mod quorum;
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Args, Debug)]
+pub(super) struct ClusterCommand {
+    #[command(subcommand)]
+    command: ClusterSubCommand,
+}
+
+#[derive(Subcommand, Debug)]
+enum ClusterSubCommand {
+    #[command(about = "List API versions per broker and the librdkafka features they allow.")]
+    ApiVersions(ClusterApiVersions),
+    #[command(about = "Show, upgrade or downgrade finalized feature versions.")]
+    Features(FeaturesCommand),
+    #[command(
+        about = "Check brokers, partitions and group lag. Exits 0 on pass, 2 on warn and 3 on fail."
+    )]
+    Health(ClusterHealth),
+    #[command(about = "Show the KRaft metadata quorum and replica lag.")]
+    Quorum(ClusterQuorum),
+}
+
+impl Invoke for ClusterCommand {
+    type E = ExecutionError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), ExecutionError> {
+        match self.command {
+            ClusterSubCommand::ApiVersions(command) => command
+                .invoke(ctx, global_args)
+                .change_context(ExecutionError::ExecutionFailed("cluster api-versions")),
+            ClusterSubCommand::Features(command) => command.invoke(ctx, global_args),
+            ClusterSubCommand::Health(command) => command
+                .invoke(ctx, global_args)
+                .change_context(ExecutionError::ExecutionFailed("cluster health")),
+            ClusterSubCommand::Quorum(command) => command
+                .invoke(ctx, global_args)
+                .change_context(ExecutionError::ExecutionFailed("cluster quorum")),
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use chrono::Utc;
+use clap::Args;
+use error_stack::{Report, ResultExt};
+use serde::Serialize;
+use tabled::{
+    settings::{Panel, Style},
+    Table, Tabled,
+};
+
+use crate::{
+    cli::{GlobalArgs, Invoke},
+    config::{clusters::NamedCluster, Context},
+    error::cli::cluster::ClusterError,
+    io::{
+        output::Output,
+        protocol::{
+            error_string,
+            quorum::{DescribeQuorumRequest, QuorumReplica},
+            ProtocolClient,
+        },
+    },
+};
+
+use super::REQUEST_TIMEOUT;
+
+#[derive(Debug, Args)]
+pub(super) struct ClusterQuorum {
+    #[arg(short, long, help = "Target cluster.")]
+    cluster: Option<String>,
+}
+
+#[derive(Debug, Serialize, Tabled)]
+struct ReplicaRow {
+    replica_id: i32,
+    role: &'static str,
+    log_end_offset: i64,
+    lag: i64,
+    #[tabled(display_with = "display_millis")]
+    last_fetch_ms_ago: Option<i64>,
+    #[tabled(display_with = "display_millis")]
+    last_caught_up_ms_ago: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+struct QuorumReport {
+    leader_id: i32,
+    leader_epoch: i32,
+    high_watermark: i64,
+    max_follower_lag: i64,
+    max_follower_lag_ms: i64,
+    replicas: Vec<ReplicaRow>,
+}
+
+fn display_millis(millis: &Option<i64>) -> String {
+    millis.map(|m| m.to_string()).unwrap_or_default()
+}
+
+impl Invoke for ClusterQuorum {
+    type E = ClusterError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), ClusterError> {
+        let NamedCluster(cluster_name, cluster_config) = ctx
+            .clusters
+            .cluster_config_or_default_or_select(self.cluster.as_deref())
+            .change_context(ClusterError::FetchCluster)?;
+
+        let mut client = ProtocolClient::connect(cluster_config, REQUEST_TIMEOUT)
+            .change_context(ClusterError::Protocol)?;
+
+        // Brokers forward the request to the active controller.
+        let quorum = client
+            .send_to_controller(&DescribeQuorumRequest)
+            .change_context(ClusterError::Protocol)
+            .attach_printable("The metadata quorum only exists on KRaft clusters.")?;
+
+        if let Some(error) = error_string(quorum.error_code) {
+            Err(Report::new(ClusterError::Rejected(error)))?
+        }
+
+        let now = Utc::now().timestamp_millis();
+
+        let leader_end_offset = quorum
+            .voters
+            .iter()
+            .find(|v| v.replica_id == quorum.leader_id)
+            .map(|v| v.log_end_offset)
+            .unwrap_or(quorum.high_watermark);
+
+        let row = |replica: &QuorumReplica, role| {
+            let ago = |timestamp: i64| (timestamp >= 0).then(|| now - timestamp);
+
+            ReplicaRow {
+                replica_id: replica.replica_id,
+                role,
+                log_end_offset: replica.log_end_offset,
+                lag: leader_end_offset - replica.log_end_offset,
+                last_fetch_ms_ago: ago(replica.last_fetch_timestamp),
+                last_caught_up_ms_ago: ago(replica.last_caught_up_timestamp),
+            }
+        };
+
+        let replicas = quorum
+            .voters
+            .iter()
+            .map(|v| match v.replica_id == quorum.leader_id {
+                true => row(v, "leader"),
+                false => row(v, "voter"),
+            })
+            .chain(quorum.observers.iter().map(|o| row(o, "observer")))
+            .collect::<Vec<_>>();
+
+        let followers = replicas.iter().filter(|r| r.role != "leader");
+
+        let report = QuorumReport {
+            leader_id: quorum.leader_id,
+            leader_epoch: quorum.leader_epoch,
+            high_watermark: quorum.high_watermark,
+            max_follower_lag: followers.clone().map(|r| r.lag).max().unwrap_or_default(),
+            max_follower_lag_ms: followers
+                .filter_map(|r| r.last_caught_up_ms_ago)
+                .max()
+                .unwrap_or_default(),
+            replicas,
+        };
+
+        let display = match global_args.out {
+            Output::Human => Table::new(&report.replicas)
+                .with(Style::modern_rounded())
+                .with(Panel::footer(format!(
+                    "Leader: {}, Epoch: {}, High watermark: {}, Max lag: {} ({} ms), Voters: {}, Observers: {}, Cluster: {}",
+                    report.leader_id,
+                    report.leader_epoch,
+                    report.high_watermark,
+                    report.max_follower_lag,
+                    report.max_follower_lag_ms,
+                    quorum.voters.len(),
+                    quorum.observers.len(),
+                    cluster_name
+                )))
+                .to_string(),
+            out => out
+                .output_string(&report)
+                .change_context(ClusterError::Output)?,
+        };
+
+        println!("{}", display);
+
+        Ok(())
+    }
+}
@@ -9,9 +9,10 @@ use crate::{
     config::{
         clusters::{
             auth::{AuthType, AuthTypeNames},
+            rest::{BasicAuth, RestEndpoint},
             ClusterConfig,
         },
-        Context, FromUserInputForVariant,
+        Context, FromUserInput, FromUserInputForVariant,
     },
     error::cli::config::cluster::AddClusterError,
 };
@@ -29,6 +30,22 @@ pub(super) struct AddCluster {
     bootstrap_servers: Vec<String>,
     #[arg(short, long, help = "Auth type to configure.")]
     auth: Option<AuthTypeNames>,
+    #[arg(long, value_hint = ValueHint::Url, help = "Schema registry URL.")]
+    schema_registry: Option<String>,
+    #[arg(
+        long,
+        requires = "schema_registry",
+        help = "Prompt for schema registry basic auth credentials."
+    )]
+    schema_registry_auth: bool,
+    #[arg(long, value_hint = ValueHint::Url, help = "Kafka Connect REST URL.")]
+    connect: Option<String>,
+    #[arg(
+        long,
+        requires = "connect",
+        help = "Prompt for Kafka Connect basic auth credentials."
+    )]
+    connect_auth: bool,
     #[arg(long, help = "Refuse user input.")]
     no_input: bool,
 }
@@ -46,6 +63,10 @@ impl Invoke for AddCluster {
             mut bootstrap_servers,
             no_input,
             auth,
+            schema_registry,
+            schema_registry_auth,
+            connect,
+            connect_auth,
         } = self;
 
         if ctx.clusters.contains_cluster_config(&name)
@@ -78,6 +99,32 @@ impl Invoke for AddCluster {
             cluster.auth.replace(user_auth);
         }
 
+        if let Some(url) = schema_registry {
+            let mut endpoint = RestEndpoint::new(url);
+
+            if schema_registry_auth {
+                endpoint.auth = Some(
+                    BasicAuth::from_user_input()
+                        .change_context(AddClusterError::InputError("schema_registry_auth"))?,
+                );
+            }
+
+            cluster.schema_registry.replace(endpoint);
+        }
+
+        if let Some(url) = connect {
+            let mut endpoint = RestEndpoint::new(url);
+
+            if connect_auth {
+                endpoint.auth = Some(
+                    BasicAuth::from_user_input()
+                        .change_context(AddClusterError::InputError("connect_auth"))?,
+                );
+            }
+
+            cluster.connect.replace(endpoint);
+        }
+
         ctx.clusters.insert_cluster_config(&name, cluster);
 
         if ctx.clusters.default().is_none() {
@@ -4,8 +4,11 @@ use error_stack::{Report, ResultExt};
 use crate::{
     cli::{GlobalArgs, Invoke},
     config::{
-        clusters::auth::{AuthType, AuthTypeNames},
-        Context, FromUserInputForVariant,
+        clusters::{
+            auth::{AuthType, AuthTypeNames},
+            rest::{BasicAuth, RestEndpoint},
+        },
+        Context, FromUserInput, FromUserInputForVariant,
     },
     error::cli::config::cluster::WritableClusterError,
 };
@@ -20,6 +23,14 @@ pub(super) struct AlterCluster {
     bootstrap_servers: Vec<String>,
     #[arg(short, long, help = "Auth type to configure.")]
     auth: Option<AuthTypeNames>,
+    #[arg(long, value_hint = ValueHint::Url, help = "Schema registry URL.")]
+    schema_registry: Option<String>,
+    #[arg(long, help = "Prompt for schema registry basic auth credentials.")]
+    schema_registry_auth: bool,
+    #[arg(long, value_hint = ValueHint::Url, help = "Kafka Connect REST URL.")]
+    connect: Option<String>,
+    #[arg(long, help = "Prompt for Kafka Connect basic auth credentials.")]
+    connect_auth: bool,
 }
 
 impl Invoke for AlterCluster {
@@ -34,6 +45,10 @@ impl Invoke for AlterCluster {
             name,
             bootstrap_servers,
             auth,
+            schema_registry,
+            schema_registry_auth,
+            connect,
+            connect_auth,
         } = self;
 
         let cluster = ctx
@@ -55,6 +70,52 @@ impl Invoke for AlterCluster {
             cluster.auth.replace(user_auth);
         }
 
+        if let Some(url) = schema_registry {
+            let auth = cluster.schema_registry.take().and_then(|e| e.auth);
+
+            cluster.schema_registry.replace(RestEndpoint { url, auth });
+        }
+
+        if schema_registry_auth {
+            let endpoint = cluster
+                .schema_registry
+                .as_mut()
+                .ok_or(Report::new(WritableClusterError::InputError(
+                    "schema_registry_auth",
+                )))
+                .attach_printable(
+                    "The cluster has no schema registry, set one with --schema-registry.",
+                )?;
+
+            endpoint.auth = Some(
+                BasicAuth::from_user_input()
+                    .change_context(WritableClusterError::InputError("schema_registry_auth"))?,
+            );
+        }
+
+        if let Some(url) = connect {
+            let auth = cluster.connect.take().and_then(|e| e.auth);
+
+            cluster.connect.replace(RestEndpoint { url, auth });
+        }
+
+        if connect_auth {
+            let endpoint = cluster
+                .connect
+                .as_mut()
+                .ok_or(Report::new(WritableClusterError::InputError(
+                    "connect_auth",
+                )))
+                .attach_printable(
+                    "The cluster has no Kafka Connect endpoint, set one with --connect.",
+                )?;
+
+            endpoint.auth = Some(
+                BasicAuth::from_user_input()
+                    .change_context(WritableClusterError::InputError("connect_auth"))?,
+            );
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,62 @@
+use std::path::PathBuf;
+
+use clap::{Args, ValueHint};
+use error_stack::{Report, ResultExt};
+
+use crate::{
+    cli::{GlobalArgs, Invoke},
+    config::Context,
+    error::cli::connect::ConnectError,
+    io::input::Input,
+};
+
+use super::{connect_client, display_info, read_config};
+
+#[derive(Debug, Args)]
+pub(super) struct ConnectCreate {
+    #[arg(index = 1, help = "Connector name, taken from the config if omitted.")]
+    name: Option<String>,
+    #[arg(short, long, value_hint = ValueHint::FilePath, help = "Connector config file.")]
+    file: PathBuf,
+    #[arg(
+        short,
+        long,
+        help = "Format of the config file, taken from the extension if omitted."
+    )]
+    input: Option<Input>,
+    #[arg(short, long, help = "Target cluster.")]
+    cluster: Option<String>,
+}
+
+impl Invoke for ConnectCreate {
+    type E = ConnectError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), ConnectError> {
+        let Self {
+            name,
+            file,
+            input,
+            cluster,
+        } = self;
+
+        let config = read_config(&file, input)?;
+
+        let name = name
+            .or_else(|| config.name().map(str::to_owned))
+            .ok_or(Report::new(ConnectError::MissingName))?;
+
+        let (cluster_name, client) = connect_client(ctx, cluster.as_deref())?;
+
+        let info = client
+            .create(&name, &config.into_config())
+            .change_context(ConnectError::Connect)?;
+
+        println!("{}", display_info(&info, &cluster_name, global_args)?);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,77 @@
+use clap::Args;
+use error_stack::ResultExt;
+use serde::Serialize;
+use tabled::{
+    settings::{Panel, Style},
+    Table, Tabled,
+};
+
+use crate::{
+    cli::{util::get_user_input_confirmation, GlobalArgs, Invoke},
+    config::Context,
+    error::cli::connect::ConnectError,
+    io::output::Output,
+};
+
+use super::connect_client;
+
+#[derive(Debug, Args)]
+pub(super) struct ConnectDelete {
+    #[arg(index = 1, help = "Connector to delete.")]
+    name: String,
+    #[arg(short, long, help = "Target cluster.")]
+    cluster: Option<String>,
+    #[arg(short, long, help = "Skip the confirmation prompt.")]
+    yes: bool,
+}
+
+#[derive(Debug, Serialize, Tabled)]
+struct DeleteReport {
+    connector: String,
+    deleted: bool,
+}
+
+impl Invoke for ConnectDelete {
+    type E = ConnectError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), ConnectError> {
+        let Self { name, cluster, yes } = self;
+
+        let (cluster_name, client) = connect_client(ctx, cluster.as_deref())?;
+
+        if !yes
+            && !get_user_input_confirmation(&format!(
+                "Are you sure you want to delete connector '{}' on '{}'?",
+                name, cluster_name
+            ))
+            .change_context(ConnectError::InputError("confirmation"))?
+        {
+            return Ok(());
+        }
+
+        client.delete(&name).change_context(ConnectError::Connect)?;
+
+        let report = DeleteReport {
+            connector: name,
+            deleted: true,
+        };
+
+        let display = match global_args.out {
+            Output::Human => Table::new([&report])
+                .with(Style::modern_rounded())
+                .with(Panel::footer(format!("Cluster: {}", cluster_name)))
+                .to_string(),
+            out => out
+                .output_string(&report)
+                .change_context(ConnectError::Output)?,
+        };
+
+        println!("{}", display);
+
+        Ok(())
+    }
+}
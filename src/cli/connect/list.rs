@@ -0,0 +1,101 @@
+use clap::Args;
+use error_stack::ResultExt;
+use serde::Serialize;
+use tabled::{
+    settings::{object::Rows, Color, Panel, Style},
+    Table, Tabled,
+};
+
+use crate::{
+    cli::{GlobalArgs, Invoke},
+    config::Context,
+    error::cli::connect::ConnectError,
+    io::output::Output,
+};
+
+use super::{connect_client, FAILED_STATE};
+
+#[derive(Debug, Args)]
+pub(super) struct ConnectList {
+    #[arg(short, long, help = "Target cluster.")]
+    cluster: Option<String>,
+}
+
+#[derive(Debug, Serialize, Tabled)]
+struct ConnectorRow {
+    name: String,
+    #[serde(rename = "type")]
+    #[tabled(rename = "type")]
+    connector_type: String,
+    state: String,
+    running_tasks: usize,
+    failed_tasks: usize,
+    total_tasks: usize,
+}
+
+impl ConnectorRow {
+    fn failed(&self) -> bool {
+        self.state == FAILED_STATE || self.failed_tasks > 0
+    }
+}
+
+impl Invoke for ConnectList {
+    type E = ConnectError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), ConnectError> {
+        let (cluster_name, client) = connect_client(ctx, self.cluster.as_deref())?;
+
+        let mut rows = client
+            .statuses()
+            .change_context(ConnectError::Connect)?
+            .into_iter()
+            .map(|status| ConnectorRow {
+                running_tasks: status.tasks.iter().filter(|t| t.state == "RUNNING").count(),
+                failed_tasks: status
+                    .tasks
+                    .iter()
+                    .filter(|t| t.state == FAILED_STATE)
+                    .count(),
+                total_tasks: status.tasks.len(),
+                name: status.name,
+                connector_type: status.connector_type.unwrap_or_default(),
+                state: status.connector.state,
+            })
+            .collect::<Vec<_>>();
+
+        rows.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let display = match global_args.out {
+            Output::Human => {
+                let mut table = Table::new(&rows);
+
+                table
+                    .with(Style::modern_rounded())
+                    .with(Panel::footer(format!(
+                        "Connectors: {}, Failed: {}, Cluster: {}",
+                        rows.len(),
+                        rows.iter().filter(|r| r.failed()).count(),
+                        cluster_name
+                    )));
+
+                // Row 0 is the header.
+                for (index, _) in rows.iter().enumerate().filter(|(_, r)| r.failed()) {
+                    table.modify(Rows::single(index + 1), Color::FG_RED);
+                }
+
+                table.to_string()
+            }
+            out => out
+                .output_string(&rows)
+                .change_context(ConnectError::Output)?,
+        };
+
+        println!("{}", display);
+
+        Ok(())
+    }
+}
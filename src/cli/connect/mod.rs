@@ -0,0 +1,252 @@
+use std::{fs, path::Path, time::Duration};
+
+use clap::{Args, Subcommand};
+use create::ConnectCreate;
+use delete::ConnectDelete;
+use error_stack::{Report, ResultExt};
+use list::ConnectList;
+use pause::ConnectPause;
+use plugins::ConnectPlugins;
+use restart::ConnectRestart;
+use resume::ConnectResume;
+use serde::Serialize;
+use status::ConnectStatus;
+use tabled::{
+    settings::{object::Rows, Color, Panel, Style},
+    Table, Tabled,
+};
+use update::ConnectUpdate;
+
+use crate::{
+    config::{clusters::NamedCluster, Context},
+    error::cli::{connect::ConnectError, ExecutionError},
+    io::{
+        connect::{ConnectClient, ConnectorConfig, ConnectorInfo, ConnectorStatus},
+        input::Input,
+        output::Output,
+    },
+};
+
+use super::{GlobalArgs, Invoke};
+
+mod create;
+mod delete;
+mod list;
+mod pause;
+mod plugins;
+mod restart;
+mod resume;
+mod status;
+mod update;
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+const FAILED_STATE: &str = "FAILED";
+
+#[derive(Args, Debug)]
+pub(super) struct ConnectCommand {
+    #[command(subcommand)]
+    command: ConnectSubCommand,
+}
+
+#[derive(Subcommand, Debug)]
+enum ConnectSubCommand {
+    #[command(about = "Create a connector from a JSON or YAML config.")]
+    Create(ConnectCreate),
+    #[command(about = "Delete a connector.")]
+    Delete(ConnectDelete),
+    #[command(about = "List connectors and their states.")]
+    List(ConnectList),
+    #[command(about = "Pause a connector and its tasks.")]
+    Pause(ConnectPause),
+    #[command(about = "List the connector plugins installed on the workers.")]
+    Plugins(ConnectPlugins),
+    #[command(about = "Restart a connector, its tasks or a single task.")]
+    Restart(ConnectRestart),
+    #[command(about = "Resume a paused connector.")]
+    Resume(ConnectResume),
+    #[command(about = "Show the state of a connector and each of its tasks.")]
+    Status(ConnectStatus),
+    #[command(about = "Replace the config of a connector, creating it if missing.")]
+    Update(ConnectUpdate),
+}
+
+impl Invoke for ConnectCommand {
+    type E = ExecutionError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), ExecutionError> {
+        match self.command {
+            ConnectSubCommand::Create(command) => command
+                .invoke(ctx, global_args)
+                .change_context(ExecutionError::ExecutionFailed("connect create")),
+            ConnectSubCommand::Delete(command) => command
+                .invoke(ctx, global_args)
+                .change_context(ExecutionError::ExecutionFailed("connect delete")),
+            ConnectSubCommand::List(command) => command
+                .invoke(ctx, global_args)
+                .change_context(ExecutionError::ExecutionFailed("connect list")),
+            ConnectSubCommand::Pause(command) => command
+                .invoke(ctx, global_args)
+                .change_context(ExecutionError::ExecutionFailed("connect pause")),
+            ConnectSubCommand::Plugins(command) => command
+                .invoke(ctx, global_args)
+                .change_context(ExecutionError::ExecutionFailed("connect plugins")),
+            ConnectSubCommand::Restart(command) => command
+                .invoke(ctx, global_args)
+                .change_context(ExecutionError::ExecutionFailed("connect restart")),
+            ConnectSubCommand::Resume(command) => command
+                .invoke(ctx, global_args)
+                .change_context(ExecutionError::ExecutionFailed("connect resume")),
+            ConnectSubCommand::Status(command) => command
+                .invoke(ctx, global_args)
+                .change_context(ExecutionError::ExecutionFailed("connect status")),
+            ConnectSubCommand::Update(command) => command
+                .invoke(ctx, global_args)
+                .change_context(ExecutionError::ExecutionFailed("connect update")),
+        }
+    }
+}
+
+fn connect_client(
+    ctx: &Context,
+    cluster: Option<&str>,
+) -> error_stack::Result<(String, ConnectClient), ConnectError> {
+    let NamedCluster(cluster_name, cluster_config) = ctx
+        .clusters
+        .cluster_config_or_default_or_select(cluster)
+        .change_context(ConnectError::FetchCluster)?;
+
+    let endpoint = cluster_config
+        .connect()
+        .ok_or(Report::new(ConnectError::NoConnect(cluster_name.clone())))
+        .attach_printable("Set one with `kcli config cluster alter <name> --connect <url>`.")?;
+
+    Ok((cluster_name, ConnectClient::new(endpoint, REQUEST_TIMEOUT)))
+}
+
+/// Without an explicit format the file extension is used, falling back to
+/// JSON.
+fn read_config(
+    path: &Path,
+    input: Option<Input>,
+) -> error_stack::Result<ConnectorConfig, ConnectError> {
+    let file = path.display().to_string();
+
+    let contents =
+        fs::read_to_string(path).change_context(ConnectError::ReadConfig(file.clone()))?;
+
+    input
+        .or_else(|| Input::from_path(path))
+        .unwrap_or_default()
+        .read_from_str(&contents)
+        .change_context(ConnectError::ReadConfig(file))
+}
+
+#[derive(Debug, Serialize, Tabled)]
+struct StatusRow {
+    kind: &'static str,
+    #[tabled(display_with = "display_task")]
+    task: Option<i32>,
+    state: String,
+    worker_id: String,
+    #[tabled(display_with = "display_trace")]
+    trace: Option<String>,
+}
+
+fn display_task(task: &Option<i32>) -> String {
+    task.map(|t| t.to_string()).unwrap_or_default()
+}
+
+/// Traces are full stack traces, only their first line fits a table.
+fn display_trace(trace: &Option<String>) -> String {
+    trace
+        .as_deref()
+        .and_then(|t| t.lines().next())
+        .unwrap_or_default()
+        .to_owned()
+}
+
+fn display_status(
+    status: &ConnectorStatus,
+    cluster_name: &str,
+    global_args: &GlobalArgs,
+) -> error_stack::Result<String, ConnectError> {
+    Ok(match global_args.out {
+        Output::Human => {
+            let rows = std::iter::once(StatusRow {
+                kind: "connector",
+                task: None,
+                state: status.connector.state.clone(),
+                worker_id: status.connector.worker_id.clone(),
+                trace: status.connector.trace.clone(),
+            })
+            .chain(status.tasks.iter().map(|task| StatusRow {
+                kind: "task",
+                task: Some(task.id),
+                state: task.state.clone(),
+                worker_id: task.worker_id.clone(),
+                trace: task.trace.clone(),
+            }))
+            .collect::<Vec<_>>();
+
+            let mut table = Table::new(&rows);
+
+            table
+                .with(Style::modern_rounded())
+                .with(Panel::footer(format!(
+                    "Connector: {}, Type: {}, Cluster: {}",
+                    status.name,
+                    status.connector_type.as_deref().unwrap_or("unknown"),
+                    cluster_name
+                )));
+
+            // Row 0 is the header.
+            for (index, _) in rows
+                .iter()
+                .enumerate()
+                .filter(|(_, r)| r.state == FAILED_STATE)
+            {
+                table.modify(Rows::single(index + 1), Color::FG_RED);
+            }
+
+            table.to_string()
+        }
+        out => out
+            .output_string(status)
+            .change_context(ConnectError::Output)?,
+    })
+}
+
+#[derive(Debug, Serialize, Tabled)]
+struct ConfigRow<'a> {
+    name: &'a str,
+    value: &'a str,
+}
+
+fn display_info(
+    info: &ConnectorInfo,
+    cluster_name: &str,
+    global_args: &GlobalArgs,
+) -> error_stack::Result<String, ConnectError> {
+    Ok(match global_args.out {
+        Output::Human => Table::new(
+            info.config
+                .iter()
+                .map(|(name, value)| ConfigRow { name, value }),
+        )
+        .with(Style::modern_rounded())
+        .with(Panel::footer(format!(
+            "Connector: {}, Tasks: {}, Cluster: {}",
+            info.name,
+            info.tasks.len(),
+            cluster_name
+        )))
+        .to_string(),
+        out => out
+            .output_string(info)
+            .change_context(ConnectError::Output)?,
+    })
+}
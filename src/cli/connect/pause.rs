@@ -0,0 +1,65 @@
+use clap::Args;
+use error_stack::ResultExt;
+use serde::Serialize;
+use tabled::{
+    settings::{Panel, Style},
+    Table, Tabled,
+};
+
+use crate::{
+    cli::{GlobalArgs, Invoke},
+    config::Context,
+    error::cli::connect::ConnectError,
+    io::output::Output,
+};
+
+use super::connect_client;
+
+#[derive(Debug, Args)]
+pub(super) struct ConnectPause {
+    #[arg(index = 1, help = "Connector to pause.")]
+    name: String,
+    #[arg(short, long, help = "Target cluster.")]
+    cluster: Option<String>,
+}
+
+#[derive(Debug, Serialize, Tabled)]
+struct PauseReport {
+    connector: String,
+    paused: bool,
+}
+
+impl Invoke for ConnectPause {
+    type E = ConnectError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), ConnectError> {
+        let Self { name, cluster } = self;
+
+        let (cluster_name, client) = connect_client(ctx, cluster.as_deref())?;
+
+        client.pause(&name).change_context(ConnectError::Connect)?;
+
+        let report = PauseReport {
+            connector: name,
+            paused: true,
+        };
+
+        let display = match global_args.out {
+            Output::Human => Table::new([&report])
+                .with(Style::modern_rounded())
+                .with(Panel::footer(format!("Cluster: {}", cluster_name)))
+                .to_string(),
+            out => out
+                .output_string(&report)
+                .change_context(ConnectError::Output)?,
+        };
+
+        println!("{}", display);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+use clap::Args;
+use error_stack::ResultExt;
+use tabled::{
+    settings::{Panel, Style},
+    Table, Tabled,
+};
+
+use crate::{
+    cli::{GlobalArgs, Invoke},
+    config::Context,
+    error::cli::connect::ConnectError,
+    io::output::Output,
+};
+
+use super::connect_client;
+
+#[derive(Debug, Args)]
+pub(super) struct ConnectPlugins {
+    #[arg(short, long, help = "Target cluster.")]
+    cluster: Option<String>,
+}
+
+#[derive(Debug, Tabled)]
+struct PluginRow<'a> {
+    class: &'a str,
+    #[tabled(rename = "type")]
+    plugin_type: &'a str,
+    version: &'a str,
+}
+
+impl Invoke for ConnectPlugins {
+    type E = ConnectError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), ConnectError> {
+        let (cluster_name, client) = connect_client(ctx, self.cluster.as_deref())?;
+
+        let plugins = client.plugins().change_context(ConnectError::Connect)?;
+
+        let display = match global_args.out {
+            Output::Human => Table::new(plugins.iter().map(|p| PluginRow {
+                class: &p.class,
+                plugin_type: &p.plugin_type,
+                version: p.version.as_deref().unwrap_or_default(),
+            }))
+            .with(Style::modern_rounded())
+            .with(Panel::footer(format!(
+                "Plugins: {}, Cluster: {}",
+                plugins.len(),
+                cluster_name
+            )))
+            .to_string(),
+            out => out
+                .output_string(&plugins)
+                .change_context(ConnectError::Output)?,
+        };
+
+        println!("{}", display);
+
+        Ok(())
+    }
+}
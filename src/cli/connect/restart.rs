@@ -0,0 +1,103 @@
+use clap::Args;
+use error_stack::ResultExt;
+use serde::Serialize;
+use tabled::{
+    settings::{Panel, Style},
+    Table, Tabled,
+};
+
+use crate::{
+    cli::{GlobalArgs, Invoke},
+    config::Context,
+    error::cli::connect::ConnectError,
+    io::output::Output,
+};
+
+use super::{connect_client, display_status};
+
+#[derive(Debug, Args)]
+pub(super) struct ConnectRestart {
+    #[arg(index = 1, help = "Connector to restart.")]
+    name: String,
+    #[arg(
+        short,
+        long,
+        conflicts_with_all = ["include_tasks", "only_failed"],
+        help = "Only restart the task with this id."
+    )]
+    task: Option<i32>,
+    #[arg(long, help = "Restart the tasks along with the connector.")]
+    include_tasks: bool,
+    #[arg(long, help = "Only restart instances that are in the FAILED state.")]
+    only_failed: bool,
+    #[arg(short, long, help = "Target cluster.")]
+    cluster: Option<String>,
+}
+
+#[derive(Debug, Serialize, Tabled)]
+struct RestartReport {
+    connector: String,
+    #[tabled(display_with = "display_task")]
+    task: Option<i32>,
+    restarted: bool,
+}
+
+fn display_task(task: &Option<i32>) -> String {
+    task.map(|t| t.to_string())
+        .unwrap_or_else(|| "all".to_owned())
+}
+
+impl Invoke for ConnectRestart {
+    type E = ConnectError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), ConnectError> {
+        let Self {
+            name,
+            task,
+            include_tasks,
+            only_failed,
+            cluster,
+        } = self;
+
+        let (cluster_name, client) = connect_client(ctx, cluster.as_deref())?;
+
+        let status = match task {
+            Some(task) => client
+                .restart_task(&name, task)
+                .map(|_| None)
+                .change_context(ConnectError::Connect)?,
+            None => client
+                .restart(&name, include_tasks, only_failed)
+                .change_context(ConnectError::Connect)?,
+        };
+
+        let display = match status {
+            Some(status) => display_status(&status, &cluster_name, global_args)?,
+            None => {
+                let report = RestartReport {
+                    connector: name,
+                    task,
+                    restarted: true,
+                };
+
+                match global_args.out {
+                    Output::Human => Table::new([&report])
+                        .with(Style::modern_rounded())
+                        .with(Panel::footer(format!("Cluster: {}", cluster_name)))
+                        .to_string(),
+                    out => out
+                        .output_string(&report)
+                        .change_context(ConnectError::Output)?,
+                }
+            }
+        };
+
+        println!("{}", display);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+use clap::Args;
+use error_stack::ResultExt;
+
+use crate::{
+    cli::{GlobalArgs, Invoke},
+    config::Context,
+    error::cli::connect::ConnectError,
+};
+
+use super::{connect_client, display_status};
+
+#[derive(Debug, Args)]
+pub(super) struct ConnectStatus {
+    #[arg(index = 1, help = "Connector to show.")]
+    name: String,
+    #[arg(short, long, help = "Target cluster.")]
+    cluster: Option<String>,
+}
+
+impl Invoke for ConnectStatus {
+    type E = ConnectError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), ConnectError> {
+        let Self { name, cluster } = self;
+
+        let (cluster_name, client) = connect_client(ctx, cluster.as_deref())?;
+
+        let status = client.status(&name).change_context(ConnectError::Connect)?;
+
+        println!("{}", display_status(&status, &cluster_name, global_args)?);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,455 @@
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, HashMap, HashSet, VecDeque},
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+use error_stack::{Report, ResultExt};
+
+use crate::{
+    error::{cli::consume::ConsumerError, io::SegmentError},
+    io::{
+        protocol::{
+            error_string,
+            fetch::{FetchRequest, FetchedPartition},
+            ProtocolClient,
+        },
+        segment::{ControlRecordType, SegmentReader},
+    },
+};
+
+const FETCH_MAX_BYTES: i32 = 1024 * 1024;
+// Records past the last stable offset are reported as open until the log is
+// fetched again, which happens at most this often.
+const LSO_REFRESH: Duration = Duration::from_secs(1);
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(super) enum RecordState {
+    NonTransactional,
+    Committed,
+    Aborted,
+    /// Beyond the last stable offset, so its transaction is still open.
+    Open,
+}
+
+impl RecordState {
+    pub(super) fn label(&self) -> Option<&'static str> {
+        match self {
+            Self::Aborted => Some("aborted"),
+            Self::Open => Some("open"),
+            Self::NonTransactional | Self::Committed => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub(super) struct Marker {
+    offset: i64,
+    producer_id: i64,
+    control: ControlRecordType,
+}
+
+impl Display for Marker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "-- {} marker from producer {} at offset {} --",
+            self.control, self.producer_id, self.offset
+        )
+    }
+}
+
+struct PartitionLog {
+    fetched_at: Instant,
+    fetched_until: i64,
+    last_stable_offset: i64,
+    batches: VecDeque<(i64, i64, RecordState)>,
+    markers: VecDeque<Marker>,
+}
+
+impl PartitionLog {
+    /// Splits a read committed fetch starting at `offset` into the state of
+    /// each data batch and the control markers between them.
+    fn read(fetched: FetchedPartition, offset: i64) -> error_stack::Result<Self, SegmentError> {
+        let mut aborted = fetched.aborted_transactions;
+        aborted.sort_by_key(|a| Reverse(a.first_offset));
+
+        let mut aborting = HashSet::new();
+        let mut log = Self {
+            fetched_at: Instant::now(),
+            fetched_until: offset,
+            last_stable_offset: fetched.last_stable_offset,
+            batches: VecDeque::new(),
+            markers: VecDeque::new(),
+        };
+
+        // The last batch of a fetch may be cut short, so stop at the first
+        // one that does not parse.
+        for batch in SegmentReader::new(&fetched.records).map_while(Result::ok) {
+            let header = &batch.header;
+
+            while aborted
+                .last()
+                .is_some_and(|a| a.first_offset <= header.last_offset())
+            {
+                aborting.extend(aborted.pop().map(|a| a.producer_id));
+            }
+
+            log.fetched_until = header.last_offset() + 1;
+
+            if header.is_control() {
+                for record in batch.records()? {
+                    let Some(key) = record.key else {
+                        continue;
+                    };
+
+                    let control = ControlRecordType::from_key(&key)?;
+
+                    if control == ControlRecordType::Abort {
+                        aborting.remove(&header.producer_id);
+                    }
+
+                    log.markers.push_back(Marker {
+                        offset: record.offset,
+                        producer_id: header.producer_id,
+                        control,
+                    });
+                }
+
+                continue;
+            }
+
+            let state = match (
+                header.is_transactional(),
+                aborting.contains(&header.producer_id),
+            ) {
+                (false, _) => RecordState::NonTransactional,
+                (true, true) => RecordState::Aborted,
+                (true, false) => RecordState::Committed,
+            };
+
+            log.batches
+                .push_back((header.base_offset, header.last_offset(), state));
+        }
+
+        Ok(log)
+    }
+
+    fn is_stale(&self, offset: i64) -> bool {
+        if offset < self.fetched_until {
+            return false;
+        }
+
+        let open = self.last_stable_offset >= 0 && offset >= self.last_stable_offset;
+
+        !open || self.fetched_at.elapsed() >= LSO_REFRESH
+    }
+
+    /// Drops what lies before `offset`, returning the state of the record
+    /// there and the markers passed on the way.
+    fn advance(&mut self, offset: i64) -> (RecordState, Vec<Marker>) {
+        let mut markers = Vec::new();
+
+        while self.markers.front().is_some_and(|m| m.offset < offset) {
+            markers.extend(self.markers.pop_front());
+        }
+
+        while self
+            .batches
+            .front()
+            .is_some_and(|(_, last, _)| *last < offset)
+        {
+            self.batches.pop_front();
+        }
+
+        let state = match self.batches.front() {
+            Some((base, _, state)) if *base <= offset => *state,
+            _ => RecordState::Open,
+        };
+
+        (state, markers)
+    }
+}
+
+/// Follows the raw log of consumed partitions to work out which transaction
+/// outcome each record belongs to, as librdkafka drops control batches.
+pub(super) struct TransactionTracker {
+    client: ProtocolClient,
+    topic: String,
+    leaders: HashMap<i32, i32>,
+    partitions: HashMap<i32, PartitionLog>,
+}
+
+impl TransactionTracker {
+    pub(super) fn new(client: ProtocolClient, topic: String, leaders: HashMap<i32, i32>) -> Self {
+        Self {
+            client,
+            topic,
+            leaders,
+            partitions: HashMap::new(),
+        }
+    }
+
+    /// Returns the state of the record at `offset`, along with the control
+    /// markers written since the previous record consumed from the partition.
+    pub(super) fn annotate(
+        &mut self,
+        partition: i32,
+        offset: i64,
+    ) -> error_stack::Result<(RecordState, Vec<Marker>), ConsumerError> {
+        let mut markers = Vec::new();
+
+        let stale = self
+            .partitions
+            .get(&partition)
+            .is_none_or(|log| log.is_stale(offset));
+
+        if stale {
+            let fetched = self.fetch(partition, offset)?;
+
+            if let Some(previous) = self.partitions.insert(partition, fetched) {
+                markers.extend(previous.markers);
+            }
+        }
+
+        let (state, passed) = self
+            .partitions
+            .get_mut(&partition)
+            .expect("Partition log inserted above.")
+            .advance(offset);
+
+        markers.extend(passed);
+
+        Ok((state, markers))
+    }
+
+    fn fetch(
+        &mut self,
+        partition: i32,
+        offset: i64,
+    ) -> error_stack::Result<PartitionLog, ConsumerError> {
+        let leader = *self
+            .leaders
+            .get(&partition)
+            .ok_or(Report::new(ConsumerError::NoLeader(
+                self.topic.clone(),
+                partition,
+            )))?;
+
+        // A read committed fetch stops at the last stable offset and lists the
+        // aborted transactions overlapping the batches it returns.
+        let fetched = self
+            .client
+            .send(
+                leader,
+                &FetchRequest {
+                    topics: BTreeMap::from([(self.topic.clone(), vec![(partition, offset)])]),
+                    partition_max_bytes: FETCH_MAX_BYTES,
+                    read_committed: true,
+                },
+            )
+            .change_context(ConsumerError::Protocol)?
+            .into_iter()
+            .find(|p| p.topic == self.topic && p.partition == partition)
+            .ok_or(Report::new(ConsumerError::Rejected(
+                "partition missing from fetch response".to_owned(),
+            )))?;
+
+        if let Some(error) = error_string(fetched.error_code) {
+            Err(Report::new(ConsumerError::Rejected(error)))
+                .attach_printable(format!("{}-{}", self.topic, partition))?
+        }
+
+        PartitionLog::read(fetched, offset)
+            .change_context(ConsumerError::CorruptBatch(self.topic.clone(), partition))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::io::{
+        protocol::fetch::{AbortedTransaction, FetchedPartition},
+        segment::{
+            tests::{producer_batch, TestRecord},
+            ControlRecordType,
+        },
+    };
+
+    use super::{PartitionLog, RecordState};
+
+    const TRANSACTIONAL: i16 = 0x10;
+    const CONTROL: i16 = 0x30;
+
+    const RECORD: TestRecord = TestRecord {
+        timestamp_delta: 0,
+        key: None,
+        value: Some(b"value"),
+        headers: &[],
+    };
+
+    fn marker(control: ControlRecordType) -> TestRecord<'static> {
+        TestRecord {
+            timestamp_delta: 0,
+            key: Some(match control {
+                ControlRecordType::Abort => &[0, 0, 0, 0],
+                _ => &[0, 0, 0, 1],
+            }),
+            value: Some(&[0, 0, 0, 0, 0, 0]),
+            headers: &[],
+        }
+    }
+
+    fn fetched(
+        batches: &[Vec<u8>],
+        aborted: &[(i64, i64)],
+        last_stable_offset: i64,
+    ) -> FetchedPartition {
+        FetchedPartition {
+            topic: "topic".to_owned(),
+            partition: 0,
+            error_code: 0,
+            last_stable_offset,
+            aborted_transactions: aborted
+                .iter()
+                .map(|(producer_id, first_offset)| AbortedTransaction {
+                    producer_id: *producer_id,
+                    first_offset: *first_offset,
+                })
+                .collect(),
+            records: batches.concat(),
+        }
+    }
+
+    /// Producer 1 aborts offsets 0-1 while producer 2 commits 2-3 alongside a
+    /// plain batch at 4. Producer 1 then starts a new transaction at 7, which
+    /// is aborted when `second_abort` is set.
+    fn interleaved(second_abort: bool) -> PartitionLog {
+        let batches = [
+            producer_batch(0, TRANSACTIONAL, 1, 0, &[RECORD, RECORD]),
+            producer_batch(2, TRANSACTIONAL, 2, 0, &[RECORD, RECORD]),
+            producer_batch(4, 0, -1, -1, &[RECORD]),
+            producer_batch(5, CONTROL, 1, -1, &[marker(ControlRecordType::Abort)]),
+            producer_batch(6, CONTROL, 2, -1, &[marker(ControlRecordType::Commit)]),
+            producer_batch(7, TRANSACTIONAL, 1, 2, &[RECORD]),
+        ];
+
+        let aborted: &[(i64, i64)] = match second_abort {
+            true => &[(1, 7), (1, 0)],
+            false => &[(1, 0)],
+        };
+
+        PartitionLog::read(fetched(&batches, aborted, 8), 0).unwrap()
+    }
+
+    fn states(log: &mut PartitionLog, offsets: &[i64]) -> Vec<RecordState> {
+        offsets.iter().map(|o| log.advance(*o).0).collect()
+    }
+
+    #[test]
+    fn aborted_transactions_only_apply_to_their_producer() {
+        let mut log = interleaved(false);
+
+        assert_eq!(log.fetched_until, 8);
+        assert_eq!(
+            states(&mut log, &[0, 1, 2, 3, 4, 7]),
+            [
+                RecordState::Aborted,
+                RecordState::Aborted,
+                RecordState::Committed,
+                RecordState::Committed,
+                RecordState::NonTransactional,
+                RecordState::Committed,
+            ]
+        );
+    }
+
+    #[test]
+    fn abort_markers_close_the_aborted_range() {
+        let mut log = interleaved(true);
+
+        assert_eq!(
+            states(&mut log, &[0, 2, 7]),
+            [
+                RecordState::Aborted,
+                RecordState::Committed,
+                RecordState::Aborted
+            ]
+        );
+    }
+
+    #[test]
+    fn control_batches_become_markers_once() {
+        let mut log = interleaved(false);
+
+        assert!(log.advance(4).1.is_empty());
+
+        let (state, markers) = log.advance(7);
+
+        assert_eq!(state, RecordState::Committed);
+        assert_eq!(
+            markers
+                .iter()
+                .map(|m| (m.offset, m.producer_id, m.control))
+                .collect::<Vec<_>>(),
+            [
+                (5, 1, ControlRecordType::Abort),
+                (6, 2, ControlRecordType::Commit)
+            ]
+        );
+        assert!(log.advance(7).1.is_empty());
+    }
+
+    #[test]
+    fn passed_batches_are_pruned() {
+        let mut log = interleaved(false);
+
+        log.advance(4);
+
+        assert_eq!(
+            log.batches
+                .iter()
+                .map(|(base, ..)| *base)
+                .collect::<Vec<_>>(),
+            [4, 7]
+        );
+        assert_eq!(log.markers.len(), 2);
+
+        log.advance(8);
+
+        assert!(log.batches.is_empty());
+        assert!(log.markers.is_empty());
+        assert_eq!(log.advance(8).0, RecordState::Open);
+    }
+
+    #[test]
+    fn records_past_the_last_stable_offset_are_open_until_refreshed() {
+        let mut log = PartitionLog::read(fetched(&[], &[], 0), 0).unwrap();
+
+        assert_eq!(log.fetched_until, 0);
+        assert!(!log.is_stale(0));
+        assert_eq!(log.advance(0).0, RecordState::Open);
+
+        let batches = [producer_batch(0, TRANSACTIONAL, 1, 0, &[RECORD, RECORD])];
+        let mut log = PartitionLog::read(fetched(&batches, &[], 2), 0).unwrap();
+
+        assert!(!log.is_stale(1));
+        assert!(!log.is_stale(2));
+        assert_eq!(log.advance(1).0, RecordState::Committed);
+
+        log.last_stable_offset = -1;
+        assert!(log.is_stale(2));
+    }
+
+    #[test]
+    fn truncated_last_batch_is_left_for_the_next_fetch() {
+        let mut records = producer_batch(0, 0, -1, -1, &[RECORD]);
+        records.extend(&producer_batch(1, 0, -1, -1, &[RECORD])[..20]);
+
+        let log = PartitionLog::read(fetched(&[records], &[], 2), 0).unwrap();
+
+        assert_eq!(log.fetched_until, 1);
+        assert_eq!(log.batches.len(), 1);
+        assert!(log.is_stale(1));
+    }
+}
@@ -0,0 +1,45 @@
+use std::{fmt::Display, str::FromStr};
+
+use clap::{builder::PossibleValue, ValueEnum};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub(super) enum IsolationLevel {
+    #[default]
+    ReadCommitted,
+    ReadUncommitted,
+}
+
+impl Display for IsolationLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.to_possible_value()
+            .expect("no values are skipped")
+            .get_name()
+            .fmt(f)
+    }
+}
+
+impl FromStr for IsolationLevel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        for variant in Self::value_variants() {
+            if variant.to_possible_value().unwrap().matches(s, false) {
+                return Ok(*variant);
+            }
+        }
+        Err(format!("invalid variant: {s}"))
+    }
+}
+
+impl ValueEnum for IsolationLevel {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[Self::ReadCommitted, Self::ReadUncommitted]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        Some(match self {
+            Self::ReadCommitted => PossibleValue::new("read_committed"),
+            Self::ReadUncommitted => PossibleValue::new("read_uncommitted"),
+        })
+    }
+}
@@ -1,5 +1,9 @@
+use std::{collections::HashMap, time::Duration};
+
+use aborted::TransactionTracker;
 use clap::Args;
 use error_stack::ResultExt;
+use isolation::IsolationLevel;
 use log::trace;
 use rdkafka::{
     config::RDKafkaLogLevel,
@@ -14,11 +18,18 @@ use crate::{
         Context,
     },
     error::cli::consume::ConsumerError,
-    io::serde::Serde,
+    io::{protocol::ProtocolClient, serde::Serde},
 };
 
 use super::{GlobalArgs, Invoke};
 
+mod aborted;
+mod isolation;
+
+const METADATA_TIMEOUT: Duration = Duration::from_secs(10);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+const NO_LEADER: i32 = -1;
+
 const _REUSE_EXISTING_TOPIC_CONFIG: &str = "Found existing topic config, do you want to reuse?";
 
 #[derive(Args, Debug)]
@@ -37,6 +48,16 @@ pub(super) struct ConsumerCommand {
     key_serde: Option<Serde>,
     #[arg(short, long, help = "Value deserialiser.")]
     value_serde: Option<Serde>,
+    #[arg(
+        long,
+        help = "Isolation level, defaults to read_uncommitted with --show-aborted."
+    )]
+    isolation: Option<IsolationLevel>,
+    #[arg(
+        long,
+        help = "Mark records of aborted or open transactions and print transaction markers."
+    )]
+    show_aborted: bool,
 }
 
 impl Invoke for ConsumerCommand {
@@ -55,8 +76,15 @@ impl Invoke for ConsumerCommand {
             mut group,
             key_serde,
             value_serde,
+            isolation,
+            show_aborted,
         } = self;
 
+        let isolation = isolation.unwrap_or(match show_aborted {
+            true => IsolationLevel::ReadUncommitted,
+            false => IsolationLevel::default(),
+        });
+
         let profile = profile
             .or_else(|| {
                 ctx.topics
@@ -120,10 +148,34 @@ impl Invoke for ConsumerCommand {
             .set("group.id", group_id)
             .set("bootstrap.servers", cluster.bootstrap_servers.join(","))
             .set("auto.offset.reset", reset_strategy.to_string())
+            .set("isolation.level", isolation.to_string())
             .set_log_level(RDKafkaLogLevel::Emerg)
             .create::<BaseConsumer>()
             .change_context(ConsumerError::CreateConsumer)?;
 
+        let mut tracker = match show_aborted {
+            true => {
+                let metadata = consumer
+                    .fetch_metadata(Some(&topic), METADATA_TIMEOUT)
+                    .change_context(ConsumerError::Metadata(topic.clone()))?;
+
+                let leaders = metadata
+                    .topics()
+                    .iter()
+                    .filter(|t| t.error().is_none())
+                    .flat_map(|t| t.partitions())
+                    .filter(|p| p.leader() != NO_LEADER)
+                    .map(|p| (p.id(), p.leader()))
+                    .collect::<HashMap<_, _>>();
+
+                let client = ProtocolClient::connect(cluster, REQUEST_TIMEOUT)
+                    .change_context(ConsumerError::Protocol)?;
+
+                Some(TransactionTracker::new(client, topic.clone(), leaders))
+            }
+            false => None,
+        };
+
         let topics = vec![topic.as_str()];
 
         consumer
@@ -150,7 +202,22 @@ impl Invoke for ConsumerCommand {
                         None => "None".to_owned(),
                     };
 
-                    println!("{}: {}", key_display, value_display);
+                    let state = match tracker.as_mut() {
+                        Some(tracker) => {
+                            let (state, markers) =
+                                tracker.annotate(message.partition(), message.offset())?;
+
+                            markers.iter().for_each(|m| println!("{}", m));
+
+                            state.label()
+                        }
+                        None => None,
+                    };
+
+                    match state {
+                        Some(label) => println!("[{}] {}: {}", label, key_display, value_display),
+                        None => println!("{}: {}", key_display, value_display),
+                    }
                 }
             }
         }
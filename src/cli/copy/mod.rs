@@ -0,0 +1,556 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    str::FromStr,
+    time::Duration,
+};
+
+use clap::Args;
+use error_stack::{Report, ResultExt};
+use log::info;
+use rdkafka::{
+    client::Client,
+    config::RDKafkaLogLevel,
+    consumer::{BaseConsumer, Consumer},
+    error::KafkaError,
+    producer::{BaseProducer, BaseRecord, Producer},
+    ClientContext, Message, Offset, TopicPartitionList,
+};
+use regex::Regex;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::{
+    config::{clusters::NamedCluster, Context},
+    error::cli::copy::CopyError,
+    io::serde::Serde,
+};
+
+use super::{
+    util::{
+        parse_timestamp,
+        producer::{send_with_backoff, DeliveryContext},
+        PartitionMapping,
+    },
+    GlobalArgs, Invoke,
+};
+
+const CLIENT_TIMEOUT: Duration = Duration::from_millis(2500);
+const FLUSH_TIMEOUT: Duration = Duration::from_secs(60);
+
+#[derive(Clone, Debug)]
+struct TopicEndpoint {
+    cluster: Option<String>,
+    topic: String,
+}
+
+impl FromStr for TopicEndpoint {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (cluster, topic) = match s.split_once(':') {
+            Some((cluster, topic)) => (Some(cluster.to_owned()), topic),
+            None => (None, s),
+        };
+
+        if topic.is_empty() {
+            Err(format!("expected '[cluster:]topic', got: {s}"))?
+        }
+
+        Ok(Self {
+            cluster: cluster.filter(|c| !c.is_empty()),
+            topic: topic.to_owned(),
+        })
+    }
+}
+
+impl Display for TopicEndpoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.cluster {
+            Some(cluster) => write!(f, "{}:{}", cluster, self.topic),
+            None => f.write_str(&self.topic),
+        }
+    }
+}
+
+#[derive(Args, Debug)]
+pub(super) struct CopyCommand {
+    #[arg(
+        long,
+        help = "Source to copy from as '[cluster:]topic', uses the default cluster if omitted."
+    )]
+    from: TopicEndpoint,
+    #[arg(
+        long,
+        help = "Destination to copy to as '[cluster:]topic', uses the default cluster if omitted."
+    )]
+    to: TopicEndpoint,
+    #[arg(long, help = "First offset to copy in each partition.")]
+    start_offset: Option<i64>,
+    #[arg(long, help = "Offset to stop copying at in each partition, exclusive.")]
+    end_offset: Option<i64>,
+    #[arg(
+        long,
+        value_parser = parse_timestamp,
+        help = "Copy records from this time, as epoch millis or RFC 3339."
+    )]
+    start_time: Option<i64>,
+    #[arg(
+        long,
+        value_parser = parse_timestamp,
+        help = "Stop copying records at this time, as epoch millis or RFC 3339."
+    )]
+    end_time: Option<i64>,
+    #[arg(
+        short,
+        long,
+        help = "Only copy records whose deserialised key or value matches this regex."
+    )]
+    filter: Option<String>,
+    #[arg(long, help = "Key deserialiser for the source topic.")]
+    from_key_serde: Option<Serde>,
+    #[arg(long, help = "Value deserialiser for the source topic.")]
+    from_value_serde: Option<Serde>,
+    #[arg(long, help = "Key serialiser for the destination topic.")]
+    to_key_serde: Option<Serde>,
+    #[arg(long, help = "Value serialiser for the destination topic.")]
+    to_value_serde: Option<Serde>,
+    #[arg(
+        long,
+        conflicts_with = "partition_map",
+        help = "Write each record to the same partition number it was read from."
+    )]
+    same_partition: bool,
+    #[arg(
+        short,
+        long,
+        value_delimiter = ',',
+        help = "Map source partitions onto destination partitions as 'source=target'. Unmapped partitions keep their number."
+    )]
+    partition_map: Vec<PartitionMapping>,
+    #[arg(long, help = "Produce records with their original timestamps.")]
+    preserve_timestamps: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct CopiedPartition {
+    partition: i32,
+    start_offset: i64,
+    end_offset: i64,
+    copied: u64,
+    filtered: u64,
+}
+
+#[derive(Debug, Serialize)]
+struct CopySummary {
+    from: String,
+    to: String,
+    partitions: Vec<CopiedPartition>,
+}
+
+struct SerdePair {
+    from: Serde,
+    to: Serde,
+}
+
+impl SerdePair {
+    fn convert(&self, bytes: &[u8], offset: i64) -> error_stack::Result<Vec<u8>, CopyError> {
+        if self.from == self.to {
+            return Ok(bytes.to_vec());
+        }
+
+        let display = self
+            .from
+            .deserialise_into_string(bytes.to_vec())
+            .change_context(CopyError::Deserialise(offset))?;
+
+        self.to
+            .serialise_from_string(&display)
+            .change_context(CopyError::Serialise(offset))
+    }
+}
+
+impl Invoke for CopyCommand {
+    type E = CopyError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), CopyError> {
+        let Self {
+            from,
+            to,
+            start_offset,
+            end_offset,
+            start_time,
+            end_time,
+            filter,
+            from_key_serde,
+            from_value_serde,
+            to_key_serde,
+            to_value_serde,
+            same_partition,
+            partition_map,
+            preserve_timestamps,
+        } = self;
+
+        let filter = match filter {
+            None => None,
+            Some(s) => Some(Regex::new(&s).change_context(CopyError::CompileRegex(s))?),
+        };
+
+        let from_config = ctx.topics.topic(&from.topic);
+        let to_config = ctx.topics.topic(&to.topic);
+
+        let from_key_serde =
+            from_key_serde.unwrap_or(from_config.map(|c| c.key_serde).unwrap_or_default());
+        let from_value_serde =
+            from_value_serde.unwrap_or(from_config.map(|c| c.value_serde).unwrap_or_default());
+
+        let key_serdes = SerdePair {
+            from: from_key_serde,
+            to: to_key_serde.unwrap_or(to_config.map(|c| c.key_serde).unwrap_or(from_key_serde)),
+        };
+
+        let value_serdes = SerdePair {
+            from: from_value_serde,
+            to: to_value_serde
+                .unwrap_or(to_config.map(|c| c.value_serde).unwrap_or(from_value_serde)),
+        };
+
+        let NamedCluster(_, source_cluster) = ctx
+            .clusters
+            .cluster_config_or_default_or_select(from.cluster.as_deref())
+            .change_context(CopyError::FetchCluster("source"))?;
+
+        let NamedCluster(_, target_cluster) = ctx
+            .clusters
+            .cluster_config_or_default_or_select(to.cluster.as_deref())
+            .change_context(CopyError::FetchCluster("destination"))?;
+
+        let consumer = source_cluster
+            .client_config()
+            .set("group.id", Uuid::new_v4().to_string())
+            .set("enable.auto.commit", "false")
+            .set("enable.partition.eof", "true")
+            .set_log_level(RDKafkaLogLevel::Emerg)
+            .create::<BaseConsumer>()
+            .change_context(CopyError::CreateConsumer)?;
+
+        // murmur2_random matches the Java client's default partitioner, so keyed
+        // records land on the same partition as records produced by Java clients.
+        let producer = target_cluster
+            .client_config()
+            .set("enable.idempotence", "true")
+            .set("partitioner", "murmur2_random")
+            .set_log_level(RDKafkaLogLevel::Emerg)
+            .create_with_context::<_, BaseProducer<DeliveryContext>>(DeliveryContext::default())
+            .change_context(CopyError::CreateProducer)?;
+
+        let source_partitions = topic_partitions(consumer.client(), &from.topic)?;
+        let target_partitions = topic_partitions(producer.client(), &to.topic)?;
+
+        let mapping = partition_map
+            .iter()
+            .map(|m| (m.source, m.target))
+            .collect::<HashMap<_, _>>();
+
+        let explicit_partitions = same_partition || !mapping.is_empty();
+
+        let target_of = |partition: i32| {
+            explicit_partitions.then(|| mapping.get(&partition).copied().unwrap_or(partition))
+        };
+
+        if let Some(missing) = source_partitions
+            .iter()
+            .filter_map(|p| target_of(*p))
+            .find(|p| !target_partitions.contains(p))
+        {
+            Err(Report::new(CopyError::PartitionNotExists(missing)))?
+        }
+
+        let start_times = match start_time {
+            Some(time) => offsets_for_time(&consumer, &from.topic, &source_partitions, time)?,
+            None => HashMap::new(),
+        };
+
+        let end_times = match end_time {
+            Some(time) => offsets_for_time(&consumer, &from.topic, &source_partitions, time)?,
+            None => HashMap::new(),
+        };
+
+        let mut copied = HashMap::new();
+        let mut remaining = HashSet::new();
+        let mut tpl = TopicPartitionList::new();
+
+        for partition in source_partitions {
+            let (low, high) = consumer
+                .fetch_watermarks(&from.topic, partition, CLIENT_TIMEOUT)
+                .change_context(CopyError::Metadata(from.topic.clone()))?;
+
+            let start = [
+                start_offset,
+                resolve_time(start_times.get(&partition), high),
+            ]
+            .into_iter()
+            .flatten()
+            .fold(low, i64::max);
+
+            let end = [end_offset, resolve_time(end_times.get(&partition), high)]
+                .into_iter()
+                .flatten()
+                .fold(high, i64::min);
+
+            copied.insert(
+                partition,
+                CopiedPartition {
+                    partition,
+                    start_offset: start,
+                    end_offset: end.max(start),
+                    copied: 0,
+                    filtered: 0,
+                },
+            );
+
+            if end > start {
+                tpl.add_partition_offset(&from.topic, partition, Offset::Offset(start))
+                    .change_context(CopyError::ConsumerFailure)?;
+
+                remaining.insert(partition);
+            }
+        }
+
+        consumer
+            .assign(&tpl)
+            .change_context(CopyError::ConsumerFailure)?;
+
+        while !remaining.is_empty() {
+            let message = match consumer.poll(Duration::from_secs(1)) {
+                None => continue,
+                Some(Err(KafkaError::PartitionEOF(partition))) => {
+                    remaining.remove(&partition);
+                    continue;
+                }
+                Some(Err(e)) => Err(e).change_context(CopyError::ConsumerFailure)?,
+                Some(Ok(message)) => message,
+            };
+
+            let partition = message.partition();
+            let offset = message.offset();
+            let summary = copied
+                .get_mut(&partition)
+                .expect("Received message for unassigned partition.");
+
+            if offset >= summary.end_offset {
+                remaining.remove(&partition);
+                continue;
+            }
+
+            if offset >= summary.end_offset - 1 {
+                info!("Finished copying partition: {}", partition);
+
+                remaining.remove(&partition);
+            }
+
+            if let Some(filter) = &filter {
+                let matches = |serde: Serde, bytes: Option<&[u8]>| {
+                    bytes
+                        .map(|b| serde.deserialise_into_string(b.to_vec()))
+                        .transpose()
+                        .map(|s| s.is_some_and(|s| filter.is_match(&s)))
+                };
+
+                let key_matches = matches(key_serdes.from, message.key())
+                    .change_context(CopyError::Deserialise(offset))?;
+                let value_matches = matches(value_serdes.from, message.payload())
+                    .change_context(CopyError::Deserialise(offset))?;
+
+                if !key_matches && !value_matches {
+                    summary.filtered += 1;
+                    continue;
+                }
+            }
+
+            let key = message
+                .key()
+                .map(|k| key_serdes.convert(k, offset))
+                .transpose()?;
+
+            let value = message
+                .payload()
+                .map(|v| value_serdes.convert(v, offset))
+                .transpose()?;
+
+            let mut record = BaseRecord::<Vec<u8>, Vec<u8>>::to(&to.topic);
+
+            if let Some(target) = target_of(partition) {
+                record = record.partition(target);
+            }
+
+            if let Some(key) = &key {
+                record = record.key(key);
+            }
+
+            if let Some(value) = &value {
+                record = record.payload(value);
+            }
+
+            if let Some(headers) = message.headers() {
+                record = record.headers(headers.detach());
+            }
+
+            if let Some(timestamp) = message
+                .timestamp()
+                .to_millis()
+                .filter(|_| preserve_timestamps)
+            {
+                record = record.timestamp(timestamp);
+            }
+
+            send_with_backoff(&producer, record).change_context(CopyError::Produce(offset))?;
+
+            summary.copied += 1;
+        }
+
+        producer
+            .flush(FLUSH_TIMEOUT)
+            .change_context(CopyError::Delivery(producer.in_flight_count() as u64))?;
+
+        let failed = producer.context().failed();
+
+        if failed > 0 {
+            Err(Report::new(CopyError::Delivery(failed)))?
+        }
+
+        let mut partitions = copied.into_values().collect::<Vec<_>>();
+
+        partitions.sort_by_key(|p| p.partition);
+
+        let display = global_args
+            .out
+            .output_string(&CopySummary {
+                from: from.to_string(),
+                to: to.to_string(),
+                partitions,
+            })
+            .change_context(CopyError::Output)?;
+
+        println!("{}", display);
+
+        Ok(())
+    }
+}
+
+fn topic_partitions<C: ClientContext>(
+    client: &Client<C>,
+    topic: &str,
+) -> error_stack::Result<Vec<i32>, CopyError> {
+    let metadata = client
+        .fetch_metadata(Some(topic), CLIENT_TIMEOUT)
+        .change_context(CopyError::Metadata(topic.to_owned()))?;
+
+    Ok(metadata
+        .topics()
+        .iter()
+        .find(|t| t.name() == topic && t.error().is_none())
+        .ok_or(Report::new(CopyError::TopicNotExists(topic.to_owned())))?
+        .partitions()
+        .iter()
+        .map(|p| p.id())
+        .collect())
+}
+
+fn offsets_for_time(
+    consumer: &BaseConsumer,
+    topic: &str,
+    partitions: &[i32],
+    timestamp: i64,
+) -> error_stack::Result<HashMap<i32, Offset>, CopyError> {
+    let mut tpl = TopicPartitionList::new();
+
+    for partition in partitions {
+        tpl.add_partition_offset(topic, *partition, Offset::Offset(timestamp))
+            .change_context(CopyError::OffsetsForTimes(timestamp))?;
+    }
+
+    let offsets = consumer
+        .offsets_for_times(tpl, CLIENT_TIMEOUT)
+        .change_context(CopyError::OffsetsForTimes(timestamp))?;
+
+    Ok(offsets
+        .elements()
+        .iter()
+        .map(|e| (e.partition(), e.offset()))
+        .collect())
+}
+
+// Partitions without a record at or after the timestamp resolve to their high
+// watermark.
+fn resolve_time(offset: Option<&Offset>, high: i64) -> Option<i64> {
+    match offset {
+        Some(Offset::Offset(offset)) => Some(*offset),
+        Some(Offset::End) => Some(high),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rdkafka::Offset;
+
+    use super::{resolve_time, SerdePair};
+    use crate::{error::cli::copy::CopyError, io::serde::Serde};
+
+    #[test]
+    fn unsupported_serdes_fail_to_convert() {
+        for from in [Serde::Avro, Serde::AvroSchema, Serde::JsonSchema] {
+            let pair = SerdePair {
+                from,
+                to: Serde::String,
+            };
+            let report = pair.convert(b"value", 12).unwrap_err();
+
+            assert!(matches!(
+                report.current_context(),
+                CopyError::Deserialise(12)
+            ));
+        }
+
+        let pair = SerdePair {
+            from: Serde::String,
+            to: Serde::Avro,
+        };
+        let report = pair.convert(b"value", 12).unwrap_err();
+
+        assert!(matches!(report.current_context(), CopyError::Serialise(12)));
+    }
+
+    #[test]
+    fn matching_serdes_copy_bytes_unchanged() {
+        let pair = SerdePair {
+            from: Serde::Avro,
+            to: Serde::Avro,
+        };
+
+        assert_eq!(pair.convert(&[0, 1, 2], 0).unwrap(), [0, 1, 2]);
+    }
+
+    #[test]
+    fn supported_serdes_convert_through_strings() {
+        let pair = SerdePair {
+            from: Serde::String,
+            to: Serde::Int,
+        };
+
+        assert_eq!(pair.convert(b"258", 0).unwrap(), [0, 0, 1, 2]);
+    }
+
+    #[test]
+    fn time_bounds_past_the_last_record_resolve_to_high_watermark() {
+        assert_eq!(resolve_time(Some(&Offset::Offset(4)), 10), Some(4));
+        assert_eq!(resolve_time(Some(&Offset::End), 10), Some(10));
+        assert_eq!(resolve_time(Some(&Offset::Invalid), 10), None);
+        assert_eq!(resolve_time(None, 10), None);
+    }
+}
@@ -0,0 +1,447 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use clap::{Args, ValueHint};
+use error_stack::{Report, ResultExt};
+use serde::Serialize;
+
+use crate::{
+    config::Context,
+    error::{cli::dump_log::DumpLogError, io::SegmentError},
+    io::{
+        output::Output,
+        segment::{
+            index::{read_offset_index, read_time_index},
+            ControlRecordType, RecordBatch, SegmentReader,
+        },
+        serde::Serde,
+    },
+};
+
+use super::{GlobalArgs, Invoke};
+
+#[derive(Args, Debug)]
+pub(super) struct DumpLogCommand {
+    #[arg(
+        index = 1,
+        required = true,
+        value_hint = ValueHint::FilePath,
+        help = "Segment files to dump, either '.log', '.index' or '.timeindex'."
+    )]
+    files: Vec<PathBuf>,
+    #[arg(short, long, help = "Print each record, not only batch headers.")]
+    records: bool,
+    #[arg(short, long, requires = "records", help = "Key deserialiser.")]
+    key_serde: Option<Serde>,
+    #[arg(short, long, requires = "records", help = "Value deserialiser.")]
+    value_serde: Option<Serde>,
+}
+
+#[derive(Debug, Serialize)]
+struct RecordDump {
+    offset: i64,
+    timestamp: i64,
+    sequence: i32,
+    key_size: Option<usize>,
+    value_size: Option<usize>,
+    header_keys: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    control: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct BatchDump {
+    position: usize,
+    base_offset: i64,
+    last_offset: i64,
+    count: i32,
+    base_sequence: i32,
+    last_sequence: i32,
+    producer_id: i64,
+    producer_epoch: i16,
+    partition_leader_epoch: i32,
+    is_transactional: bool,
+    is_control: bool,
+    has_delete_horizon: bool,
+    timestamp_type: String,
+    max_timestamp: i64,
+    size: usize,
+    magic: i8,
+    compression: String,
+    crc: u32,
+    crc_valid: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    records: Option<Vec<RecordDump>>,
+}
+
+#[derive(Debug, Serialize)]
+struct IndexMismatch {
+    index_offset: i64,
+    index_value: i64,
+    log_value: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum FileDump {
+    Log {
+        file: String,
+        batches: Vec<BatchDump>,
+    },
+    OffsetIndex {
+        file: String,
+        entries: usize,
+        mismatches: Vec<IndexMismatch>,
+    },
+    TimeIndex {
+        file: String,
+        entries: usize,
+        mismatches: Vec<IndexMismatch>,
+        non_monotonic: Vec<i64>,
+    },
+}
+
+impl Invoke for DumpLogCommand {
+    type E = DumpLogError;
+
+    fn invoke(
+        self,
+        _: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), DumpLogError> {
+        let dumps = self
+            .files
+            .iter()
+            .map(|file| match file.extension().and_then(|e| e.to_str()) {
+                Some("log") => self.dump_log(file),
+                Some("index") => dump_offset_index(file),
+                Some("timeindex") => dump_time_index(file),
+                _ => Err(Report::new(DumpLogError::UnsupportedFile(
+                    file.display().to_string(),
+                ))),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if global_args.out == Output::Human {
+            dumps.iter().for_each(print_human);
+        } else {
+            let display = global_args
+                .out
+                .output_string(&dumps)
+                .change_context(DumpLogError::Output)?;
+
+            println!("{}", display);
+        }
+
+        Ok(())
+    }
+}
+
+impl DumpLogCommand {
+    fn dump_log(&self, file: &Path) -> error_stack::Result<FileDump, DumpLogError> {
+        let bytes = read_file(file)?;
+
+        let batches = SegmentReader::new(&bytes)
+            .map(|batch| {
+                let batch = batch
+                    .change_context_lazy(|| DumpLogError::Segment(file.display().to_string()))?;
+
+                self.dump_batch(&batch)
+                    .change_context_lazy(|| DumpLogError::Segment(file.display().to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(FileDump::Log {
+            file: file.display().to_string(),
+            batches,
+        })
+    }
+
+    fn dump_batch(&self, batch: &RecordBatch) -> error_stack::Result<BatchDump, SegmentError> {
+        let header = &batch.header;
+
+        let records = if self.records {
+            Some(
+                batch
+                    .records()?
+                    .into_iter()
+                    .map(|record| {
+                        let control = match (&record.key, header.is_control()) {
+                            (Some(key), true) => {
+                                ControlRecordType::from_key(key).map(|c| Some(c.to_string()))?
+                            }
+                            _ => None,
+                        };
+
+                        let deserialise = |serde: &Option<Serde>, bytes: &Option<Vec<u8>>| match (
+                            serde,
+                            bytes,
+                            header.is_control(),
+                        ) {
+                            (Some(serde), Some(bytes), false) => Some(
+                                serde
+                                    .deserialise_into_string(bytes.clone())
+                                    .unwrap_or(format!("<failed to deserialise as {}>", serde)),
+                            ),
+                            _ => None,
+                        };
+
+                        Ok(RecordDump {
+                            offset: record.offset,
+                            timestamp: record.timestamp,
+                            sequence: record.sequence,
+                            key_size: record.key.as_ref().map(Vec::len),
+                            value_size: record.value.as_ref().map(Vec::len),
+                            header_keys: record.headers.iter().map(|h| h.key.clone()).collect(),
+                            control,
+                            key: deserialise(&self.key_serde, &record.key),
+                            value: deserialise(&self.value_serde, &record.value),
+                        })
+                    })
+                    .collect::<error_stack::Result<Vec<_>, SegmentError>>()?,
+            )
+        } else {
+            None
+        };
+
+        Ok(BatchDump {
+            position: batch.position,
+            base_offset: header.base_offset,
+            last_offset: header.last_offset(),
+            count: header.record_count,
+            base_sequence: header.base_sequence,
+            last_sequence: header.last_sequence(),
+            producer_id: header.producer_id,
+            producer_epoch: header.producer_epoch,
+            partition_leader_epoch: header.partition_leader_epoch,
+            is_transactional: header.is_transactional(),
+            is_control: header.is_control(),
+            has_delete_horizon: header.has_delete_horizon(),
+            timestamp_type: header.timestamp_type().to_string(),
+            max_timestamp: header.max_timestamp,
+            size: header.size(),
+            magic: header.magic,
+            // Only decompressing the records needs a known codec.
+            compression: header
+                .compression()
+                .map(|c| c.to_string())
+                .unwrap_or_else(|_| format!("unknown({})", header.attributes & 0x07)),
+            crc: header.crc,
+            crc_valid: batch.crc_valid,
+            records,
+        })
+    }
+}
+
+fn read_file(file: &Path) -> error_stack::Result<Vec<u8>, DumpLogError> {
+    fs::read(file).change_context(DumpLogError::ReadFile(file.display().to_string()))
+}
+
+fn base_offset(file: &Path) -> error_stack::Result<i64, DumpLogError> {
+    file.file_stem()
+        .and_then(|s| s.to_str())
+        .and_then(|s| s.parse::<i64>().ok())
+        .ok_or(Report::new(DumpLogError::InvalidFileName(
+            file.display().to_string(),
+        )))
+        .attach_printable("Segment files are named after their base offset.")
+}
+
+fn read_log_batches<'a>(
+    file: &Path,
+    bytes: &'a [u8],
+) -> error_stack::Result<Vec<RecordBatch<'a>>, DumpLogError> {
+    SegmentReader::new(bytes)
+        .collect::<Result<Vec<_>, _>>()
+        .change_context(DumpLogError::Segment(file.display().to_string()))
+}
+
+fn dump_offset_index(file: &Path) -> error_stack::Result<FileDump, DumpLogError> {
+    let log_file = file.with_extension("log");
+    let log_bytes = read_file(&log_file)?;
+    let reader = SegmentReader::new(&log_bytes);
+
+    let entries = read_offset_index(&read_file(file)?, base_offset(file)?)
+        .change_context(DumpLogError::Segment(file.display().to_string()))?;
+
+    let mismatches = entries
+        .iter()
+        .filter_map(|entry| {
+            let log_offset = reader
+                .batch_at(entry.position)
+                .ok()
+                .map(|batch| batch.header.last_offset());
+
+            match log_offset {
+                Some(log_offset) if log_offset == entry.offset => None,
+                _ => Some(IndexMismatch {
+                    index_offset: entry.offset,
+                    index_value: entry.position as i64,
+                    log_value: log_offset,
+                }),
+            }
+        })
+        .collect();
+
+    Ok(FileDump::OffsetIndex {
+        file: file.display().to_string(),
+        entries: entries.len(),
+        mismatches,
+    })
+}
+
+fn dump_time_index(file: &Path) -> error_stack::Result<FileDump, DumpLogError> {
+    let log_file = file.with_extension("log");
+    let log_bytes = read_file(&log_file)?;
+    let batches = read_log_batches(&log_file, &log_bytes)?;
+
+    let entries = read_time_index(&read_file(file)?, base_offset(file)?)
+        .change_context(DumpLogError::Segment(file.display().to_string()))?;
+
+    let mismatches = entries
+        .iter()
+        .filter_map(|entry| {
+            let log_timestamp = batches
+                .iter()
+                .find(|b| {
+                    b.header.base_offset <= entry.offset && entry.offset <= b.header.last_offset()
+                })
+                .map(|b| b.header.max_timestamp);
+
+            match log_timestamp {
+                Some(log_timestamp) if log_timestamp == entry.timestamp => None,
+                _ => Some(IndexMismatch {
+                    index_offset: entry.offset,
+                    index_value: entry.timestamp,
+                    log_value: log_timestamp,
+                }),
+            }
+        })
+        .collect();
+
+    let non_monotonic = entries
+        .windows(2)
+        .filter(|pair| pair[1].timestamp < pair[0].timestamp || pair[1].offset < pair[0].offset)
+        .map(|pair| pair[1].offset)
+        .collect();
+
+    Ok(FileDump::TimeIndex {
+        file: file.display().to_string(),
+        entries: entries.len(),
+        mismatches,
+        non_monotonic,
+    })
+}
+
+fn print_human(dump: &FileDump) {
+    match dump {
+        FileDump::Log { file, batches } => {
+            println!("Dumping {}", file);
+
+            for batch in batches {
+                println!(
+                    "baseOffset: {} lastOffset: {} count: {} baseSequence: {} lastSequence: {} \
+                    producerId: {} producerEpoch: {} partitionLeaderEpoch: {} isTransactional: {} \
+                    isControl: {} position: {} {}: {} size: {} magic: {} compresscodec: {} \
+                    crc: {} isvalid: {}",
+                    batch.base_offset,
+                    batch.last_offset,
+                    batch.count,
+                    batch.base_sequence,
+                    batch.last_sequence,
+                    batch.producer_id,
+                    batch.producer_epoch,
+                    batch.partition_leader_epoch,
+                    batch.is_transactional,
+                    batch.is_control,
+                    batch.position,
+                    batch.timestamp_type,
+                    batch.max_timestamp,
+                    batch.size,
+                    batch.magic,
+                    batch.compression,
+                    batch.crc,
+                    batch.crc_valid,
+                );
+
+                for record in batch.records.iter().flatten() {
+                    let mut line = format!(
+                        "| offset: {} {}: {} keySize: {} valueSize: {} sequence: {} headerKeys: [{}]",
+                        record.offset,
+                        batch.timestamp_type,
+                        record.timestamp,
+                        record.key_size.map(|s| s as i64).unwrap_or(-1),
+                        record.value_size.map(|s| s as i64).unwrap_or(-1),
+                        record.sequence,
+                        record.header_keys.join(","),
+                    );
+
+                    if let Some(control) = &record.control {
+                        line.push_str(&format!(" endTxnMarker: {}", control));
+                    }
+
+                    if let Some(key) = &record.key {
+                        line.push_str(&format!(" key: {}", key));
+                    }
+
+                    if let Some(value) = &record.value {
+                        line.push_str(&format!(" payload: {}", value));
+                    }
+
+                    println!("{}", line);
+                }
+            }
+        }
+        FileDump::OffsetIndex {
+            file,
+            entries,
+            mismatches,
+        } => {
+            println!("Dumping {}", file);
+            println!("Checked {} index entries.", entries);
+
+            for mismatch in mismatches {
+                println!(
+                    "Mismatch in index: offset {} points to position {}, batch there ends at offset {}",
+                    mismatch.index_offset,
+                    mismatch.index_value,
+                    mismatch
+                        .log_value
+                        .map(|v| v.to_string())
+                        .unwrap_or("<no batch>".to_owned())
+                );
+            }
+        }
+        FileDump::TimeIndex {
+            file,
+            entries,
+            mismatches,
+            non_monotonic,
+        } => {
+            println!("Dumping {}", file);
+            println!("Checked {} time index entries.", entries);
+
+            for mismatch in mismatches {
+                println!(
+                    "Mismatch in time index: offset {} indexed at timestamp {}, batch max timestamp {}",
+                    mismatch.index_offset,
+                    mismatch.index_value,
+                    mismatch
+                        .log_value
+                        .map(|v| v.to_string())
+                        .unwrap_or("<no batch>".to_owned())
+                );
+            }
+
+            for offset in non_monotonic {
+                println!("Time index is not monotonic at offset {}", offset);
+            }
+        }
+    }
+}
@@ -0,0 +1,257 @@
+use std::{fs::File, io::BufReader, path::PathBuf, time::Duration};
+
+use clap::{Args, ValueHint};
+use error_stack::{Report, ResultExt};
+use rdkafka::{
+    config::RDKafkaLogLevel,
+    consumer::{BaseConsumer, Consumer},
+    error::RDKafkaErrorCode,
+    TopicPartitionList,
+};
+use serde::{Deserialize, Serialize};
+use tabled::Tabled;
+use uuid::Uuid;
+
+use crate::{
+    cli::{
+        topic::TopicFilter,
+        util::{display_replicas, get_user_input_confirmation, print_rows},
+        GlobalArgs, Invoke,
+    },
+    config::{clusters::NamedCluster, Context},
+    error::cli::leader_election::LeaderElectionError,
+    io::admin::{elect_leaders, ElectionType},
+};
+
+const CLIENT_TIMEOUT: Duration = Duration::from_millis(2500);
+const ELECTION_TIMEOUT: Duration = Duration::from_secs(30);
+const NO_LEADER: i32 = -1;
+
+#[derive(Debug, Args)]
+pub(super) struct LeaderElectionCommand {
+    #[arg(short, long, help = "Target cluster.")]
+    cluster: Option<String>,
+    #[arg(
+        long = "type",
+        default_value_t,
+        help = "Elect the preferred replica, or any replica for partitions without a leader."
+    )]
+    election_type: ElectionType,
+    #[command(flatten)]
+    filter: TopicFilter,
+    #[arg(
+        long,
+        value_hint = ValueHint::FilePath,
+        conflicts_with_all = ["exclude_internal", "exclude_prefix", "include_prefix", "regex"],
+        help = "JSON partitions file in the kafka-leader-election format."
+    )]
+    partitions_file: Option<PathBuf>,
+    #[arg(short, long, help = "Skip the confirmation prompt.")]
+    yes: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct PartitionsFile {
+    partitions: Vec<PartitionsFileEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PartitionsFileEntry {
+    topic: String,
+    partition: i32,
+}
+
+#[derive(Debug, Serialize, Tabled)]
+struct SkewedRow {
+    topic: String,
+    partition: i32,
+    #[tabled(display_with = "display_leader")]
+    leader: i32,
+    preferred_leader: i32,
+    #[tabled(display_with = "display_replicas")]
+    isr: Vec<i32>,
+}
+
+#[derive(Debug, Serialize, Tabled)]
+struct ElectionRow {
+    topic: String,
+    partition: i32,
+    result: String,
+    #[serde(skip)]
+    #[tabled(skip)]
+    failed: bool,
+}
+
+fn display_leader(leader: &i32) -> String {
+    match *leader {
+        NO_LEADER => "none".to_owned(),
+        leader => leader.to_string(),
+    }
+}
+
+impl Invoke for LeaderElectionCommand {
+    type E = LeaderElectionError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), LeaderElectionError> {
+        let Self {
+            cluster,
+            election_type,
+            filter,
+            partitions_file,
+            yes,
+        } = self;
+
+        let NamedCluster(cluster_name, cluster_config) = ctx
+            .clusters
+            .cluster_config_or_default_or_select(cluster.as_deref())
+            .change_context(LeaderElectionError::FetchCluster)?;
+
+        let consumer = cluster_config
+            .client_config()
+            .set("group.id", Uuid::new_v4().to_string())
+            .set_log_level(RDKafkaLogLevel::Emerg)
+            .create::<BaseConsumer>()
+            .change_context(LeaderElectionError::CreateClient)?;
+
+        let metadata = consumer
+            .fetch_metadata(None, CLIENT_TIMEOUT)
+            .change_context(LeaderElectionError::Metadata)?;
+
+        let is_match = filter
+            .matcher()
+            .change_context(LeaderElectionError::Filter)?;
+
+        let mut partitions = metadata
+            .topics()
+            .iter()
+            .filter(|t| t.error().is_none() && is_match(t.name()))
+            .flat_map(|t| t.partitions().iter().map(|p| (t.name(), p)))
+            .collect::<Vec<_>>();
+
+        if let Some(path) = &partitions_file {
+            let file = File::open(path)
+                .change_context(LeaderElectionError::ReadFile(path.display().to_string()))?;
+
+            let requested: PartitionsFile = serde_json::from_reader(BufReader::new(file))
+                .change_context(LeaderElectionError::ReadFile(path.display().to_string()))?;
+
+            if let Some(missing) = requested.partitions.iter().find(|r| {
+                !partitions
+                    .iter()
+                    .any(|(t, p)| *t == r.topic && p.id() == r.partition)
+            }) {
+                Err(Report::new(LeaderElectionError::PartitionNotExists(
+                    missing.topic.clone(),
+                    missing.partition,
+                )))?
+            }
+
+            partitions.retain(|(t, p)| {
+                requested
+                    .partitions
+                    .iter()
+                    .any(|r| r.topic == *t && r.partition == p.id())
+            });
+        }
+
+        let mut skewed = partitions
+            .iter()
+            .filter(|(_, p)| p.replicas().first() != Some(&p.leader()))
+            .map(|(t, p)| SkewedRow {
+                topic: t.to_string(),
+                partition: p.id(),
+                leader: p.leader(),
+                preferred_leader: p.replicas().first().copied().unwrap_or(NO_LEADER),
+                isr: p.isr().to_vec(),
+            })
+            .collect::<Vec<_>>();
+
+        skewed.sort_by(|a, b| (&a.topic, a.partition).cmp(&(&b.topic, b.partition)));
+
+        print_rows(
+            global_args.out,
+            &skewed,
+            Some(format!(
+                "Not on preferred leader: {} of {} partitions, Cluster: {}",
+                skewed.len(),
+                partitions.len(),
+                cluster_name
+            )),
+            LeaderElectionError::Output,
+        )?;
+
+        // Preferred elections only need the skewed partitions, while unclean
+        // elections only apply to partitions that have lost their leader.
+        let targets = skewed
+            .iter()
+            .filter(|s| election_type == ElectionType::Preferred || s.leader == NO_LEADER)
+            .collect::<Vec<_>>();
+
+        if targets.is_empty() {
+            return Ok(());
+        }
+
+        if election_type == ElectionType::Unclean
+            && !yes
+            && !get_user_input_confirmation(&format!(
+                "Unclean election may lose committed records. Elect leaders for {} partitions on '{}'?",
+                targets.len(),
+                cluster_name
+            ))
+            .change_context(LeaderElectionError::InputError("confirmation"))?
+        {
+            return Ok(());
+        }
+
+        let mut tpl = TopicPartitionList::new();
+
+        for target in &targets {
+            tpl.add_partition(&target.topic, target.partition);
+        }
+
+        let mut results = elect_leaders(consumer.client(), election_type, &tpl, ELECTION_TIMEOUT)
+            .change_context(LeaderElectionError::Election)?
+            .into_iter()
+            .map(|r| {
+                let (result, failed) = match r.error {
+                    None => ("elected".to_owned(), false),
+                    Some(RDKafkaErrorCode::ElectionNotNeeded) => ("not needed".to_owned(), false),
+                    Some(code) => (code.to_string(), true),
+                };
+
+                ElectionRow {
+                    topic: r.topic,
+                    partition: r.partition,
+                    result,
+                    failed,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        results.sort_by(|a, b| (&a.topic, a.partition).cmp(&(&b.topic, b.partition)));
+
+        let failed = results.iter().filter(|r| r.failed).count();
+
+        print_rows(
+            global_args.out,
+            &results,
+            Some(format!(
+                "Elected: {}, Failed: {}, Cluster: {}",
+                results.len() - failed,
+                failed,
+                cluster_name
+            )),
+            LeaderElectionError::Output,
+        )?;
+
+        if failed > 0 {
+            Err(Report::new(LeaderElectionError::PartitionsFailed(failed)))?
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,261 @@
+use std::{cmp::Reverse, collections::BTreeMap, time::Duration};
+
+use clap::Args;
+use error_stack::{Report, ResultExt};
+use serde::Serialize;
+use tabled::{
+    settings::{Panel, Style},
+    Table, Tabled,
+};
+
+use crate::{
+    cli::{topic::TopicFilter, GlobalArgs, Invoke},
+    config::{clusters::NamedCluster, Context},
+    error::cli::log_dirs::LogDirsError,
+    io::{
+        output::Output,
+        protocol::{error_string, log_dirs::DescribeLogDirsRequest, ProtocolClient},
+    },
+};
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+const SIZE_UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+
+#[derive(Debug, Args)]
+pub(super) struct LogDirsCommand {
+    #[arg(short, long, help = "Target cluster.")]
+    cluster: Option<String>,
+    #[arg(
+        short,
+        long,
+        value_delimiter = ',',
+        help = "Broker ids to query, comma separated. Defaults to every broker."
+    )]
+    brokers: Vec<i32>,
+    #[command(flatten)]
+    filter: TopicFilter,
+    #[arg(
+        short,
+        long,
+        help = "Show every partition replica instead of totals per topic."
+    )]
+    partitions: bool,
+    #[arg(short, long, help = "Sort by size, largest first, instead of by name.")]
+    sort_by_size: bool,
+}
+
+#[derive(Debug, Serialize, Tabled)]
+struct DirRow {
+    broker: i32,
+    log_dir: String,
+    #[tabled(display_with = "display_error")]
+    error: Option<String>,
+    replicas: usize,
+    #[tabled(display_with = "display_size")]
+    size: i64,
+}
+
+#[derive(Debug, Default, Serialize, Tabled)]
+struct TopicRow {
+    topic: String,
+    replicas: usize,
+    #[tabled(display_with = "display_size")]
+    size: i64,
+    future_replicas: usize,
+    max_future_lag: i64,
+}
+
+#[derive(Debug, Serialize, Tabled)]
+struct PartitionRow {
+    broker: i32,
+    log_dir: String,
+    topic: String,
+    partition: i32,
+    #[tabled(display_with = "display_size")]
+    size: i64,
+    offset_lag: i64,
+    future: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct LogDirsReport {
+    log_dirs: Vec<DirRow>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    topics: Option<Vec<TopicRow>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    partitions: Option<Vec<PartitionRow>>,
+}
+
+fn display_error(error: &Option<String>) -> String {
+    error.clone().unwrap_or_default()
+}
+
+fn display_size(size: &i64) -> String {
+    let mut value = *size as f64;
+    let mut unit = 0;
+
+    while value >= 1024.0 && unit < SIZE_UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    match unit {
+        0 => format!("{} {}", size, SIZE_UNITS[0]),
+        _ => format!("{:.1} {}", value, SIZE_UNITS[unit]),
+    }
+}
+
+impl Invoke for LogDirsCommand {
+    type E = LogDirsError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), LogDirsError> {
+        let Self {
+            cluster,
+            brokers,
+            filter,
+            partitions,
+            sort_by_size,
+        } = self;
+
+        let NamedCluster(cluster_name, cluster_config) = ctx
+            .clusters
+            .cluster_config_or_default_or_select(cluster.as_deref())
+            .change_context(LogDirsError::FetchCluster)?;
+
+        let is_match = filter.matcher().change_context(LogDirsError::Filter)?;
+
+        let mut client = ProtocolClient::connect(cluster_config, REQUEST_TIMEOUT)
+            .change_context(LogDirsError::Connect)?;
+
+        if let Some(missing) = brokers.iter().find(|b| !client.brokers().contains_key(b)) {
+            Err(Report::new(LogDirsError::BrokerNotExists(*missing)))?
+        }
+
+        let brokers = match brokers.is_empty() {
+            true => client.brokers().keys().copied().collect(),
+            false => brokers,
+        };
+
+        let mut dir_rows = Vec::new();
+        let mut partition_rows = Vec::new();
+
+        // Each broker only reports the log dirs it hosts, so every broker in
+        // scope has to be asked directly.
+        for broker in brokers {
+            let log_dirs = client
+                .send(broker, &DescribeLogDirsRequest { topics: None })
+                .change_context(LogDirsError::Protocol(broker))?;
+
+            for log_dir in log_dirs {
+                // Dir totals cover every replica, only the detail is filtered.
+                dir_rows.push(DirRow {
+                    broker,
+                    log_dir: log_dir.log_dir.clone(),
+                    error: error_string(log_dir.error_code),
+                    replicas: log_dir.partitions.len(),
+                    size: log_dir.partitions.iter().map(|p| p.size).sum(),
+                });
+
+                let replicas = log_dir
+                    .partitions
+                    .into_iter()
+                    .filter(|p| is_match(&p.topic))
+                    .map(|p| PartitionRow {
+                        broker,
+                        log_dir: log_dir.log_dir.clone(),
+                        topic: p.topic,
+                        partition: p.partition,
+                        size: p.size,
+                        offset_lag: p.offset_lag,
+                        future: p.is_future,
+                    })
+                    .collect::<Vec<_>>();
+
+                partition_rows.extend(replicas);
+            }
+        }
+
+        let total_size = partition_rows.iter().map(|p| p.size).sum::<i64>();
+        let total_replicas = partition_rows.len();
+
+        let (topic_rows, partition_rows) = match partitions {
+            true => {
+                match sort_by_size {
+                    true => partition_rows.sort_by_key(|r| Reverse(r.size)),
+                    false => partition_rows.sort_by(|a, b| {
+                        (&a.topic, a.partition, a.broker).cmp(&(&b.topic, b.partition, b.broker))
+                    }),
+                }
+
+                (None, Some(partition_rows))
+            }
+            false => {
+                let mut topics = BTreeMap::<String, TopicRow>::new();
+
+                for row in partition_rows {
+                    let topic = topics.entry(row.topic.clone()).or_default();
+
+                    topic.replicas += 1;
+                    topic.size += row.size;
+
+                    if row.future {
+                        topic.future_replicas += 1;
+                        topic.max_future_lag = topic.max_future_lag.max(row.offset_lag);
+                    }
+                }
+
+                let mut topic_rows = topics
+                    .into_iter()
+                    .map(|(topic, row)| TopicRow { topic, ..row })
+                    .collect::<Vec<_>>();
+
+                if sort_by_size {
+                    topic_rows.sort_by_key(|r| Reverse(r.size));
+                }
+
+                (Some(topic_rows), None)
+            }
+        };
+
+        let display = match global_args.out {
+            Output::Human => {
+                let dirs = Table::new(&dir_rows)
+                    .with(Style::modern_rounded())
+                    .to_string();
+
+                let footer = format!(
+                    "Replicas: {}, Size: {}, Cluster: {}",
+                    total_replicas,
+                    display_size(&total_size),
+                    cluster_name
+                );
+
+                let mut detail = match (&topic_rows, &partition_rows) {
+                    (Some(rows), _) => Table::new(rows),
+                    (_, rows) => Table::new(rows.iter().flatten()),
+                };
+
+                detail
+                    .with(Style::modern_rounded())
+                    .with(Panel::footer(footer));
+
+                format!("{}\n{}", dirs, detail)
+            }
+            out => out
+                .output_string(&LogDirsReport {
+                    log_dirs: dir_rows,
+                    topics: topic_rows,
+                    partitions: partition_rows,
+                })
+                .change_context(LogDirsError::Output)?,
+        };
+
+        println!("{}", display);
+
+        Ok(())
+    }
+}
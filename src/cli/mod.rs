@@ -1,15 +1,32 @@
 use std::process::exit;
 
 use acl::AclCommand;
+use apply::ApplyCommand;
+use backup::BackupCommand;
+use broker::BrokerCommand;
 use clap::{Parser, Subcommand};
+use cluster::ClusterCommand;
 use completions::CompletionsCommand;
 use config::ConfigCommand;
+use connect::ConnectCommand;
 use consumer::ConsumerCommand;
+use copy::CopyCommand;
+use dump_log::DumpLogCommand;
 use error_stack::ResultExt;
 use group::GroupCommand;
+use leader_election::LeaderElectionCommand;
+use log_dirs::LogDirsCommand;
+use perf::PerfCommand;
 use producer::ProducerCommand;
+use quota::QuotaCommand;
+use reassign::ReassignCommand;
+use restore::RestoreCommand;
+use schema::SchemaCommand;
 use simplelog::LevelFilter;
+use top::TopCommand;
 use topic::TopicCommand;
+use tx::TxCommand;
+use verify::VerifyCommand;
 
 use crate::{
     config::Context,
@@ -19,13 +36,30 @@ use crate::{
 };
 
 mod acl;
+mod apply;
+mod backup;
+mod broker;
+mod cluster;
 mod completions;
 mod config;
+mod connect;
 mod consumer;
+mod copy;
+mod dump_log;
 mod group;
+mod leader_election;
+mod log_dirs;
+mod perf;
 mod producer;
+mod quota;
+mod reassign;
+mod restore;
+mod schema;
+mod top;
 mod topic;
+mod tx;
 pub mod util;
+mod verify;
 
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None, author)]
@@ -47,45 +81,64 @@ pub struct Cli {
 enum RootCommand {
     #[command(about = "Manage Kafka ACLS")]
     Acl(AclCommand),
+    #[command(about = "Plan and apply declarative topic specs")]
+    Apply(ApplyCommand),
+    #[command(about = "Back up a topic to local files")]
+    Backup(BackupCommand),
+    #[command(about = "Inspect brokers and manage their dynamic configs")]
+    Broker(BrokerCommand),
+    #[command(about = "Inspect API versions, features and the KRaft metadata quorum")]
+    Cluster(ClusterCommand),
     #[command(about = "Manage kcli configurations")]
     Config(ConfigCommand),
+    #[command(about = "Manage Kafka Connect connectors and tasks")]
+    Connect(ConnectCommand),
     #[command(about = "Consumer messages from a topic")]
     Consume(ConsumerCommand),
+    #[command(about = "Copy messages between topics and clusters")]
+    Copy(CopyCommand),
+    #[command(about = "Dump Kafka log segment and index files")]
+    DumpLog(DumpLogCommand),
     #[command(about = "Manage Kafka consumer group")]
     Group(GroupCommand),
+    #[command(about = "Move partition leadership back to preferred replicas")]
+    LeaderElection(LeaderElectionCommand),
+    #[command(about = "Show log dir usage per broker, topic and partition")]
+    LogDirs(LogDirsCommand),
+    #[command(about = "Run producer and consumer performance tests")]
+    Perf(PerfCommand),
     #[command(about = "Produce messages to a topic")]
     Produce(ProducerCommand),
+    #[command(about = "Manage client quotas for users, client ids and ips")]
+    Quota(QuotaCommand),
+    #[command(about = "Plan and run partition replica reassignments")]
+    Reassign(ReassignCommand),
+    #[command(about = "Restore a topic backup from local files")]
+    Restore(RestoreCommand),
+    #[command(about = "Manage schema registry subjects and compatibility")]
+    Schema(SchemaCommand),
+    #[command(about = "Live dashboard of cluster health and throughput")]
+    Top(TopCommand),
     #[command(about = "Manage Kafka topics")]
     Topic(TopicCommand),
+    #[command(about = "Inspect and abort transactions")]
+    Tx(TxCommand),
+    #[command(about = "Produce and check sequenced records for correctness testing")]
+    Verify(VerifyCommand),
     #[command(about = "Print out shell completions")]
     Completions(CompletionsCommand),
 }
 /*
-kafka-broker-api-versions.sh
 kafka-cluster.sh
 kafka-configs.sh
-kafka-consumer-perf-test.sh
 kafka-delegation-tokens.sh
-kafka-delete-records.sh
-kafka-dump-log.sh
-kafka-e2e-latency.sh
-kafka-features.sh
-kafka-get-offsets.sh
 kafka-jmx.sh
-kafka-leader-election.sh
-kafka-log-dirs.sh
-kafka-metadata-quorum.sh
 kafka-metadata-shell.sh
 kafka-mirror-maker.sh
-kafka-producer-perf-test.sh
-kafka-reassign-partitions.sh
 kafka-replica-verification.sh
 kafka-run-class.sh
 kafka-storage.sh
 kafka-streams-application-reset.sh
-kafka-transactions.sh
-kafka-verifiable-consumer.sh
-kafka-verifiable-producer.sh
 */
 
 pub trait Invoke {
@@ -126,18 +179,57 @@ impl Cli {
 
         match command {
             RootCommand::Acl(command) => command.execute(),
+            RootCommand::Apply(command) => command
+                .invoke(&mut ctx, &global_args)
+                .change_context(ExecutionError::ExecutionFailed("apply")),
+            RootCommand::Backup(command) => command
+                .invoke(&mut ctx, &global_args)
+                .change_context(ExecutionError::ExecutionFailed("backup")),
+            RootCommand::Broker(command) => command.invoke(&mut ctx, &global_args),
+            RootCommand::Cluster(command) => command.invoke(&mut ctx, &global_args),
             RootCommand::Config(command) => command.invoke(&mut ctx, &global_args),
+            RootCommand::Connect(command) => command.invoke(&mut ctx, &global_args),
             RootCommand::Consume(command) => command
                 .invoke(&mut ctx, &global_args)
                 .change_context(ExecutionError::ExecutionFailed("consume")),
+            RootCommand::Copy(command) => command
+                .invoke(&mut ctx, &global_args)
+                .change_context(ExecutionError::ExecutionFailed("copy")),
+            RootCommand::DumpLog(command) => command
+                .invoke(&mut ctx, &global_args)
+                .change_context(ExecutionError::ExecutionFailed("dump-log")),
             RootCommand::Group(command) => command.execute(),
-            RootCommand::Produce(command) => command.execute(),
+            RootCommand::LeaderElection(command) => command
+                .invoke(&mut ctx, &global_args)
+                .change_context(ExecutionError::ExecutionFailed("leader-election")),
+            RootCommand::LogDirs(command) => command
+                .invoke(&mut ctx, &global_args)
+                .change_context(ExecutionError::ExecutionFailed("log-dirs")),
+            RootCommand::Perf(command) => command.invoke(&mut ctx, &global_args),
+            RootCommand::Produce(command) => command
+                .invoke(&mut ctx, &global_args)
+                .change_context(ExecutionError::ExecutionFailed("produce")),
+            RootCommand::Quota(command) => command.invoke(&mut ctx, &global_args),
+            RootCommand::Reassign(command) => command.invoke(&mut ctx, &global_args),
+            RootCommand::Restore(command) => command
+                .invoke(&mut ctx, &global_args)
+                .change_context(ExecutionError::ExecutionFailed("restore")),
+            RootCommand::Schema(command) => command.invoke(&mut ctx, &global_args),
+            RootCommand::Top(command) => command
+                .invoke(&mut ctx, &global_args)
+                .change_context(ExecutionError::ExecutionFailed("top")),
             RootCommand::Topic(command) => command.invoke(&mut ctx, &global_args),
+            RootCommand::Tx(command) => command.invoke(&mut ctx, &global_args),
+            RootCommand::Verify(command) => command.invoke(&mut ctx, &global_args),
             RootCommand::Completions(command) => command.execute(),
         }
         .inspect_err(handle_expect_report);
 
         ctx.write_out().inspect_err(handle_expect_report);
+
+        if ctx.exit_code != 0 {
+            exit(ctx.exit_code);
+        }
     }
 }
 
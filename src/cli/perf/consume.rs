@@ -0,0 +1,363 @@
+use std::{
+    collections::BTreeMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use clap::{
+    builder::{PossibleValuesParser, TypedValueParser},
+    Args, ValueEnum,
+};
+use error_stack::{Report, ResultExt};
+use log::info;
+use rdkafka::{
+    config::RDKafkaLogLevel,
+    consumer::{BaseConsumer, Consumer, ConsumerContext, Rebalance},
+    ClientContext, Message, Offset, TopicPartitionList,
+};
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::{
+    cli::{util::parse_key_value, GlobalArgs, Invoke},
+    config::{clusters::NamedCluster, Context},
+    error::cli::perf::PerfConsumeError,
+    io::serde::Serde,
+};
+
+const CLIENT_TIMEOUT: Duration = Duration::from_millis(2500);
+const POLL_TIMEOUT: Duration = Duration::from_millis(100);
+const REPORT_INTERVAL: Duration = Duration::from_secs(5);
+const MEGABYTE: f64 = 1024.0 * 1024.0;
+
+#[derive(Debug, Args)]
+pub(super) struct PerfConsume {
+    #[arg(index = 1, help = "Topic to consume from.")]
+    topic: String,
+    #[arg(short, long, help = "Target cluster to consume from.")]
+    cluster: Option<String>,
+    #[arg(
+        short,
+        long,
+        required_unless_present = "duration",
+        help = "Number of records to consume."
+    )]
+    num_records: Option<u64>,
+    #[arg(short, long, help = "Consume for this many seconds.")]
+    duration: Option<u64>,
+    #[arg(
+        short,
+        long,
+        conflicts_with = "partitions",
+        help = "Join this consumer group instead of assigning partitions manually."
+    )]
+    group: Option<String>,
+    #[arg(
+        short,
+        long,
+        value_delimiter = ',',
+        help = "Partitions to assign, defaults to all partitions."
+    )]
+    partitions: Vec<i32>,
+    #[arg(
+        short,
+        long,
+        value_parser = supported_serde(),
+        help = "Deserialise keys with this serde."
+    )]
+    key_serde: Option<Serde>,
+    #[arg(
+        short,
+        long,
+        value_parser = supported_serde(),
+        help = "Deserialise values with this serde."
+    )]
+    value_serde: Option<Serde>,
+    #[arg(
+        long,
+        default_value_t = 10,
+        help = "Stop after this many seconds without receiving a record."
+    )]
+    timeout: u64,
+    #[arg(
+        short = 'X',
+        long = "consumer-config",
+        value_parser = parse_key_value,
+        help = "Additional consumer configuration as 'key=value'."
+    )]
+    consumer_config: Vec<(String, String)>,
+}
+
+// Unsupported serdes would fail every record, so they are rejected before the
+// run starts rather than counted as decode failures.
+fn supported_serde() -> impl TypedValueParser<Value = Serde> {
+    PossibleValuesParser::new(
+        Serde::value_variants()
+            .iter()
+            .filter(|s| s.is_supported())
+            .filter_map(Serde::to_possible_value),
+    )
+    .map(|s| s.parse::<Serde>().expect("Parsed from possible values."))
+}
+
+#[derive(Default)]
+struct RebalanceContext {
+    first_assignment: Mutex<Option<Instant>>,
+}
+
+impl ClientContext for RebalanceContext {}
+
+impl ConsumerContext for RebalanceContext {
+    fn post_rebalance(&self, rebalance: &Rebalance<'_>) {
+        if let Rebalance::Assign(_) = rebalance {
+            self.first_assignment
+                .lock()
+                .expect("Rebalance lock poisoned.")
+                .get_or_insert_with(Instant::now);
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+struct PartitionStats {
+    records: u64,
+    bytes: u64,
+}
+
+#[derive(Debug, Serialize)]
+struct DecodeStats {
+    failures: u64,
+    total_ms: f64,
+    avg_us_per_record: f64,
+}
+
+#[derive(Debug, Serialize)]
+struct PerfConsumeResult {
+    topic: String,
+    group: Option<String>,
+    records: u64,
+    bytes: u64,
+    elapsed_secs: f64,
+    rebalance_ms: Option<f64>,
+    fetch_secs: f64,
+    records_per_sec: f64,
+    mb_per_sec: f64,
+    fetch_records_per_sec: f64,
+    fetch_mb_per_sec: f64,
+    decode: Option<DecodeStats>,
+    partitions: BTreeMap<i32, PartitionStats>,
+}
+
+impl Invoke for PerfConsume {
+    type E = PerfConsumeError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), PerfConsumeError> {
+        let Self {
+            topic,
+            cluster,
+            num_records,
+            duration,
+            group,
+            partitions,
+            key_serde,
+            value_serde,
+            timeout,
+            consumer_config,
+        } = self;
+
+        let NamedCluster(_, cluster_config) = ctx
+            .clusters
+            .cluster_config_or_default_or_select(cluster.as_deref())
+            .change_context(PerfConsumeError::FetchCluster)?;
+
+        let mut client_config = cluster_config.client_config();
+
+        client_config
+            .set(
+                "group.id",
+                group.clone().unwrap_or(Uuid::new_v4().to_string()),
+            )
+            .set("enable.auto.commit", "false")
+            .set("auto.offset.reset", "earliest")
+            .set_log_level(RDKafkaLogLevel::Emerg);
+
+        for (key, value) in consumer_config {
+            client_config.set(key, value);
+        }
+
+        let consumer = client_config
+            .create_with_context::<_, BaseConsumer<RebalanceContext>>(RebalanceContext::default())
+            .change_context(PerfConsumeError::CreateConsumer)?;
+
+        let start = Instant::now();
+
+        if group.is_some() {
+            consumer
+                .subscribe(&[&topic])
+                .change_context(PerfConsumeError::ConsumerFailure)?;
+        } else {
+            let metadata = consumer
+                .fetch_metadata(Some(&topic), CLIENT_TIMEOUT)
+                .change_context(PerfConsumeError::Metadata(topic.clone()))?;
+
+            let available = metadata
+                .topics()
+                .iter()
+                .find(|t| t.name() == topic && t.error().is_none())
+                .ok_or(Report::new(PerfConsumeError::TopicNotExists(topic.clone())))?
+                .partitions()
+                .iter()
+                .map(|p| p.id())
+                .collect::<Vec<_>>();
+
+            let partitions = if partitions.is_empty() {
+                available
+            } else {
+                if let Some(missing) = partitions.iter().find(|p| !available.contains(p)) {
+                    Err(Report::new(PerfConsumeError::PartitionNotExists(*missing)))?
+                }
+
+                partitions
+            };
+
+            let mut tpl = TopicPartitionList::new();
+
+            for partition in partitions {
+                tpl.add_partition_offset(&topic, partition, Offset::Beginning)
+                    .change_context(PerfConsumeError::ConsumerFailure)?;
+            }
+
+            consumer
+                .assign(&tpl)
+                .change_context(PerfConsumeError::ConsumerFailure)?;
+        }
+
+        let duration = duration.map(Duration::from_secs);
+        let timeout = Duration::from_secs(timeout);
+        let decode = key_serde.is_some() || value_serde.is_some();
+
+        let mut stats = BTreeMap::<i32, PartitionStats>::new();
+        let mut records = 0;
+        let mut bytes = 0;
+        let mut decode_time = Duration::ZERO;
+        let mut decode_failures = 0;
+        let mut last_record = Instant::now();
+        let mut last_report = start;
+
+        while num_records.is_none_or(|n| records < n)
+            && duration.is_none_or(|d| start.elapsed() < d)
+            && last_record.elapsed() < timeout
+        {
+            let message = match consumer.poll(POLL_TIMEOUT) {
+                None => continue,
+                Some(Err(e)) => Err(e).change_context(PerfConsumeError::ConsumerFailure)?,
+                Some(Ok(message)) => message,
+            };
+
+            last_record = Instant::now();
+
+            let size = message.key().map_or(0, <[u8]>::len) as u64
+                + message.payload().map_or(0, <[u8]>::len) as u64;
+
+            if decode {
+                let decode_start = Instant::now();
+
+                let key_result = match (key_serde, message.key()) {
+                    (Some(serde), Some(key)) => serde.deserialise_into_string(key.to_vec()).err(),
+                    _ => None,
+                };
+
+                let value_result = match (value_serde, message.payload()) {
+                    (Some(serde), Some(value)) => {
+                        serde.deserialise_into_string(value.to_vec()).err()
+                    }
+                    _ => None,
+                };
+
+                decode_time += decode_start.elapsed();
+
+                if key_result.is_some() || value_result.is_some() {
+                    decode_failures += 1;
+                }
+            }
+
+            let partition = stats.entry(message.partition()).or_default();
+
+            partition.records += 1;
+            partition.bytes += size;
+
+            records += 1;
+            bytes += size;
+
+            if last_report.elapsed() >= REPORT_INTERVAL {
+                let elapsed = start.elapsed().as_secs_f64();
+
+                info!(
+                    "{} records consumed, {:.1} records/sec, {:.2} MB/sec",
+                    records,
+                    records as f64 / elapsed,
+                    bytes as f64 / MEGABYTE / elapsed
+                );
+
+                last_report = Instant::now();
+            }
+        }
+
+        // Time spent waiting for the idle timeout is not fetch time.
+        let end = if last_record.elapsed() >= timeout {
+            last_record
+        } else {
+            Instant::now()
+        };
+
+        let elapsed = end.saturating_duration_since(start);
+
+        let rebalance = group.as_ref().and_then(|_| {
+            consumer
+                .context()
+                .first_assignment
+                .lock()
+                .expect("Rebalance lock poisoned.")
+                .map(|assigned| assigned.saturating_duration_since(start))
+        });
+
+        let fetch = elapsed.saturating_sub(rebalance.unwrap_or_default());
+
+        let elapsed_secs = elapsed.as_secs_f64();
+        let fetch_secs = fetch.as_secs_f64();
+
+        let display = global_args
+            .out
+            .output_string(&PerfConsumeResult {
+                topic,
+                group,
+                records,
+                bytes,
+                elapsed_secs,
+                rebalance_ms: rebalance.map(|r| r.as_secs_f64() * 1000.0),
+                fetch_secs,
+                records_per_sec: records as f64 / elapsed_secs,
+                mb_per_sec: bytes as f64 / MEGABYTE / elapsed_secs,
+                fetch_records_per_sec: records as f64 / fetch_secs,
+                fetch_mb_per_sec: bytes as f64 / MEGABYTE / fetch_secs,
+                decode: decode.then(|| DecodeStats {
+                    failures: decode_failures,
+                    total_ms: decode_time.as_secs_f64() * 1000.0,
+                    avg_us_per_record: match records {
+                        0 => 0.0,
+                        n => decode_time.as_secs_f64() * 1_000_000.0 / n as f64,
+                    },
+                }),
+                partitions: stats,
+            })
+            .change_context(PerfConsumeError::Output)?;
+
+        println!("{}", display);
+
+        Ok(())
+    }
+}
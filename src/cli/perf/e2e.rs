@@ -0,0 +1,321 @@
+use std::{
+    fs::File,
+    io::BufWriter,
+    path::PathBuf,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use chrono::Local;
+use clap::{Args, ValueHint};
+use error_stack::{Report, ResultExt};
+use log::info;
+use rdkafka::{
+    config::RDKafkaLogLevel,
+    consumer::{BaseConsumer, Consumer},
+    producer::{BaseProducer, BaseRecord, Producer},
+    Message, Offset, TopicPartitionList,
+};
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::{
+    cli::{
+        util::producer::{send_with_backoff, Acks, DeliveryContext},
+        GlobalArgs, Invoke,
+    },
+    config::{clusters::NamedCluster, Context},
+    error::cli::perf::PerfE2eError,
+};
+
+use super::histogram::{LatencyBucket, LatencyHistogram, LatencySummary};
+
+const CLIENT_TIMEOUT: Duration = Duration::from_millis(2500);
+// Acknowledgements are only noticed between consumer polls, so keep them short.
+const POLL_TIMEOUT: Duration = Duration::from_millis(1);
+const TIMESTAMP_LEN: usize = 8;
+
+#[derive(Debug, Args)]
+pub(super) struct PerfE2e {
+    #[arg(index = 1, help = "Topic to send probe records through.")]
+    topic: String,
+    #[arg(short, long, help = "Target cluster to measure.")]
+    cluster: Option<String>,
+    #[arg(
+        short,
+        long,
+        default_value_t = 1000,
+        help = "Number of measured probe records."
+    )]
+    num_records: u64,
+    #[arg(
+        short,
+        long,
+        default_value_t = 100,
+        help = "Probe records to send before measuring."
+    )]
+    warmup: u64,
+    #[arg(
+        short = 's',
+        long,
+        default_value_t = 100,
+        help = "Size of each probe payload in bytes, at least 8 to hold the send timestamp."
+    )]
+    record_size: usize,
+    #[arg(
+        long,
+        default_value_t,
+        help = "Acknowledgements required from the broker."
+    )]
+    acks: Acks,
+    #[arg(
+        long,
+        default_value_t = 10,
+        help = "Seconds to wait for each probe to be acknowledged and consumed."
+    )]
+    timeout: u64,
+    #[arg(
+        long,
+        value_hint = ValueHint::FilePath,
+        help = "Write the latency histograms as JSON to this file."
+    )]
+    histogram_file: Option<PathBuf>,
+}
+
+#[derive(Debug, Serialize)]
+struct E2eResult {
+    topic: String,
+    probes: u64,
+    warmup: u64,
+    acks: String,
+    produce: LatencySummary,
+    round_trip: LatencySummary,
+    produce_to_consume: LatencySummary,
+}
+
+#[derive(Debug, Serialize)]
+struct E2eHistogram<'a> {
+    topic: &'a str,
+    created_at: String,
+    round_trip: E2eSeries,
+    produce_to_consume: E2eSeries,
+}
+
+#[derive(Debug, Serialize)]
+struct E2eSeries {
+    summary: LatencySummary,
+    buckets: Vec<LatencyBucket>,
+}
+
+impl E2eSeries {
+    fn new(histogram: &LatencyHistogram) -> Self {
+        Self {
+            summary: histogram.summary(),
+            buckets: histogram.buckets(),
+        }
+    }
+}
+
+impl Invoke for PerfE2e {
+    type E = PerfE2eError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), PerfE2eError> {
+        let Self {
+            topic,
+            cluster,
+            num_records,
+            warmup,
+            record_size,
+            acks,
+            timeout,
+            histogram_file,
+        } = self;
+
+        let NamedCluster(_, cluster_config) = ctx
+            .clusters
+            .cluster_config_or_default_or_select(cluster.as_deref())
+            .change_context(PerfE2eError::FetchCluster)?;
+
+        let producer = cluster_config
+            .client_config()
+            .set("acks", acks.config_value())
+            .set("linger.ms", "0")
+            .set_log_level(RDKafkaLogLevel::Emerg)
+            .create_with_context::<_, BaseProducer<DeliveryContext>>(DeliveryContext::default())
+            .change_context(PerfE2eError::CreateProducer)?;
+
+        let consumer = cluster_config
+            .client_config()
+            .set("group.id", Uuid::new_v4().to_string())
+            .set("enable.auto.commit", "false")
+            .set("fetch.wait.max.ms", "0")
+            .set_log_level(RDKafkaLogLevel::Emerg)
+            .create::<BaseConsumer>()
+            .change_context(PerfE2eError::CreateConsumer)?;
+
+        let metadata = consumer
+            .fetch_metadata(Some(&topic), CLIENT_TIMEOUT)
+            .change_context(PerfE2eError::Metadata(topic.clone()))?;
+
+        let partitions = metadata
+            .topics()
+            .iter()
+            .find(|t| t.name() == topic && t.error().is_none())
+            .ok_or(Report::new(PerfE2eError::TopicNotExists(topic.clone())))?
+            .partitions()
+            .iter()
+            .map(|p| p.id())
+            .collect::<Vec<_>>();
+
+        // Assigning from the current high watermarks rather than Offset::End
+        // avoids missing a probe produced before the end offset is resolved.
+        let mut tpl = TopicPartitionList::new();
+
+        for partition in partitions {
+            let (_, high) = consumer
+                .fetch_watermarks(&topic, partition, CLIENT_TIMEOUT)
+                .change_context(PerfE2eError::Metadata(topic.clone()))?;
+
+            tpl.add_partition_offset(&topic, partition, Offset::Offset(high))
+                .change_context(PerfE2eError::ConsumerFailure)?;
+        }
+
+        consumer
+            .assign(&tpl)
+            .change_context(PerfE2eError::ConsumerFailure)?;
+
+        let run_id = Uuid::new_v4();
+        let timeout = Duration::from_secs(timeout);
+        let mut payload = (0..record_size.max(TIMESTAMP_LEN))
+            .map(|_| fastrand::u8(b'A'..=b'Z'))
+            .collect::<Vec<_>>();
+
+        let mut produce = LatencyHistogram::default();
+        let mut round_trip = LatencyHistogram::default();
+        let mut produce_to_consume = LatencyHistogram::default();
+
+        for probe in 0..warmup + num_records {
+            let key = format!("kcli-e2e-{}-{}", run_id, probe).into_bytes();
+
+            payload[..TIMESTAMP_LEN].copy_from_slice(&epoch_micros().to_be_bytes());
+
+            let sent = Instant::now();
+
+            send_with_backoff(
+                &producer,
+                BaseRecord::to(&topic).key(&key).payload(&payload),
+            )
+            .change_context(PerfE2eError::Produce(probe))?;
+
+            // The producer is polled alongside the consumer rather than flushed,
+            // so a probe can be consumed before its acknowledgement is seen.
+            let mut acknowledged = None;
+            let mut consumed = None;
+
+            let (acknowledged, consumed) = loop {
+                if let (Some(acknowledged), Some(consumed)) = (acknowledged, consumed) {
+                    break (acknowledged, consumed);
+                }
+
+                if sent.elapsed() > timeout {
+                    Err(Report::new(PerfE2eError::Timeout(probe)))?
+                }
+
+                producer.poll(Duration::ZERO);
+
+                if acknowledged.is_none() && producer.in_flight_count() == 0 {
+                    if producer.context().failed() > 0 {
+                        Err(Report::new(PerfE2eError::Produce(probe)))?
+                    }
+
+                    acknowledged = Some(sent.elapsed());
+                }
+
+                if consumed.is_some() {
+                    producer.poll(POLL_TIMEOUT);
+                    continue;
+                }
+
+                match consumer.poll(POLL_TIMEOUT) {
+                    None => (),
+                    Some(Err(e)) => Err(e).change_context(PerfE2eError::ConsumerFailure)?,
+                    Some(Ok(message)) if message.key() == Some(key.as_slice()) => {
+                        let sent_at = message
+                            .payload()
+                            .and_then(|p| p.get(..TIMESTAMP_LEN))
+                            .and_then(|t| t.try_into().ok())
+                            .map(u64::from_be_bytes)
+                            .ok_or(Report::new(PerfE2eError::ConsumerFailure))
+                            .attach_printable("Probe payload is missing its send timestamp.")?;
+
+                        consumed = Some(Duration::from_micros(
+                            epoch_micros().saturating_sub(sent_at),
+                        ));
+                    }
+                    Some(Ok(_)) => (),
+                }
+            };
+
+            if probe < warmup {
+                continue;
+            }
+
+            // A round trip ends once the probe is both acknowledged and consumed.
+            produce.record(acknowledged);
+            round_trip.record(sent.elapsed());
+            produce_to_consume.record(consumed);
+
+            if (probe - warmup + 1) % 100 == 0 {
+                info!(
+                    "Measured {} of {} probes, last produce to consume: {:?}",
+                    probe - warmup + 1,
+                    num_records,
+                    consumed
+                );
+            }
+        }
+
+        if let Some(path) = histogram_file {
+            let file = File::create(&path)
+                .change_context(PerfE2eError::WriteHistogram(path.display().to_string()))?;
+
+            serde_json::to_writer_pretty(
+                BufWriter::new(file),
+                &E2eHistogram {
+                    topic: &topic,
+                    created_at: Local::now().to_rfc3339(),
+                    round_trip: E2eSeries::new(&round_trip),
+                    produce_to_consume: E2eSeries::new(&produce_to_consume),
+                },
+            )
+            .change_context(PerfE2eError::WriteHistogram(path.display().to_string()))?;
+        }
+
+        let display = global_args
+            .out
+            .output_string(&E2eResult {
+                topic,
+                probes: num_records,
+                warmup,
+                acks: acks.to_string(),
+                produce: produce.summary(),
+                round_trip: round_trip.summary(),
+                produce_to_consume: produce_to_consume.summary(),
+            })
+            .change_context(PerfE2eError::Output)?;
+
+        println!("{}", display);
+
+        Ok(())
+    }
+}
+
+fn epoch_micros() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_micros() as u64
+}
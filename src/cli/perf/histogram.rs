@@ -0,0 +1,292 @@
+use std::time::Duration;
+
+use serde::Serialize;
+
+const BUCKET_BOUNDS_MS: &[f64] = &[
+    0.5, 1.0, 2.0, 3.0, 5.0, 7.5, 10.0, 15.0, 20.0, 30.0, 50.0, 75.0, 100.0, 150.0, 200.0, 300.0,
+    500.0, 750.0, 1000.0, 2000.0, 5000.0, 10000.0,
+];
+
+/// Values below this are counted exactly, above it every power of two is split
+/// into this many buckets, keeping values within 1/64 of what was recorded.
+const SUB_BUCKETS: u64 = 64;
+const SUB_BUCKET_BITS: u32 = SUB_BUCKETS.trailing_zeros();
+const BUCKETS: usize = bucket_index(u64::MAX) + 1;
+
+/// Log-linear histogram of microsecond latencies, fixed in size however many
+/// samples are recorded.
+#[derive(Debug)]
+pub struct LatencyHistogram {
+    counts: Vec<u64>,
+    count: u64,
+    sum: u64,
+    min: u64,
+    max: u64,
+}
+
+impl Default for LatencyHistogram {
+    fn default() -> Self {
+        Self {
+            counts: vec![0; BUCKETS],
+            count: 0,
+            sum: 0,
+            min: u64::MAX,
+            max: 0,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct LatencySummary {
+    pub count: usize,
+    pub min_ms: f64,
+    pub mean_ms: f64,
+    pub p50_ms: f64,
+    pub p95_ms: f64,
+    pub p99_ms: f64,
+    pub p999_ms: f64,
+    pub max_ms: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LatencyBucket {
+    pub le_ms: Option<f64>,
+    pub count: usize,
+}
+
+impl LatencyHistogram {
+    pub fn record(&mut self, latency: Duration) {
+        let micros = latency.as_micros().min(u64::MAX as u128) as u64;
+
+        self.counts[bucket_index(micros)] += 1;
+        self.count += 1;
+        self.sum = self.sum.saturating_add(micros);
+        self.min = self.min.min(micros);
+        self.max = self.max.max(micros);
+    }
+
+    /// Highest value of the bucket holding the sample at `rank`, counting from 1.
+    fn value_at_rank(&self, rank: u64) -> u64 {
+        let mut seen = 0;
+
+        for (index, count) in self.counts.iter().enumerate() {
+            seen += count;
+
+            if seen >= rank {
+                return bucket_max(index).min(self.max);
+            }
+        }
+
+        self.max
+    }
+
+    pub fn summary(&self) -> LatencySummary {
+        let percentile = |p: f64| match self.count {
+            0 => 0.0,
+            n => micros_to_ms(self.value_at_rank(((n as f64 * p).ceil() as u64).clamp(1, n))),
+        };
+
+        LatencySummary {
+            count: self.count as usize,
+            min_ms: match self.count {
+                0 => 0.0,
+                _ => micros_to_ms(self.min),
+            },
+            mean_ms: match self.count {
+                0 => 0.0,
+                n => micros_to_ms(self.sum / n),
+            },
+            p50_ms: percentile(0.5),
+            p95_ms: percentile(0.95),
+            p99_ms: percentile(0.99),
+            p999_ms: percentile(0.999),
+            max_ms: micros_to_ms(self.max),
+        }
+    }
+
+    // Buckets are cumulative like Prometheus histograms, ending with an
+    // unbounded bucket holding every sample.
+    pub fn buckets(&self) -> Vec<LatencyBucket> {
+        BUCKET_BOUNDS_MS
+            .iter()
+            .map(|bound| LatencyBucket {
+                le_ms: Some(*bound),
+                count: self.counts[..=bucket_index((bound * 1000.0) as u64)]
+                    .iter()
+                    .sum::<u64>() as usize,
+            })
+            .chain([LatencyBucket {
+                le_ms: None,
+                count: self.count as usize,
+            }])
+            .collect()
+    }
+}
+
+const fn bucket_index(micros: u64) -> usize {
+    if micros < 2 * SUB_BUCKETS {
+        return micros as usize;
+    }
+
+    let shift = u64::BITS - 1 - micros.leading_zeros() - SUB_BUCKET_BITS;
+
+    ((shift as u64 + 1) * SUB_BUCKETS + (micros >> shift) - SUB_BUCKETS) as usize
+}
+
+fn bucket_max(index: usize) -> u64 {
+    let index = index as u64;
+
+    if index < 2 * SUB_BUCKETS {
+        return index;
+    }
+
+    let shift = index / SUB_BUCKETS - 1;
+    let sub_bucket = index % SUB_BUCKETS + SUB_BUCKETS;
+
+    (((sub_bucket as u128 + 1) << shift) - 1).min(u64::MAX as u128) as u64
+}
+
+fn micros_to_ms(micros: u64) -> f64 {
+    micros as f64 / 1000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{bucket_index, bucket_max, LatencyHistogram, BUCKETS, SUB_BUCKETS};
+
+    fn histogram(micros: impl IntoIterator<Item = u64>) -> LatencyHistogram {
+        let mut histogram = LatencyHistogram::default();
+
+        for micros in micros {
+            histogram.record(Duration::from_micros(micros));
+        }
+
+        histogram
+    }
+
+    #[test]
+    fn small_values_have_their_own_bucket() {
+        for micros in 0..2 * SUB_BUCKETS {
+            assert_eq!(bucket_index(micros), micros as usize);
+            assert_eq!(bucket_max(micros as usize), micros);
+        }
+    }
+
+    #[test]
+    fn powers_of_two_start_a_bucket() {
+        for bit in 1..u64::BITS {
+            let value = 1u64 << bit;
+            let index = bucket_index(value);
+
+            assert_eq!(bucket_index(value - 1), index - 1, "2^{}", bit);
+            assert_eq!(bucket_max(index - 1), value - 1, "2^{}", bit);
+            assert!(bucket_max(index) >= value, "2^{}", bit);
+            assert!(
+                bucket_max(index) - value <= value / SUB_BUCKETS,
+                "2^{}",
+                bit
+            );
+        }
+    }
+
+    #[test]
+    fn buckets_stay_within_a_sixty_fourth_of_the_value() {
+        for value in (0..u64::BITS).flat_map(|bit| {
+            let base = 1u64 << bit;
+            [base, base + base / 3, base.saturating_mul(2) - 1]
+        }) {
+            let max = bucket_max(bucket_index(value));
+
+            assert!(max >= value, "{}", value);
+            assert!(max - value <= value / SUB_BUCKETS, "{}", value);
+        }
+    }
+
+    #[test]
+    fn values_past_the_top_bucket_are_clamped() {
+        assert_eq!(bucket_index(u64::MAX), BUCKETS - 1);
+        assert_eq!(bucket_max(BUCKETS - 1), u64::MAX);
+
+        let mut histogram = LatencyHistogram::default();
+        histogram.record(Duration::MAX);
+        histogram.record(Duration::from_micros(1));
+
+        let summary = histogram.summary();
+
+        assert_eq!(summary.count, 2);
+        assert_eq!(summary.max_ms, u64::MAX as f64 / 1000.0);
+        assert_eq!(summary.p50_ms, 0.001);
+        assert_eq!(histogram.value_at_rank(2), u64::MAX);
+    }
+
+    #[test]
+    fn percentiles_of_exact_values() {
+        let histogram = histogram(1..=100);
+        let summary = histogram.summary();
+
+        assert_eq!(summary.count, 100);
+        assert_eq!(summary.min_ms, 0.001);
+        assert_eq!(summary.mean_ms, 0.05);
+        assert_eq!(summary.p50_ms, 0.05);
+        assert_eq!(summary.p99_ms, 0.099);
+        assert_eq!(summary.max_ms, 0.1);
+        assert_eq!(histogram.value_at_rank(100), 100);
+    }
+
+    #[test]
+    fn percentiles_of_bucketed_values() {
+        let histogram = histogram((1..=100_000).map(|i| i * 10));
+        let summary = histogram.summary();
+
+        for (percentile, expected) in [
+            (summary.p50_ms, 500.0),
+            (summary.p99_ms, 990.0),
+            (summary.p999_ms, 999.0),
+        ] {
+            assert!(percentile >= expected, "{} < {}", percentile, expected);
+            assert!(
+                percentile <= expected * 65.0 / 64.0,
+                "{} > {}",
+                percentile,
+                expected
+            );
+        }
+
+        assert_eq!(summary.max_ms, 1000.0);
+        assert_eq!(histogram.value_at_rank(100_000), 1_000_000);
+    }
+
+    #[test]
+    fn empty_histogram_reports_zeros() {
+        let histogram = LatencyHistogram::default();
+        let summary = histogram.summary();
+
+        assert_eq!(summary.count, 0);
+        assert_eq!(
+            [
+                summary.min_ms,
+                summary.mean_ms,
+                summary.p50_ms,
+                summary.p99_ms,
+                summary.max_ms
+            ],
+            [0.0; 5]
+        );
+        assert!(histogram.buckets().iter().all(|b| b.count == 0));
+    }
+
+    #[test]
+    fn buckets_are_cumulative() {
+        let buckets = histogram([100, 900, 4_000, 20_000_000]).buckets();
+
+        let count = |le_ms: Option<f64>| buckets.iter().find(|b| b.le_ms == le_ms).unwrap().count;
+
+        assert_eq!(count(Some(0.5)), 1);
+        assert_eq!(count(Some(1.0)), 2);
+        assert_eq!(count(Some(5.0)), 3);
+        assert_eq!(count(Some(10000.0)), 3);
+        assert_eq!(count(None), 4);
+    }
+}
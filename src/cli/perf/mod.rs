@@ -0,0 +1,102 @@
+use std::{fmt::Display, str::FromStr};
+
+use clap::{builder::PossibleValue, Args, Subcommand, ValueEnum};
+use consume::PerfConsume;
+use e2e::PerfE2e;
+use error_stack::ResultExt;
+use produce::PerfProduce;
+
+use crate::{config::Context, error::cli::ExecutionError};
+
+use super::{GlobalArgs, Invoke};
+
+mod consume;
+mod e2e;
+mod histogram;
+mod produce;
+
+#[derive(Args, Debug)]
+pub(super) struct PerfCommand {
+    #[command(subcommand)]
+    command: PerfSubCommand,
+}
+
+#[derive(Subcommand, Debug)]
+enum PerfSubCommand {
+    #[command(about = "Measure consumer fetch throughput.")]
+    Consume(PerfConsume),
+    #[command(about = "Measure end to end latency through a topic.")]
+    E2e(PerfE2e),
+    #[command(about = "Measure producer throughput and latency.")]
+    Produce(PerfProduce),
+}
+
+impl Invoke for PerfCommand {
+    type E = ExecutionError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), ExecutionError> {
+        match self.command {
+            PerfSubCommand::Consume(command) => command
+                .invoke(ctx, global_args)
+                .change_context(ExecutionError::ExecutionFailed("perf consume")),
+            PerfSubCommand::E2e(command) => command
+                .invoke(ctx, global_args)
+                .change_context(ExecutionError::ExecutionFailed("perf e2e")),
+            PerfSubCommand::Produce(command) => command
+                .invoke(ctx, global_args)
+                .change_context(ExecutionError::ExecutionFailed("perf produce")),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum CompressionType {
+    #[default]
+    None,
+    Gzip,
+    Snappy,
+    Lz4,
+    Zstd,
+}
+
+impl Display for CompressionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.to_possible_value()
+            .expect("no values are skipped")
+            .get_name()
+            .fmt(f)
+    }
+}
+
+impl FromStr for CompressionType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        for variant in Self::value_variants() {
+            if variant.to_possible_value().unwrap().matches(s, false) {
+                return Ok(*variant);
+            }
+        }
+        Err(format!("invalid variant: {s}"))
+    }
+}
+
+impl ValueEnum for CompressionType {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[Self::None, Self::Gzip, Self::Snappy, Self::Lz4, Self::Zstd]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        Some(match self {
+            Self::None => PossibleValue::new("none"),
+            Self::Gzip => PossibleValue::new("gzip"),
+            Self::Snappy => PossibleValue::new("snappy"),
+            Self::Lz4 => PossibleValue::new("lz4"),
+            Self::Zstd => PossibleValue::new("zstd"),
+        })
+    }
+}
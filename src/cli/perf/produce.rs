@@ -0,0 +1,272 @@
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use chrono::Utc;
+use clap::Args;
+use error_stack::ResultExt;
+use log::info;
+use rdkafka::{
+    config::RDKafkaLogLevel,
+    message::DeliveryResult,
+    producer::{BaseProducer, BaseRecord, Producer, ProducerContext},
+    ClientContext,
+};
+use serde::Serialize;
+
+use crate::{
+    cli::{
+        util::{
+            parse_key_value,
+            producer::{send_with_backoff, Acks},
+        },
+        GlobalArgs, Invoke,
+    },
+    config::{clusters::NamedCluster, Context},
+    error::cli::perf::PerfProduceError,
+};
+
+use super::{
+    histogram::{LatencyHistogram, LatencySummary},
+    CompressionType,
+};
+
+const FLUSH_TIMEOUT: Duration = Duration::from_secs(300);
+const REPORT_INTERVAL: Duration = Duration::from_secs(5);
+const MEGABYTE: f64 = 1024.0 * 1024.0;
+
+#[derive(Debug, Args)]
+pub(super) struct PerfProduce {
+    #[arg(index = 1, help = "Topic to produce to.")]
+    topic: String,
+    #[arg(short, long, help = "Target cluster to produce to.")]
+    cluster: Option<String>,
+    #[arg(
+        short,
+        long,
+        required_unless_present = "duration",
+        help = "Number of records to produce."
+    )]
+    num_records: Option<u64>,
+    #[arg(short, long, help = "Produce for this many seconds.")]
+    duration: Option<u64>,
+    #[arg(
+        short = 's',
+        long,
+        default_value_t = 100,
+        help = "Size of each random payload in bytes."
+    )]
+    record_size: usize,
+    #[arg(
+        long,
+        conflicts_with = "record_size",
+        help = "Payload template, '{seq}' and '{timestamp}' are replaced for every record."
+    )]
+    payload_template: Option<String>,
+    #[arg(
+        short,
+        long,
+        value_parser = clap::value_parser!(u64).range(1..),
+        help = "Maximum records per second, unthrottled if omitted."
+    )]
+    throughput: Option<u64>,
+    #[arg(
+        long,
+        default_value_t,
+        help = "Acknowledgements required from the broker."
+    )]
+    acks: Acks,
+    #[arg(long, default_value_t, help = "Compression codec for record batches.")]
+    compression: CompressionType,
+    #[arg(long, help = "Maximum batch size in bytes.")]
+    batch_size: Option<u64>,
+    #[arg(long, help = "Time to wait for a batch to fill in milliseconds.")]
+    linger_ms: Option<u64>,
+    #[arg(
+        short = 'X',
+        long = "producer-config",
+        value_parser = parse_key_value,
+        help = "Additional producer configuration as 'key=value'."
+    )]
+    producer_config: Vec<(String, String)>,
+}
+
+#[derive(Default)]
+struct LatencyContext {
+    latencies: Mutex<LatencyHistogram>,
+    failed: AtomicU64,
+}
+
+impl ClientContext for LatencyContext {}
+
+impl ProducerContext for LatencyContext {
+    type DeliveryOpaque = Box<Instant>;
+
+    fn delivery(&self, delivery_result: &DeliveryResult<'_>, sent: Self::DeliveryOpaque) {
+        match delivery_result {
+            Ok(_) => self
+                .latencies
+                .lock()
+                .expect("Latency histogram lock poisoned.")
+                .record(sent.elapsed()),
+            Err(_) => {
+                self.failed.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct PerfProduceResult {
+    topic: String,
+    records: u64,
+    failed: u64,
+    bytes: u64,
+    elapsed_secs: f64,
+    records_per_sec: f64,
+    mb_per_sec: f64,
+    latency: LatencySummary,
+}
+
+impl Invoke for PerfProduce {
+    type E = PerfProduceError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), PerfProduceError> {
+        let Self {
+            topic,
+            cluster,
+            num_records,
+            duration,
+            record_size,
+            payload_template,
+            throughput,
+            acks,
+            compression,
+            batch_size,
+            linger_ms,
+            producer_config,
+        } = self;
+
+        let NamedCluster(_, cluster_config) = ctx
+            .clusters
+            .cluster_config_or_default_or_select(cluster.as_deref())
+            .change_context(PerfProduceError::FetchCluster)?;
+
+        let mut client_config = cluster_config.client_config();
+
+        client_config
+            .set("acks", acks.config_value())
+            .set("compression.type", compression.to_string())
+            .set_log_level(RDKafkaLogLevel::Emerg);
+
+        if let Some(batch_size) = batch_size {
+            client_config.set("batch.size", batch_size.to_string());
+        }
+
+        if let Some(linger_ms) = linger_ms {
+            client_config.set("linger.ms", linger_ms.to_string());
+        }
+
+        for (key, value) in producer_config {
+            client_config.set(key, value);
+        }
+
+        let producer = client_config
+            .create_with_context::<_, BaseProducer<LatencyContext>>(LatencyContext::default())
+            .change_context(PerfProduceError::CreateProducer)?;
+
+        let random_payload = (0..record_size)
+            .map(|_| fastrand::u8(b'A'..=b'Z'))
+            .collect::<Vec<_>>();
+
+        let duration = duration.map(Duration::from_secs);
+
+        let start = Instant::now();
+        let mut last_report = start;
+        let mut records = 0;
+        let mut bytes = 0;
+
+        while num_records.is_none_or(|n| records < n)
+            && duration.is_none_or(|d| start.elapsed() < d)
+        {
+            let payload = match &payload_template {
+                Some(template) => template
+                    .replace("{seq}", &records.to_string())
+                    .replace("{timestamp}", &Utc::now().timestamp_millis().to_string())
+                    .into_bytes(),
+                None => random_payload.clone(),
+            };
+
+            bytes += payload.len() as u64;
+
+            let record =
+                BaseRecord::<Vec<u8>, Vec<u8>, _>::with_opaque_to(&topic, Box::new(Instant::now()))
+                    .payload(&payload);
+
+            send_with_backoff(&producer, record).change_context(PerfProduceError::Produce)?;
+
+            records += 1;
+
+            if let Some(throughput) = throughput {
+                let expected = Duration::from_secs_f64(records as f64 / throughput as f64);
+                let elapsed = start.elapsed();
+
+                if expected > elapsed {
+                    producer.poll(expected - elapsed);
+                }
+            }
+
+            if last_report.elapsed() >= REPORT_INTERVAL {
+                let elapsed = start.elapsed().as_secs_f64();
+
+                info!(
+                    "{} records sent, {:.1} records/sec, {:.2} MB/sec",
+                    records,
+                    records as f64 / elapsed,
+                    bytes as f64 / MEGABYTE / elapsed
+                );
+
+                last_report = Instant::now();
+            }
+        }
+
+        producer
+            .flush(FLUSH_TIMEOUT)
+            .change_context(PerfProduceError::Flush)?;
+
+        let elapsed_secs = start.elapsed().as_secs_f64();
+
+        let latency = producer
+            .context()
+            .latencies
+            .lock()
+            .expect("Latency histogram lock poisoned.")
+            .summary();
+
+        let display = global_args
+            .out
+            .output_string(&PerfProduceResult {
+                topic,
+                records,
+                failed: producer.context().failed.load(Ordering::Relaxed),
+                bytes,
+                elapsed_secs,
+                records_per_sec: records as f64 / elapsed_secs,
+                mb_per_sec: bytes as f64 / MEGABYTE / elapsed_secs,
+                latency,
+            })
+            .change_context(PerfProduceError::Output)?;
+
+        println!("{}", display);
+
+        Ok(())
+    }
+}
@@ -1,12 +1,329 @@
+use std::{
+    fs::File,
+    io::{stdin, BufRead, BufReader},
+    path::PathBuf,
+    time::Duration,
+};
+
 use clap::Args;
+use error_stack::{Report, ResultExt};
+use log::info;
+use rdkafka::{
+    config::RDKafkaLogLevel,
+    producer::{BaseProducer, BaseRecord, Producer},
+};
+use serde::Serialize;
+use tabled::{
+    settings::{Panel, Style},
+    Table, Tabled,
+};
+
+use crate::{
+    config::{clusters::NamedCluster, Context},
+    error::cli::produce::ProduceError,
+    io::{output::Output, serde::Serde},
+};
+
+use super::{
+    util::producer::{send_with_backoff, Acks, DeliveryContext},
+    GlobalArgs, Invoke,
+};
 
-use crate::error::cli::ExecutionError;
+const TRANSACTION_TIMEOUT: Duration = Duration::from_secs(60);
+const FLUSH_TIMEOUT: Duration = Duration::from_secs(60);
 
 #[derive(Args, Debug)]
-pub(super) struct ProducerCommand {}
+pub(super) struct ProducerCommand {
+    #[arg(index = 1, help = "Topic to produce to.")]
+    topic: String,
+    #[arg(short, long, help = "Target cluster to produce to.")]
+    cluster: Option<String>,
+    #[arg(
+        short,
+        long,
+        help = "File to read records from, one per line. Reads stdin if omitted."
+    )]
+    file: Option<PathBuf>,
+    #[arg(
+        long,
+        help = "Separator between key and value, lines without it are produced unkeyed."
+    )]
+    key_separator: Option<String>,
+    #[arg(short, long, help = "Key serialiser.")]
+    key_serde: Option<Serde>,
+    #[arg(short, long, help = "Value serialiser.")]
+    value_serde: Option<Serde>,
+    #[arg(
+        long,
+        default_value_t,
+        help = "Acknowledgements required from the broker."
+    )]
+    acks: Acks,
+    #[arg(long, help = "Enable the idempotent producer.")]
+    idempotent: bool,
+    #[arg(long, help = "Maximum in-flight requests per broker connection.")]
+    max_in_flight: Option<u32>,
+    #[arg(
+        long,
+        help = "Produce within transactions using this transactional id."
+    )]
+    transactional_id: Option<String>,
+    #[arg(
+        long,
+        requires = "transactional_id",
+        help = "Records per transaction, a single transaction is used if omitted."
+    )]
+    transaction_size: Option<u64>,
+    #[arg(
+        long,
+        requires = "transactional_id",
+        help = "Abort every transaction instead of committing it."
+    )]
+    abort: bool,
+}
+
+#[derive(Debug, Serialize, Tabled)]
+struct TransactionRow {
+    transaction: u64,
+    first_record: u64,
+    records: u64,
+    outcome: &'static str,
+}
+
+#[derive(Debug, Serialize, Tabled)]
+struct ProduceReport {
+    topic: String,
+    sent: u64,
+    failed: u64,
+    #[tabled(skip)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    transactional_id: Option<String>,
+    #[tabled(skip)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    transactions: Vec<TransactionRow>,
+}
+
+impl Invoke for ProducerCommand {
+    type E = ProduceError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), ProduceError> {
+        let Self {
+            topic,
+            cluster,
+            file,
+            key_separator,
+            key_serde,
+            value_serde,
+            acks,
+            idempotent,
+            max_in_flight,
+            transactional_id,
+            transaction_size,
+            abort,
+        } = self;
+
+        let topic_config = ctx.topics.topic(&topic);
+        let key_serde = key_serde
+            .or_else(|| topic_config.map(|t| t.key_serde))
+            .unwrap_or_default();
+        let value_serde = value_serde
+            .or_else(|| topic_config.map(|t| t.value_serde))
+            .unwrap_or_default();
+
+        let NamedCluster(cluster_name, cluster_config) = ctx
+            .clusters
+            .cluster_config_or_default_or_select(cluster.as_deref())
+            .change_context(ProduceError::FetchCluster)?;
+
+        let (source, reader): (String, Box<dyn BufRead>) = match &file {
+            Some(path) => (
+                path.display().to_string(),
+                Box::new(BufReader::new(File::open(path).change_context(
+                    ProduceError::ReadInput(path.display().to_string()),
+                )?)),
+            ),
+            None => ("stdin".to_owned(), Box::new(stdin().lock())),
+        };
+
+        let mut client_config = cluster_config.client_config();
+
+        client_config
+            .set("acks", acks.config_value())
+            .set("enable.idempotence", idempotent.to_string())
+            .set_log_level(RDKafkaLogLevel::Emerg);
+
+        if let Some(max_in_flight) = max_in_flight {
+            client_config.set(
+                "max.in.flight.requests.per.connection",
+                max_in_flight.to_string(),
+            );
+        }
+
+        if let Some(transactional_id) = &transactional_id {
+            client_config.set("transactional.id", transactional_id);
+        }
+
+        let producer = client_config
+            .create_with_context::<_, BaseProducer<DeliveryContext>>(DeliveryContext::default())
+            .change_context(ProduceError::CreateProducer)?;
+
+        if let Some(transactional_id) = &transactional_id {
+            producer
+                .init_transactions(TRANSACTION_TIMEOUT)
+                .change_context(ProduceError::InitTransactions(transactional_id.clone()))?;
+        }
+
+        let mut sent = 0;
+        let mut open: Option<TransactionRow> = None;
+        let mut transactions = Vec::new();
+
+        for (index, line) in reader.lines().enumerate() {
+            let line_number = index as u64 + 1;
+            let line = line.change_context(ProduceError::ReadInput(source.clone()))?;
+
+            if line.is_empty() {
+                continue;
+            }
+
+            if transactional_id.is_some() && open.is_none() {
+                let transaction = transactions.len() as u64 + 1;
+
+                producer
+                    .begin_transaction()
+                    .change_context(ProduceError::BeginTransaction(transaction))?;
 
-impl ProducerCommand {
-    pub(super) fn execute(&self) -> error_stack::Result<(), ExecutionError> {
-        todo!("ProducerCommand")
+                info!("Began transaction {} at record {}", transaction, sent);
+
+                open = Some(TransactionRow {
+                    transaction,
+                    first_record: sent,
+                    records: 0,
+                    outcome: "open",
+                });
+            }
+
+            let (key, value) = match key_separator
+                .as_deref()
+                .and_then(|separator| line.split_once(separator))
+            {
+                Some((key, value)) => (
+                    Some(
+                        key_serde
+                            .serialise_from_string(key)
+                            .change_context(ProduceError::KeySerialisation(line_number))?,
+                    ),
+                    value,
+                ),
+                None => (None, line.as_str()),
+            };
+
+            let value = value_serde
+                .serialise_from_string(value)
+                .change_context(ProduceError::ValueSerialisation(line_number))?;
+
+            let record = BaseRecord::to(&topic).payload(&value);
+            let record = match &key {
+                Some(key) => record.key(key),
+                None => record,
+            };
+
+            send_with_backoff(&producer, record)
+                .change_context(ProduceError::Produce(line_number))?;
+
+            sent += 1;
+
+            if let Some(mut transaction) = open.take() {
+                transaction.records += 1;
+
+                match transaction_size.is_some_and(|size| transaction.records >= size) {
+                    true => transactions.push(end_transaction(&producer, transaction, abort)?),
+                    false => open = Some(transaction),
+                }
+            }
+        }
+
+        if let Some(transaction) = open {
+            transactions.push(end_transaction(&producer, transaction, abort)?);
+        }
+
+        producer
+            .flush(FLUSH_TIMEOUT)
+            .change_context(ProduceError::Flush)?;
+
+        // Aborting purges records still queued, which surface as failed
+        // deliveries, so only plain produces treat them as errors.
+        let failed = match transactional_id {
+            Some(_) => 0,
+            None => producer.context().failed(),
+        };
+
+        let report = ProduceReport {
+            topic,
+            sent,
+            failed,
+            transactional_id,
+            transactions,
+        };
+
+        let display = match global_args.out {
+            Output::Human => match &report.transactional_id {
+                Some(transactional_id) => Table::new(&report.transactions)
+                    .with(Style::modern_rounded())
+                    .with(Panel::footer(format!(
+                        "Sent: {}, Transactional id: {}, Cluster: {}",
+                        report.sent, transactional_id, cluster_name
+                    )))
+                    .to_string(),
+                None => Table::new([&report])
+                    .with(Style::modern_rounded())
+                    .with(Panel::footer(format!("Cluster: {}", cluster_name)))
+                    .to_string(),
+            },
+            out => out
+                .output_string(&report)
+                .change_context(ProduceError::Output)?,
+        };
+
+        println!("{}", display);
+
+        if failed > 0 {
+            Err(Report::new(ProduceError::Delivery(failed)))?
+        }
+
+        Ok(())
     }
 }
+
+fn end_transaction(
+    producer: &BaseProducer<DeliveryContext>,
+    mut transaction: TransactionRow,
+    abort: bool,
+) -> error_stack::Result<TransactionRow, ProduceError> {
+    match abort {
+        true => {
+            producer
+                .abort_transaction(TRANSACTION_TIMEOUT)
+                .change_context(ProduceError::AbortTransaction(transaction.transaction))?;
+
+            transaction.outcome = "aborted";
+        }
+        false => {
+            producer
+                .commit_transaction(TRANSACTION_TIMEOUT)
+                .change_context(ProduceError::CommitTransaction(transaction.transaction))?;
+
+            transaction.outcome = "committed";
+        }
+    }
+
+    info!(
+        "Transaction {} {} with {} records",
+        transaction.transaction, transaction.outcome, transaction.records
+    );
+
+    Ok(transaction)
+}
@@ -0,0 +1,197 @@
+use clap::{ArgGroup, Args};
+use error_stack::{Report, ResultExt};
+use serde::Serialize;
+use tabled::{
+    settings::{Panel, Style},
+    Table, Tabled,
+};
+
+use crate::{
+    cli::{util::get_user_input_confirmation, GlobalArgs, Invoke},
+    config::{clusters::NamedCluster, Context},
+    error::cli::quota::QuotaError,
+    io::{
+        output::Output,
+        protocol::{
+            error_string,
+            quotas::{AlterClientQuotasRequest, QuotaOp},
+            ProtocolClient,
+        },
+    },
+};
+
+use super::{display_entity, EntityArgs, QuotaKey, REQUEST_TIMEOUT};
+
+#[derive(Debug, Args)]
+#[command(group(
+    ArgGroup::new("ops")
+        .required(true)
+        .multiple(true)
+        .args([
+            "producer_byte_rate",
+            "consumer_byte_rate",
+            "request_percentage",
+            "connection_creation_rate",
+            "delete",
+        ])
+))]
+pub(super) struct QuotaAlter {
+    #[arg(short, long, help = "Target cluster.")]
+    cluster: Option<String>,
+    #[command(flatten)]
+    entity: EntityArgs,
+    #[arg(long, help = "Produce throughput limit in bytes per second.")]
+    producer_byte_rate: Option<f64>,
+    #[arg(long, help = "Fetch throughput limit in bytes per second.")]
+    consumer_byte_rate: Option<f64>,
+    #[arg(
+        long,
+        help = "Percentage of a request handler thread's time per quota window."
+    )]
+    request_percentage: Option<f64>,
+    #[arg(long, help = "Connections per second accepted from an ip.")]
+    connection_creation_rate: Option<f64>,
+    #[arg(
+        short,
+        long,
+        value_delimiter = ',',
+        help = "Quotas to remove, comma separated."
+    )]
+    delete: Vec<QuotaKey>,
+    #[arg(
+        long,
+        help = "Have the cluster validate the change without applying it."
+    )]
+    validate_only: bool,
+    #[arg(short, long, help = "Skip the confirmation prompt.")]
+    yes: bool,
+}
+
+#[derive(Debug, Serialize, Tabled)]
+struct AlterRow {
+    entity: String,
+    changes: String,
+    result: String,
+    #[serde(skip)]
+    #[tabled(skip)]
+    failed: bool,
+}
+
+impl Invoke for QuotaAlter {
+    type E = QuotaError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), QuotaError> {
+        let Self {
+            cluster,
+            entity,
+            producer_byte_rate,
+            consumer_byte_rate,
+            request_percentage,
+            connection_creation_rate,
+            delete,
+            validate_only,
+            yes,
+        } = self;
+
+        let entity = entity.entity();
+
+        if entity.is_empty() {
+            Err(Report::new(QuotaError::MissingEntity))?
+        }
+
+        let ops = [
+            (QuotaKey::ProducerByteRate, producer_byte_rate),
+            (QuotaKey::ConsumerByteRate, consumer_byte_rate),
+            (QuotaKey::RequestPercentage, request_percentage),
+            (QuotaKey::ConnectionCreationRate, connection_creation_rate),
+        ]
+        .into_iter()
+        .filter_map(|(key, value)| value.map(|v| (key.to_string(), QuotaOp::Set(v))))
+        .chain(
+            delete
+                .into_iter()
+                .map(|key| (key.to_string(), QuotaOp::Remove)),
+        )
+        .collect::<Vec<_>>();
+
+        let changes = ops
+            .iter()
+            .map(|(key, op)| match op {
+                QuotaOp::Set(value) => format!("{}={}", key, value),
+                QuotaOp::Remove => format!("-{}", key),
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+
+        let NamedCluster(cluster_name, cluster_config) = ctx
+            .clusters
+            .cluster_config_or_default_or_select(cluster.as_deref())
+            .change_context(QuotaError::FetchCluster)?;
+
+        if !yes
+            && !validate_only
+            && !get_user_input_confirmation(&format!(
+                "Apply '{}' to quotas for '{}' in '{}'?",
+                changes,
+                display_entity(&entity),
+                cluster_name
+            ))
+            .change_context(QuotaError::InputError("confirmation"))?
+        {
+            return Ok(());
+        }
+
+        let mut client = ProtocolClient::connect(cluster_config, REQUEST_TIMEOUT)
+            .change_context(QuotaError::Protocol)?;
+
+        let rows = client
+            .send_to_controller(&AlterClientQuotasRequest {
+                entries: vec![(entity, ops)],
+                validate_only,
+            })
+            .change_context(QuotaError::Protocol)?
+            .into_iter()
+            .map(|r| {
+                let (result, failed) = match error_string(r.error_code) {
+                    None if validate_only => ("valid".to_owned(), false),
+                    None => ("altered".to_owned(), false),
+                    Some(error) => (r.error_message.unwrap_or(error), true),
+                };
+
+                AlterRow {
+                    entity: display_entity(&r.entity),
+                    changes: changes.clone(),
+                    result,
+                    failed,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let display = match global_args.out {
+            Output::Human => Table::new(&rows)
+                .with(Style::modern_rounded())
+                .with(Panel::footer(format!(
+                    "Validate only: {}, Cluster: {}",
+                    validate_only, cluster_name
+                )))
+                .to_string(),
+            out => out
+                .output_string(&rows)
+                .change_context(QuotaError::Output)?,
+        };
+
+        println!("{}", display);
+
+        let failed = rows.iter().filter(|r| r.failed).count();
+
+        if failed > 0 {
+            Err(Report::new(QuotaError::EntitiesFailed(failed)))?
+        }
+
+        Ok(())
+    }
+}
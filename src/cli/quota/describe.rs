@@ -0,0 +1,138 @@
+use clap::Args;
+use error_stack::{Report, ResultExt};
+use serde::Serialize;
+use tabled::{
+    settings::{Panel, Style},
+    Table, Tabled,
+};
+
+use crate::{
+    cli::{GlobalArgs, Invoke},
+    config::{clusters::NamedCluster, Context},
+    error::cli::quota::QuotaError,
+    io::{
+        output::Output,
+        protocol::{error_string, quotas::DescribeClientQuotasRequest, ProtocolClient},
+    },
+};
+
+use super::{
+    display_name, entity_name, EntityArgs, QuotaKey, CLIENT_ID, IP, REQUEST_TIMEOUT, USER,
+};
+
+#[derive(Debug, Args)]
+pub(super) struct QuotaDescribe {
+    #[arg(short, long, help = "Target cluster.")]
+    cluster: Option<String>,
+    #[command(flatten)]
+    entity: EntityArgs,
+    #[arg(
+        long,
+        requires = "entity",
+        help = "Only show entities made of exactly the given entity types."
+    )]
+    strict: bool,
+}
+
+#[derive(Debug, Serialize, Tabled)]
+struct QuotaRow {
+    #[tabled(display_with = "display_name")]
+    user: Option<String>,
+    #[tabled(display_with = "display_name")]
+    client_id: Option<String>,
+    #[tabled(display_with = "display_name")]
+    ip: Option<String>,
+    #[tabled(display_with = "display_value")]
+    producer_byte_rate: Option<f64>,
+    #[tabled(display_with = "display_value")]
+    consumer_byte_rate: Option<f64>,
+    #[tabled(display_with = "display_value")]
+    request_percentage: Option<f64>,
+    #[tabled(display_with = "display_value")]
+    connection_creation_rate: Option<f64>,
+}
+
+fn display_value(value: &Option<f64>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_default()
+}
+
+impl Invoke for QuotaDescribe {
+    type E = QuotaError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), QuotaError> {
+        let Self {
+            cluster,
+            entity,
+            strict,
+        } = self;
+
+        let NamedCluster(cluster_name, cluster_config) = ctx
+            .clusters
+            .cluster_config_or_default_or_select(cluster.as_deref())
+            .change_context(QuotaError::FetchCluster)?;
+
+        let mut client = ProtocolClient::connect(cluster_config, REQUEST_TIMEOUT)
+            .change_context(QuotaError::Protocol)?;
+
+        let response = client
+            .send_to_controller(&DescribeClientQuotasRequest {
+                components: entity.components(),
+                strict,
+            })
+            .change_context(QuotaError::Protocol)?;
+
+        if let Some(error) = error_string(response.error_code) {
+            Err(Report::new(QuotaError::Rejected(
+                response.error_message.unwrap_or(error),
+            )))?
+        }
+
+        let mut rows = response
+            .entries
+            .into_iter()
+            .map(|entry| {
+                let value = |key: QuotaKey| {
+                    entry
+                        .values
+                        .iter()
+                        .find(|(k, _)| *k == key.to_string())
+                        .map(|(_, v)| *v)
+                };
+
+                QuotaRow {
+                    user: entity_name(&entry.entity, USER),
+                    client_id: entity_name(&entry.entity, CLIENT_ID),
+                    ip: entity_name(&entry.entity, IP),
+                    producer_byte_rate: value(QuotaKey::ProducerByteRate),
+                    consumer_byte_rate: value(QuotaKey::ConsumerByteRate),
+                    request_percentage: value(QuotaKey::RequestPercentage),
+                    connection_creation_rate: value(QuotaKey::ConnectionCreationRate),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        rows.sort_by(|a, b| (&a.user, &a.client_id, &a.ip).cmp(&(&b.user, &b.client_id, &b.ip)));
+
+        let display = match global_args.out {
+            Output::Human => Table::new(&rows)
+                .with(Style::modern_rounded())
+                .with(Panel::footer(format!(
+                    "Count: {}, Cluster: {}",
+                    rows.len(),
+                    cluster_name
+                )))
+                .to_string(),
+            out => out
+                .output_string(&rows)
+                .change_context(QuotaError::Output)?,
+        };
+
+        println!("{}", display);
+
+        Ok(())
+    }
+}
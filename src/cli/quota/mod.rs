@@ -0,0 +1,191 @@
+use std::{fmt::Display, str::FromStr, time::Duration};
+
+use alter::QuotaAlter;
+use clap::{builder::PossibleValue, ArgGroup, Args, Subcommand, ValueEnum};
+use describe::QuotaDescribe;
+use error_stack::ResultExt;
+
+use crate::{
+    config::Context,
+    error::cli::ExecutionError,
+    io::protocol::quotas::{QuotaEntity, QuotaMatch},
+};
+
+use super::{GlobalArgs, Invoke};
+
+mod alter;
+mod describe;
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+const DEFAULT_ENTITY: &str = "<default>";
+
+const USER: &str = "user";
+const CLIENT_ID: &str = "client-id";
+const IP: &str = "ip";
+
+#[derive(Args, Debug)]
+pub(super) struct QuotaCommand {
+    #[command(subcommand)]
+    command: QuotaSubCommand,
+}
+
+#[derive(Subcommand, Debug)]
+enum QuotaSubCommand {
+    #[command(about = "Set or remove quotas for a user, client id or ip.")]
+    Alter(QuotaAlter),
+    #[command(about = "Show quotas, optionally narrowed to an entity.")]
+    Describe(QuotaDescribe),
+}
+
+impl Invoke for QuotaCommand {
+    type E = ExecutionError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), ExecutionError> {
+        match self.command {
+            QuotaSubCommand::Alter(command) => command
+                .invoke(ctx, global_args)
+                .change_context(ExecutionError::ExecutionFailed("quota alter")),
+            QuotaSubCommand::Describe(command) => command
+                .invoke(ctx, global_args)
+                .change_context(ExecutionError::ExecutionFailed("quota describe")),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum QuotaKey {
+    ProducerByteRate,
+    ConsumerByteRate,
+    RequestPercentage,
+    ConnectionCreationRate,
+}
+
+impl Display for QuotaKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.to_possible_value()
+            .expect("no values are skipped")
+            .get_name()
+            .fmt(f)
+    }
+}
+
+impl FromStr for QuotaKey {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        for variant in Self::value_variants() {
+            if variant.to_possible_value().unwrap().matches(s, false) {
+                return Ok(*variant);
+            }
+        }
+        Err(format!("invalid variant: {s}"))
+    }
+}
+
+impl ValueEnum for QuotaKey {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[
+            Self::ProducerByteRate,
+            Self::ConsumerByteRate,
+            Self::RequestPercentage,
+            Self::ConnectionCreationRate,
+        ]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        Some(match self {
+            Self::ProducerByteRate => PossibleValue::new("producer_byte_rate"),
+            Self::ConsumerByteRate => PossibleValue::new("consumer_byte_rate"),
+            Self::RequestPercentage => PossibleValue::new("request_percentage"),
+            Self::ConnectionCreationRate => PossibleValue::new("connection_creation_rate"),
+        })
+    }
+}
+
+/// Quota entity selection shared by describe and alter. Each entity type can be
+/// given by name or as the default for that type.
+#[derive(Debug, Args)]
+#[command(group(
+    ArgGroup::new("entity")
+        .multiple(true)
+        .args(["user", "user_default", "client_id", "client_id_default", "ip", "ip_default"])
+))]
+struct EntityArgs {
+    #[arg(
+        short,
+        long,
+        conflicts_with = "user_default",
+        help = "User principal name."
+    )]
+    user: Option<String>,
+    #[arg(long, help = "The default quota for all users.")]
+    user_default: bool,
+    #[arg(long, conflicts_with = "client_id_default", help = "Client id.")]
+    client_id: Option<String>,
+    #[arg(long, help = "The default quota for all client ids.")]
+    client_id_default: bool,
+    #[arg(
+        long,
+        conflicts_with_all = ["ip_default", "user", "user_default", "client_id", "client_id_default"],
+        help = "Client ip address, for connection creation rate quotas."
+    )]
+    ip: Option<String>,
+    #[arg(
+        long,
+        conflicts_with_all = ["user", "user_default", "client_id", "client_id_default"],
+        help = "The default quota for all client ips."
+    )]
+    ip_default: bool,
+}
+
+impl EntityArgs {
+    fn components(&self) -> Vec<(&'static str, QuotaMatch<'_>)> {
+        [
+            (USER, self.user.as_deref(), self.user_default),
+            (CLIENT_ID, self.client_id.as_deref(), self.client_id_default),
+            (IP, self.ip.as_deref(), self.ip_default),
+        ]
+        .into_iter()
+        .filter_map(|(entity_type, name, default)| match (name, default) {
+            (Some(name), _) => Some((entity_type, QuotaMatch::Exact(name))),
+            (None, true) => Some((entity_type, QuotaMatch::Default)),
+            (None, false) => None,
+        })
+        .collect()
+    }
+
+    fn entity(&self) -> QuotaEntity {
+        self.components()
+            .into_iter()
+            .map(|(entity_type, matcher)| match matcher {
+                QuotaMatch::Exact(name) => (entity_type.to_owned(), Some(name.to_owned())),
+                QuotaMatch::Default => (entity_type.to_owned(), None),
+            })
+            .collect()
+    }
+}
+
+/// Returns the display name of one entity type, `<default>` for the default
+/// entity, or `None` when the entity does not include that type.
+fn entity_name(entity: &QuotaEntity, entity_type: &str) -> Option<String> {
+    entity
+        .iter()
+        .find(|(t, _)| t == entity_type)
+        .map(|(_, name)| name.clone().unwrap_or_else(|| DEFAULT_ENTITY.to_owned()))
+}
+
+fn display_entity(entity: &QuotaEntity) -> String {
+    entity
+        .iter()
+        .map(|(t, name)| format!("{}={}", t, name.as_deref().unwrap_or(DEFAULT_ENTITY)))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn display_name(name: &Option<String>) -> String {
+    name.clone().unwrap_or_default()
+}
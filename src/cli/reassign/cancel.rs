@@ -0,0 +1,146 @@
+use std::{collections::BTreeMap, path::PathBuf};
+
+use clap::{Args, ValueHint};
+use error_stack::{Report, ResultExt};
+use rdkafka::{config::RDKafkaLogLevel, consumer::BaseConsumer};
+use serde::Serialize;
+use tabled::{settings::Style, Table, Tabled};
+use uuid::Uuid;
+
+use crate::{
+    cli::{GlobalArgs, Invoke},
+    config::{clusters::NamedCluster, Context},
+    error::cli::reassign::ReassignError,
+    io::{
+        output::Output,
+        protocol::{
+            error_string, reassignment::AlterPartitionReassignmentsRequest, ProtocolClient,
+        },
+    },
+};
+
+use super::{remove_throttles, ReassignmentPlan, REQUEST_TIMEOUT};
+
+const NO_REASSIGNMENT_IN_PROGRESS: i16 = 85;
+
+#[derive(Debug, Args)]
+pub(super) struct ReassignCancel {
+    #[arg(
+        index = 1,
+        value_hint = ValueHint::FilePath,
+        help = "Reassignment plan file in the kafka-reassign-partitions format."
+    )]
+    plan: PathBuf,
+    #[arg(short, long, help = "Target cluster.")]
+    cluster: Option<String>,
+    #[arg(long, help = "Keep replication throttles in place after cancelling.")]
+    preserve_throttles: bool,
+}
+
+#[derive(Debug, Serialize, Tabled)]
+struct CancelRow {
+    topic: String,
+    partition: i32,
+    result: String,
+    #[serde(skip)]
+    #[tabled(skip)]
+    failed: bool,
+}
+
+impl Invoke for ReassignCancel {
+    type E = ReassignError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), ReassignError> {
+        let Self {
+            plan,
+            cluster,
+            preserve_throttles,
+        } = self;
+
+        let plan = ReassignmentPlan::read(&plan)?;
+
+        let NamedCluster(_, cluster_config) = ctx
+            .clusters
+            .cluster_config_or_default_or_select(cluster.as_deref())
+            .change_context(ReassignError::FetchCluster)?;
+
+        let mut client = ProtocolClient::connect(cluster_config, REQUEST_TIMEOUT)
+            .change_context(ReassignError::Protocol)?;
+
+        let topics = plan.topics();
+
+        let response = client
+            .send_to_controller(&AlterPartitionReassignmentsRequest {
+                timeout_ms: REQUEST_TIMEOUT.as_millis() as i32,
+                topics: topics
+                    .iter()
+                    .map(|(t, p)| (t.to_owned(), p.iter().map(|p| (*p, None)).collect()))
+                    .collect::<BTreeMap<_, _>>(),
+            })
+            .change_context(ReassignError::Protocol)?;
+
+        if let Some(error) = error_string(response.error_code) {
+            Err(Report::new(ReassignError::Rejected(
+                response.error_message.unwrap_or(error),
+            )))?
+        }
+
+        let rows = response
+            .partitions
+            .into_iter()
+            .map(|r| {
+                let (result, failed) = match r.error_code {
+                    0 => ("cancelled".to_owned(), false),
+                    NO_REASSIGNMENT_IN_PROGRESS => ("not in progress".to_owned(), false),
+                    code => (
+                        r.error_message.or(error_string(code)).unwrap_or_default(),
+                        true,
+                    ),
+                };
+
+                CancelRow {
+                    topic: r.topic,
+                    partition: r.partition,
+                    result,
+                    failed,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        if !preserve_throttles {
+            let consumer = cluster_config
+                .client_config()
+                .set("group.id", Uuid::new_v4().to_string())
+                .set_log_level(RDKafkaLogLevel::Emerg)
+                .create::<BaseConsumer>()
+                .change_context(ReassignError::CreateClient)?;
+
+            remove_throttles(
+                &consumer,
+                client.brokers().keys().copied(),
+                topics.into_keys(),
+            )?;
+        }
+
+        let display = match global_args.out {
+            Output::Human => Table::new(&rows).with(Style::modern_rounded()).to_string(),
+            out => out
+                .output_string(&rows)
+                .change_context(ReassignError::Output)?,
+        };
+
+        println!("{}", display);
+
+        let failed = rows.iter().filter(|r| r.failed).count();
+
+        if failed > 0 {
+            Err(Report::new(ReassignError::PartitionsFailed(failed)))?
+        }
+
+        Ok(())
+    }
+}
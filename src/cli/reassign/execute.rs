@@ -0,0 +1,258 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::PathBuf,
+};
+
+use clap::{Args, ValueHint};
+use error_stack::{Report, ResultExt};
+use rdkafka::{config::RDKafkaLogLevel, consumer::BaseConsumer};
+use serde::Serialize;
+use tabled::{settings::Style, Table, Tabled};
+use uuid::Uuid;
+
+use crate::{
+    cli::{
+        util::{display_replicas, get_user_input_confirmation},
+        GlobalArgs, Invoke,
+    },
+    config::{clusters::NamedCluster, Context},
+    error::cli::reassign::ReassignError,
+    io::{
+        admin::{AlterConfigOp, ConfigAlteration, ConfigResource},
+        output::Output,
+        protocol::{
+            error_string, reassignment::AlterPartitionReassignmentsRequest, ProtocolClient,
+        },
+    },
+};
+
+use super::{
+    apply_config, current_assignment, ReassignmentPlan, FOLLOWER_THROTTLED_RATE,
+    FOLLOWER_THROTTLED_REPLICAS, LEADER_THROTTLED_RATE, LEADER_THROTTLED_REPLICAS, REQUEST_TIMEOUT,
+};
+
+#[derive(Debug, Args)]
+pub(super) struct ReassignExecute {
+    #[arg(
+        index = 1,
+        value_hint = ValueHint::FilePath,
+        help = "Reassignment plan file in the kafka-reassign-partitions format."
+    )]
+    plan: PathBuf,
+    #[arg(short, long, help = "Target cluster.")]
+    cluster: Option<String>,
+    #[arg(
+        short,
+        long,
+        help = "Limit replication traffic for the moving replicas, in bytes per second."
+    )]
+    throttle: Option<u64>,
+    #[arg(short, long, help = "Skip the confirmation prompt.")]
+    yes: bool,
+}
+
+#[derive(Debug, Serialize, Tabled)]
+struct ExecuteRow {
+    topic: String,
+    partition: i32,
+    #[tabled(display_with = "display_replicas")]
+    current: Vec<i32>,
+    #[tabled(display_with = "display_replicas")]
+    target: Vec<i32>,
+    #[tabled(display_with = "display_error")]
+    error: Option<String>,
+}
+
+fn display_error(error: &Option<String>) -> String {
+    error.clone().unwrap_or_default()
+}
+
+impl Invoke for ReassignExecute {
+    type E = ReassignError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), ReassignError> {
+        let Self {
+            plan,
+            cluster,
+            throttle,
+            yes,
+        } = self;
+
+        let plan = ReassignmentPlan::read(&plan)?;
+
+        let NamedCluster(cluster_name, cluster_config) = ctx
+            .clusters
+            .cluster_config_or_default_or_select(cluster.as_deref())
+            .change_context(ReassignError::FetchCluster)?;
+
+        let consumer = cluster_config
+            .client_config()
+            .set("group.id", Uuid::new_v4().to_string())
+            .set_log_level(RDKafkaLogLevel::Emerg)
+            .create::<BaseConsumer>()
+            .change_context(ReassignError::CreateClient)?;
+
+        let mut client = ProtocolClient::connect(cluster_config, REQUEST_TIMEOUT)
+            .change_context(ReassignError::Protocol)?;
+
+        let target = plan.assignment();
+        let current = current_assignment(&consumer, &plan.topics().into_keys().collect())?;
+
+        for (topic, partition) in target.keys() {
+            if !current.contains_key(&(topic.to_owned(), *partition)) {
+                Err(Report::new(ReassignError::InvalidPlan(format!(
+                    "partition {}-{} does not exist",
+                    topic, partition
+                ))))?
+            }
+        }
+
+        if let Some(missing) = target
+            .values()
+            .flatten()
+            .find(|b| !client.brokers().contains_key(b))
+        {
+            Err(Report::new(ReassignError::BrokerNotExists(*missing)))?
+        }
+
+        let moving = target
+            .iter()
+            .filter(|(k, v)| current[*k] != **v)
+            .collect::<BTreeMap<_, _>>();
+
+        if moving.is_empty() {
+            println!("All partitions already match the plan.");
+            return Ok(());
+        }
+
+        if !yes
+            && !get_user_input_confirmation(&format!(
+                "Are you sure you want to reassign {} partitions on '{}'?",
+                moving.len(),
+                cluster_name
+            ))
+            .change_context(ReassignError::InputError("confirmation"))?
+        {
+            return Ok(());
+        }
+
+        if let Some(rate) = throttle {
+            let mut leaders = BTreeMap::<&str, Vec<String>>::new();
+            let mut followers = BTreeMap::<&str, Vec<String>>::new();
+            let mut brokers = BTreeSet::<i32>::new();
+
+            for ((topic, partition), replicas) in &moving {
+                let existing = &current[&(topic.to_owned(), *partition)];
+
+                brokers.extend(existing.iter().chain(replicas.iter()).copied());
+
+                leaders
+                    .entry(topic)
+                    .or_default()
+                    .extend(existing.iter().map(|b| format!("{}:{}", partition, b)));
+
+                followers.entry(topic).or_default().extend(
+                    replicas
+                        .iter()
+                        .filter(|b| !existing.contains(b))
+                        .map(|b| format!("{}:{}", partition, b)),
+                );
+            }
+
+            let alterations = brokers
+                .into_iter()
+                .map(|broker| ConfigAlteration {
+                    resource: ConfigResource::Broker(broker),
+                    entries: vec![
+                        (
+                            LEADER_THROTTLED_RATE.to_owned(),
+                            AlterConfigOp::Set(rate.to_string()),
+                        ),
+                        (
+                            FOLLOWER_THROTTLED_RATE.to_owned(),
+                            AlterConfigOp::Set(rate.to_string()),
+                        ),
+                    ],
+                })
+                .chain(
+                    leaders
+                        .into_iter()
+                        .map(|(topic, replicas)| ConfigAlteration {
+                            resource: ConfigResource::Topic(topic.to_owned()),
+                            entries: vec![
+                                (
+                                    LEADER_THROTTLED_REPLICAS.to_owned(),
+                                    AlterConfigOp::Set(replicas.join(",")),
+                                ),
+                                (
+                                    FOLLOWER_THROTTLED_REPLICAS.to_owned(),
+                                    AlterConfigOp::Set(followers[topic].join(",")),
+                                ),
+                            ],
+                        }),
+                )
+                .collect::<Vec<_>>();
+
+            apply_config(&consumer, &alterations)?;
+        }
+
+        let mut topics = BTreeMap::<String, Vec<_>>::new();
+
+        for ((topic, partition), replicas) in &moving {
+            topics
+                .entry(topic.to_owned())
+                .or_default()
+                .push((*partition, Some((*replicas).clone())));
+        }
+
+        let response = client
+            .send_to_controller(&AlterPartitionReassignmentsRequest {
+                timeout_ms: REQUEST_TIMEOUT.as_millis() as i32,
+                topics,
+            })
+            .change_context(ReassignError::Protocol)?;
+
+        if let Some(error) = error_string(response.error_code) {
+            Err(Report::new(ReassignError::Rejected(
+                response.error_message.unwrap_or(error),
+            )))?
+        }
+
+        let rows = response
+            .partitions
+            .into_iter()
+            .map(|r| {
+                let key = (r.topic.clone(), r.partition);
+
+                ExecuteRow {
+                    current: current[&key].clone(),
+                    target: target[&key].clone(),
+                    topic: r.topic,
+                    partition: r.partition,
+                    error: error_string(r.error_code).map(|e| r.error_message.unwrap_or(e)),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let display = match global_args.out {
+            Output::Human => Table::new(&rows).with(Style::modern_rounded()).to_string(),
+            out => out
+                .output_string(&rows)
+                .change_context(ReassignError::Output)?,
+        };
+
+        println!("{}", display);
+
+        let failed = rows.iter().filter(|r| r.error.is_some()).count();
+
+        if failed > 0 {
+            Err(Report::new(ReassignError::PartitionsFailed(failed)))?
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,267 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs::File,
+    io::{BufReader, BufWriter},
+    path::Path,
+    time::Duration,
+};
+
+use cancel::ReassignCancel;
+use clap::{Args, Subcommand};
+use error_stack::{Report, ResultExt};
+use execute::ReassignExecute;
+use log::warn;
+use plan::ReassignPlan;
+use rdkafka::consumer::{BaseConsumer, Consumer};
+use serde::{Deserialize, Serialize};
+use verify::ReassignVerify;
+
+use crate::{
+    config::Context,
+    error::cli::{reassign::ReassignError, ExecutionError},
+    io::admin::{incremental_alter_configs, AlterConfigOp, ConfigAlteration, ConfigResource},
+};
+
+use super::{GlobalArgs, Invoke};
+
+mod cancel;
+mod execute;
+mod plan;
+mod verify;
+
+const CLIENT_TIMEOUT: Duration = Duration::from_millis(2500);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+const PLAN_VERSION: i32 = 1;
+const ANY_LOG_DIR: &str = "any";
+
+const LEADER_THROTTLED_RATE: &str = "leader.replication.throttled.rate";
+const FOLLOWER_THROTTLED_RATE: &str = "follower.replication.throttled.rate";
+const LEADER_THROTTLED_REPLICAS: &str = "leader.replication.throttled.replicas";
+const FOLLOWER_THROTTLED_REPLICAS: &str = "follower.replication.throttled.replicas";
+
+type Assignment = BTreeMap<(String, i32), Vec<i32>>;
+
+#[derive(Args, Debug)]
+pub(super) struct ReassignCommand {
+    #[command(subcommand)]
+    command: ReassignSubCommand,
+}
+
+#[derive(Subcommand, Debug)]
+enum ReassignSubCommand {
+    #[command(about = "Cancel in progress reassignments from a plan file.")]
+    Cancel(ReassignCancel),
+    #[command(about = "Start the reassignments in a plan file.")]
+    Execute(ReassignExecute),
+    #[command(about = "Generate a balanced reassignment plan for topics.")]
+    Plan(ReassignPlan),
+    #[command(about = "Check the progress of the reassignments in a plan file.")]
+    Verify(ReassignVerify),
+}
+
+impl Invoke for ReassignCommand {
+    type E = ExecutionError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), ExecutionError> {
+        match self.command {
+            ReassignSubCommand::Cancel(command) => command
+                .invoke(ctx, global_args)
+                .change_context(ExecutionError::ExecutionFailed("reassign cancel")),
+            ReassignSubCommand::Execute(command) => command
+                .invoke(ctx, global_args)
+                .change_context(ExecutionError::ExecutionFailed("reassign execute")),
+            ReassignSubCommand::Plan(command) => command
+                .invoke(ctx, global_args)
+                .change_context(ExecutionError::ExecutionFailed("reassign plan")),
+            ReassignSubCommand::Verify(command) => command
+                .invoke(ctx, global_args)
+                .change_context(ExecutionError::ExecutionFailed("reassign verify")),
+        }
+    }
+}
+
+/// Partition assignment in the `kafka-reassign-partitions` JSON format.
+#[derive(Debug, Deserialize, Serialize)]
+struct ReassignmentPlan {
+    version: i32,
+    partitions: Vec<PlanPartition>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct PlanPartition {
+    topic: String,
+    partition: i32,
+    replicas: Vec<i32>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    log_dirs: Vec<String>,
+}
+
+impl ReassignmentPlan {
+    fn from_assignment(assignment: &Assignment) -> Self {
+        Self {
+            version: PLAN_VERSION,
+            partitions: assignment
+                .iter()
+                .map(|((topic, partition), replicas)| PlanPartition {
+                    topic: topic.to_owned(),
+                    partition: *partition,
+                    replicas: replicas.clone(),
+                    log_dirs: vec![ANY_LOG_DIR.to_owned(); replicas.len()],
+                })
+                .collect(),
+        }
+    }
+
+    fn read(path: &Path) -> error_stack::Result<Self, ReassignError> {
+        let file =
+            File::open(path).change_context(ReassignError::ReadFile(path.display().to_string()))?;
+
+        let plan: Self = serde_json::from_reader(BufReader::new(file))
+            .change_context(ReassignError::ReadFile(path.display().to_string()))?;
+
+        if plan.version != PLAN_VERSION {
+            Err(Report::new(ReassignError::InvalidPlan(format!(
+                "unsupported version {}",
+                plan.version
+            ))))?
+        }
+
+        let mut seen = BTreeSet::new();
+
+        for partition in &plan.partitions {
+            let name = format!("{}-{}", partition.topic, partition.partition);
+
+            if !seen.insert((&partition.topic, partition.partition)) {
+                Err(Report::new(ReassignError::InvalidPlan(format!(
+                    "{} is listed more than once",
+                    name
+                ))))?
+            }
+
+            let replicas = partition.replicas.iter().collect::<BTreeSet<_>>();
+
+            if replicas.is_empty() || replicas.len() != partition.replicas.len() {
+                Err(Report::new(ReassignError::InvalidPlan(format!(
+                    "{} must have a non empty list of distinct replicas",
+                    name
+                ))))?
+            }
+
+            if partition.log_dirs.iter().any(|d| d != ANY_LOG_DIR) {
+                warn!(
+                    "Ignoring log_dirs for {}, moving replicas between log directories is not supported.",
+                    name
+                );
+            }
+        }
+
+        Ok(plan)
+    }
+
+    fn write(&self, path: &Path) -> error_stack::Result<(), ReassignError> {
+        let file = File::create(path)
+            .change_context(ReassignError::WriteFile(path.display().to_string()))?;
+
+        serde_json::to_writer_pretty(BufWriter::new(file), self)
+            .change_context(ReassignError::WriteFile(path.display().to_string()))
+    }
+
+    fn assignment(&self) -> Assignment {
+        self.partitions
+            .iter()
+            .map(|p| ((p.topic.clone(), p.partition), p.replicas.clone()))
+            .collect()
+    }
+
+    fn topics(&self) -> BTreeMap<String, Vec<i32>> {
+        let mut topics = BTreeMap::<_, Vec<_>>::new();
+
+        for partition in &self.partitions {
+            topics
+                .entry(partition.topic.clone())
+                .or_default()
+                .push(partition.partition);
+        }
+
+        topics
+    }
+}
+
+fn current_assignment(
+    consumer: &BaseConsumer,
+    topics: &BTreeSet<String>,
+) -> error_stack::Result<Assignment, ReassignError> {
+    let metadata = consumer
+        .fetch_metadata(None, CLIENT_TIMEOUT)
+        .change_context(ReassignError::Metadata)?;
+
+    let assignment = metadata
+        .topics()
+        .iter()
+        .filter(|t| t.error().is_none() && topics.contains(t.name()))
+        .flat_map(|t| {
+            t.partitions()
+                .iter()
+                .map(|p| ((t.name().to_owned(), p.id()), p.replicas().to_vec()))
+        })
+        .collect::<Assignment>();
+
+    if let Some(missing) = topics
+        .iter()
+        .find(|topic| !assignment.keys().any(|(t, _)| t == *topic))
+    {
+        Err(Report::new(ReassignError::TopicNotExists(missing.clone())))?
+    }
+
+    Ok(assignment)
+}
+
+fn remove_throttles(
+    consumer: &BaseConsumer,
+    brokers: impl IntoIterator<Item = i32>,
+    topics: impl IntoIterator<Item = String>,
+) -> error_stack::Result<(), ReassignError> {
+    let alterations = brokers
+        .into_iter()
+        .map(|broker| ConfigAlteration {
+            resource: ConfigResource::Broker(broker),
+            entries: vec![
+                (LEADER_THROTTLED_RATE.to_owned(), AlterConfigOp::Delete),
+                (FOLLOWER_THROTTLED_RATE.to_owned(), AlterConfigOp::Delete),
+            ],
+        })
+        .chain(topics.into_iter().map(|topic| ConfigAlteration {
+            resource: ConfigResource::Topic(topic),
+            entries: vec![
+                (LEADER_THROTTLED_REPLICAS.to_owned(), AlterConfigOp::Delete),
+                (
+                    FOLLOWER_THROTTLED_REPLICAS.to_owned(),
+                    AlterConfigOp::Delete,
+                ),
+            ],
+        }))
+        .collect::<Vec<_>>();
+
+    apply_config(consumer, &alterations)
+}
+
+fn apply_config(
+    consumer: &BaseConsumer,
+    alterations: &[ConfigAlteration],
+) -> error_stack::Result<(), ReassignError> {
+    let results = incremental_alter_configs(consumer.client(), alterations, REQUEST_TIMEOUT)
+        .change_context(ReassignError::Throttle)?;
+
+    for result in results {
+        if let Some(error) = result.error {
+            Err(Report::new(ReassignError::Throttle))
+                .attach_printable(format!("{}: {}", result.resource, error))?
+        }
+    }
+
+    Ok(())
+}
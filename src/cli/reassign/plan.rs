@@ -0,0 +1,417 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs::File,
+    io::BufReader,
+    path::PathBuf,
+};
+
+use clap::{ArgGroup, Args, ValueHint};
+use error_stack::{Report, ResultExt};
+use rdkafka::{
+    config::RDKafkaLogLevel,
+    consumer::{BaseConsumer, Consumer},
+};
+use serde::{Deserialize, Serialize};
+use tabled::{
+    settings::{Panel, Style},
+    Table, Tabled,
+};
+use uuid::Uuid;
+
+use crate::{
+    cli::{util::display_replicas, GlobalArgs, Invoke},
+    config::{clusters::NamedCluster, Context},
+    error::cli::reassign::ReassignError,
+    io::output::Output,
+};
+
+use super::{current_assignment, Assignment, ReassignmentPlan, CLIENT_TIMEOUT};
+
+#[derive(Debug, Args)]
+#[command(group(
+    ArgGroup::new("source")
+        .required(true)
+        .args(["topic", "topics_file"])
+))]
+pub(super) struct ReassignPlan {
+    #[arg(short, long, help = "Target cluster.")]
+    cluster: Option<String>,
+    #[arg(
+        short,
+        long,
+        value_delimiter = ',',
+        help = "Topics to reassign, comma separated."
+    )]
+    topic: Vec<String>,
+    #[arg(
+        long,
+        value_hint = ValueHint::FilePath,
+        help = "JSON topics file in the kafka-reassign-partitions topics-to-move format."
+    )]
+    topics_file: Option<PathBuf>,
+    #[arg(
+        short,
+        long,
+        required = true,
+        value_delimiter = ',',
+        help = "Broker ids to spread replicas across, comma separated."
+    )]
+    brokers: Vec<i32>,
+    #[arg(
+        short,
+        long,
+        value_hint = ValueHint::FilePath,
+        help = "Write the proposed plan to this file."
+    )]
+    output: Option<PathBuf>,
+    #[arg(
+        long,
+        value_hint = ValueHint::FilePath,
+        help = "Write the current assignment to this file so it can be restored later."
+    )]
+    rollback_output: Option<PathBuf>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TopicsFile {
+    topics: Vec<TopicsFileEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TopicsFileEntry {
+    topic: String,
+}
+
+#[derive(Debug, Serialize, Tabled)]
+struct MoveRow {
+    topic: String,
+    partition: i32,
+    #[tabled(display_with = "display_replicas")]
+    current: Vec<i32>,
+    #[tabled(display_with = "display_replicas")]
+    proposed: Vec<i32>,
+}
+
+impl Invoke for ReassignPlan {
+    type E = ReassignError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), ReassignError> {
+        let Self {
+            cluster,
+            topic,
+            topics_file,
+            brokers,
+            output,
+            rollback_output,
+        } = self;
+
+        let NamedCluster(cluster_name, cluster_config) = ctx
+            .clusters
+            .cluster_config_or_default_or_select(cluster.as_deref())
+            .change_context(ReassignError::FetchCluster)?;
+
+        let topics = match &topics_file {
+            Some(path) => {
+                let file = File::open(path)
+                    .change_context(ReassignError::ReadFile(path.display().to_string()))?;
+
+                serde_json::from_reader::<_, TopicsFile>(BufReader::new(file))
+                    .change_context(ReassignError::ReadFile(path.display().to_string()))?
+                    .topics
+                    .into_iter()
+                    .map(|t| t.topic)
+                    .collect::<BTreeSet<_>>()
+            }
+            None => topic.into_iter().collect(),
+        };
+
+        let consumer = cluster_config
+            .client_config()
+            .set("group.id", Uuid::new_v4().to_string())
+            .set_log_level(RDKafkaLogLevel::Emerg)
+            .create::<BaseConsumer>()
+            .change_context(ReassignError::CreateClient)?;
+
+        let metadata = consumer
+            .fetch_metadata(None, CLIENT_TIMEOUT)
+            .change_context(ReassignError::Metadata)?;
+
+        if let Some(missing) = brokers
+            .iter()
+            .find(|b| !metadata.brokers().iter().any(|m| m.id() == **b))
+        {
+            Err(Report::new(ReassignError::BrokerNotExists(*missing)))?
+        }
+
+        let brokers = brokers.into_iter().collect::<BTreeSet<_>>();
+
+        let current = current_assignment(&consumer, &topics)?;
+        let proposed = balance(&current, &brokers)?;
+
+        if let Some(path) = &rollback_output {
+            ReassignmentPlan::from_assignment(&current).write(path)?;
+        }
+
+        if let Some(path) = &output {
+            ReassignmentPlan::from_assignment(&proposed).write(path)?;
+        }
+
+        let display = match global_args.out {
+            Output::Human => {
+                let moves = current
+                    .iter()
+                    .filter(|(k, v)| proposed[*k] != **v)
+                    .map(|((topic, partition), replicas)| MoveRow {
+                        topic: topic.to_owned(),
+                        partition: *partition,
+                        current: replicas.clone(),
+                        proposed: proposed[&(topic.to_owned(), *partition)].clone(),
+                    })
+                    .collect::<Vec<_>>();
+
+                let replica_moves = moves
+                    .iter()
+                    .map(|m| m.proposed.iter().filter(|r| !m.current.contains(r)).count())
+                    .sum::<usize>();
+
+                Table::new(&moves)
+                    .with(Style::modern_rounded())
+                    .with(Panel::footer(format!(
+                        "Partitions changed: {}, Replicas moved: {}, Cluster: {}",
+                        moves.len(),
+                        replica_moves,
+                        cluster_name
+                    )))
+                    .to_string()
+            }
+            out => out
+                .output_string(&ReassignmentPlan::from_assignment(&proposed))
+                .change_context(ReassignError::Output)?,
+        };
+
+        println!("{}", display);
+
+        Ok(())
+    }
+}
+
+/// Spreads replicas evenly across `brokers`, keeping existing replicas in place
+/// wherever the broker is in the target set and not already over its share.
+fn balance(
+    current: &Assignment,
+    brokers: &BTreeSet<i32>,
+) -> error_stack::Result<Assignment, ReassignError> {
+    for ((topic, _), replicas) in current {
+        if replicas.len() > brokers.len() {
+            Err(Report::new(ReassignError::NotEnoughBrokers {
+                topic: topic.to_owned(),
+                replication_factor: replicas.len(),
+                brokers: brokers.len(),
+            }))?
+        }
+    }
+
+    let total = current.values().map(|r| r.len()).sum::<usize>();
+    let max_load = total.div_ceil(brokers.len().max(1));
+
+    let mut load = brokers.iter().map(|b| (*b, 0)).collect::<BTreeMap<_, _>>();
+    let mut proposed = Assignment::new();
+
+    for (partition, replicas) in current {
+        let mut kept = Vec::with_capacity(replicas.len());
+
+        for replica in replicas {
+            if let Some(count) = load.get_mut(replica).filter(|c| **c < max_load) {
+                *count += 1;
+                kept.push(*replica);
+            }
+        }
+
+        proposed.insert(partition.clone(), kept);
+    }
+
+    for (partition, replicas) in current {
+        let kept = proposed.get_mut(partition).expect("Inserted above.");
+
+        while kept.len() < replicas.len() {
+            let (broker, count) = load
+                .iter_mut()
+                .filter(|(b, _)| !kept.contains(b))
+                .min_by_key(|(b, c)| (**c, **b))
+                .expect("Replication factor checked against broker count.");
+
+            *count += 1;
+            kept.push(*broker);
+        }
+    }
+
+    // Filling partitions can force a replica onto a full broker when every
+    // emptier broker already holds one, so even out any remaining skew by
+    // swapping single replicas, preferring ones that were already moving.
+    loop {
+        let (&max_broker, &max_count) = load.iter().max_by_key(|(b, c)| (**c, -**b)).unwrap();
+        let (&min_broker, &min_count) = load.iter().min_by_key(|(b, c)| (**c, **b)).unwrap();
+
+        if max_count - min_count <= 1 {
+            break;
+        }
+
+        let candidates = proposed
+            .iter_mut()
+            .filter(|(_, r)| r.contains(&max_broker) && !r.contains(&min_broker))
+            .map(|(p, r)| (current[p].contains(&max_broker), r))
+            .min_by_key(|(existing, _)| *existing);
+
+        let Some((_, replicas)) = candidates else {
+            break;
+        };
+
+        for replica in replicas.iter_mut().filter(|r| **r == max_broker) {
+            *replica = min_broker;
+        }
+
+        *load.get_mut(&max_broker).unwrap() -= 1;
+        *load.get_mut(&min_broker).unwrap() += 1;
+    }
+
+    Ok(proposed)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, BTreeSet};
+
+    use super::balance;
+    use crate::{cli::reassign::Assignment, error::cli::reassign::ReassignError};
+
+    fn assignment(partitions: &[(&str, i32, &[i32])]) -> Assignment {
+        partitions
+            .iter()
+            .map(|(topic, partition, replicas)| {
+                ((topic.to_string(), *partition), replicas.to_vec())
+            })
+            .collect()
+    }
+
+    fn loads(assignment: &Assignment) -> BTreeMap<i32, usize> {
+        let mut loads = BTreeMap::new();
+
+        for broker in assignment.values().flatten() {
+            *loads.entry(*broker).or_default() += 1;
+        }
+
+        loads
+    }
+
+    fn assert_valid(current: &Assignment, proposed: &Assignment, brokers: &BTreeSet<i32>) {
+        assert_eq!(
+            current.keys().collect::<Vec<_>>(),
+            proposed.keys().collect::<Vec<_>>()
+        );
+
+        for (partition, replicas) in proposed {
+            let distinct = replicas.iter().collect::<BTreeSet<_>>();
+
+            assert_eq!(replicas.len(), current[partition].len(), "{:?}", partition);
+            assert_eq!(distinct.len(), replicas.len(), "{:?}", partition);
+            assert!(
+                replicas.iter().all(|r| brokers.contains(r)),
+                "{:?}",
+                partition
+            );
+        }
+
+        let loads = loads(proposed);
+        let max = brokers
+            .iter()
+            .map(|b| loads.get(b).copied().unwrap_or_default())
+            .max();
+        let min = brokers
+            .iter()
+            .map(|b| loads.get(b).copied().unwrap_or_default())
+            .min();
+
+        assert!(max.unwrap() - min.unwrap() <= 1, "{:?}", loads);
+    }
+
+    #[test]
+    fn balanced_assignment_is_kept() {
+        let current = assignment(&[("a", 0, &[1, 2]), ("a", 1, &[2, 3]), ("a", 2, &[3, 1])]);
+        let brokers = BTreeSet::from([1, 2, 3]);
+
+        assert_eq!(balance(&current, &brokers).unwrap(), current);
+    }
+
+    #[test]
+    fn replicas_move_onto_added_brokers() {
+        let current = assignment(&[
+            ("a", 0, &[1, 2]),
+            ("a", 1, &[2, 1]),
+            ("a", 2, &[1, 2]),
+            ("a", 3, &[2, 1]),
+        ]);
+        let brokers = BTreeSet::from([1, 2, 3, 4]);
+        let proposed = balance(&current, &brokers).unwrap();
+
+        assert_valid(&current, &proposed, &brokers);
+        assert_eq!(
+            loads(&proposed),
+            BTreeMap::from([(1, 2), (2, 2), (3, 2), (4, 2)])
+        );
+
+        let unmoved = current
+            .iter()
+            .flat_map(|(p, r)| r.iter().filter(|b| proposed[p].contains(b)))
+            .count();
+        assert_eq!(unmoved, 4);
+    }
+
+    #[test]
+    fn replicas_move_off_removed_brokers() {
+        let current = assignment(&[
+            ("a", 0, &[1, 2, 3]),
+            ("a", 1, &[2, 3, 4]),
+            ("b", 0, &[3, 4, 1]),
+            ("b", 1, &[4, 1, 2]),
+        ]);
+        let brokers = BTreeSet::from([1, 2, 3]);
+        let proposed = balance(&current, &brokers).unwrap();
+
+        assert_valid(&current, &proposed, &brokers);
+        assert_eq!(proposed[&("a".to_owned(), 0)], [1, 2, 3]);
+    }
+
+    #[test]
+    fn skew_left_by_filling_is_evened_out() {
+        let current = assignment(&[
+            ("a", 0, &[1, 2, 3]),
+            ("a", 1, &[1, 2, 3]),
+            ("a", 2, &[1, 2, 3]),
+            ("a", 3, &[1, 2, 3]),
+            ("a", 4, &[1, 2, 3]),
+        ]);
+        let brokers = BTreeSet::from([1, 2, 3, 4, 5, 6]);
+        let proposed = balance(&current, &brokers).unwrap();
+
+        assert_valid(&current, &proposed, &brokers);
+    }
+
+    #[test]
+    fn replication_factor_above_broker_count_is_rejected() {
+        let current = assignment(&[("a", 0, &[1, 2, 3])]);
+        let report = balance(&current, &BTreeSet::from([1, 2])).unwrap_err();
+
+        assert!(matches!(
+            report.current_context(),
+            ReassignError::NotEnoughBrokers {
+                replication_factor: 3,
+                brokers: 2,
+                ..
+            }
+        ));
+    }
+}
@@ -0,0 +1,205 @@
+use std::{collections::BTreeMap, fmt::Display, path::PathBuf};
+
+use clap::{Args, ValueHint};
+use error_stack::{Report, ResultExt};
+use rdkafka::{config::RDKafkaLogLevel, consumer::BaseConsumer};
+use serde::Serialize;
+use tabled::{
+    settings::{Panel, Style},
+    Table, Tabled,
+};
+use uuid::Uuid;
+
+use crate::{
+    cli::{util::display_replicas, GlobalArgs, Invoke},
+    config::{clusters::NamedCluster, Context},
+    error::cli::reassign::ReassignError,
+    io::{
+        output::Output,
+        protocol::{error_string, reassignment::ListPartitionReassignmentsRequest, ProtocolClient},
+    },
+};
+
+use super::{current_assignment, remove_throttles, ReassignmentPlan, REQUEST_TIMEOUT};
+
+#[derive(Debug, Args)]
+pub(super) struct ReassignVerify {
+    #[arg(
+        index = 1,
+        value_hint = ValueHint::FilePath,
+        help = "Reassignment plan file in the kafka-reassign-partitions format."
+    )]
+    plan: PathBuf,
+    #[arg(short, long, help = "Target cluster.")]
+    cluster: Option<String>,
+    #[arg(
+        long,
+        help = "Keep replication throttles in place once every reassignment has completed."
+    )]
+    preserve_throttles: bool,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum ReassignmentStatus {
+    Complete,
+    InProgress,
+    Mismatch,
+}
+
+impl Display for ReassignmentStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Complete => f.write_str("complete"),
+            Self::InProgress => f.write_str("in progress"),
+            Self::Mismatch => f.write_str("does not match plan"),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Tabled)]
+struct VerifyRow {
+    topic: String,
+    partition: i32,
+    status: ReassignmentStatus,
+    #[tabled(display_with = "display_replicas")]
+    current: Vec<i32>,
+    #[tabled(display_with = "display_replicas")]
+    target: Vec<i32>,
+    #[tabled(display_with = "display_replicas")]
+    adding: Vec<i32>,
+    #[tabled(display_with = "display_replicas")]
+    removing: Vec<i32>,
+}
+
+#[derive(Debug, Serialize)]
+struct VerifyReport {
+    complete: bool,
+    throttles_removed: bool,
+    partitions: Vec<VerifyRow>,
+}
+
+impl Invoke for ReassignVerify {
+    type E = ReassignError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), ReassignError> {
+        let Self {
+            plan,
+            cluster,
+            preserve_throttles,
+        } = self;
+
+        let plan = ReassignmentPlan::read(&plan)?;
+
+        let NamedCluster(cluster_name, cluster_config) = ctx
+            .clusters
+            .cluster_config_or_default_or_select(cluster.as_deref())
+            .change_context(ReassignError::FetchCluster)?;
+
+        let consumer = cluster_config
+            .client_config()
+            .set("group.id", Uuid::new_v4().to_string())
+            .set_log_level(RDKafkaLogLevel::Emerg)
+            .create::<BaseConsumer>()
+            .change_context(ReassignError::CreateClient)?;
+
+        let mut client = ProtocolClient::connect(cluster_config, REQUEST_TIMEOUT)
+            .change_context(ReassignError::Protocol)?;
+
+        let topics = plan.topics();
+        let current = current_assignment(&consumer, &topics.keys().cloned().collect())?;
+
+        let response = client
+            .send_to_controller(&ListPartitionReassignmentsRequest {
+                timeout_ms: REQUEST_TIMEOUT.as_millis() as i32,
+                topics: Some(topics.clone()),
+            })
+            .change_context(ReassignError::Protocol)?;
+
+        if let Some(error) = error_string(response.error_code) {
+            Err(Report::new(ReassignError::Rejected(
+                response.error_message.unwrap_or(error),
+            )))?
+        }
+
+        let ongoing = response
+            .reassignments
+            .into_iter()
+            .map(|r| ((r.topic.clone(), r.partition), r))
+            .collect::<BTreeMap<_, _>>();
+
+        let partitions = plan
+            .assignment()
+            .into_iter()
+            .map(|(key, target)| {
+                let replicas = current.get(&key).cloned().unwrap_or_default();
+                let (status, adding, removing) = match ongoing.get(&key) {
+                    Some(r) => (
+                        ReassignmentStatus::InProgress,
+                        r.adding_replicas.clone(),
+                        r.removing_replicas.clone(),
+                    ),
+                    None if replicas == target => {
+                        (ReassignmentStatus::Complete, Vec::new(), Vec::new())
+                    }
+                    None => (ReassignmentStatus::Mismatch, Vec::new(), Vec::new()),
+                };
+
+                VerifyRow {
+                    topic: key.0,
+                    partition: key.1,
+                    status,
+                    current: replicas,
+                    target,
+                    adding,
+                    removing,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let complete = partitions
+            .iter()
+            .all(|p| p.status == ReassignmentStatus::Complete);
+
+        let throttles_removed = complete && !preserve_throttles;
+
+        if throttles_removed {
+            remove_throttles(
+                &consumer,
+                client.brokers().keys().copied(),
+                topics.into_keys(),
+            )?;
+        }
+
+        let display = match global_args.out {
+            Output::Human => Table::new(&partitions)
+                .with(Style::modern_rounded())
+                .with(Panel::footer(format!(
+                    "Complete: {}/{}, Throttles removed: {}, Cluster: {}",
+                    partitions
+                        .iter()
+                        .filter(|p| p.status == ReassignmentStatus::Complete)
+                        .count(),
+                    partitions.len(),
+                    throttles_removed,
+                    cluster_name
+                )))
+                .to_string(),
+            out => out
+                .output_string(&VerifyReport {
+                    complete,
+                    throttles_removed,
+                    partitions,
+                })
+                .change_context(ReassignError::Output)?,
+        };
+
+        println!("{}", display);
+
+        Ok(())
+    }
+}
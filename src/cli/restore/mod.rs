@@ -0,0 +1,215 @@
+use std::{collections::HashMap, path::PathBuf, time::Duration};
+
+use clap::{Args, ValueHint};
+use error_stack::{Report, ResultExt};
+use log::info;
+use rdkafka::{
+    config::RDKafkaLogLevel,
+    message::{Header, OwnedHeaders},
+    producer::{BaseProducer, BaseRecord, Producer},
+};
+use serde::Serialize;
+
+use crate::{
+    config::{clusters::NamedCluster, Context},
+    error::cli::backup::RestoreError,
+    io::backup::{BackupManifest, BackupReader, BackupRecord},
+};
+
+use super::{
+    util::{
+        producer::{send_with_backoff, DeliveryContext},
+        PartitionMapping,
+    },
+    GlobalArgs, Invoke,
+};
+
+const CLIENT_TIMEOUT: Duration = Duration::from_millis(2500);
+const FLUSH_TIMEOUT: Duration = Duration::from_secs(60);
+
+#[derive(Args, Debug)]
+pub(super) struct RestoreCommand {
+    #[arg(index = 1, value_hint = ValueHint::DirPath, help = "Backup directory to restore from.")]
+    from: PathBuf,
+    #[arg(
+        short,
+        long,
+        help = "Topic to restore into, defaults to the backed up topic."
+    )]
+    topic: Option<String>,
+    #[arg(short, long, help = "Target cluster to restore into.")]
+    cluster: Option<String>,
+    #[arg(
+        short,
+        long,
+        value_delimiter = ',',
+        help = "Map backed up partitions onto target partitions as 'source=target'. Unmapped partitions keep their number."
+    )]
+    partition_map: Vec<PartitionMapping>,
+    #[arg(long, help = "Produce records with their original timestamps.")]
+    preserve_timestamps: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct RestoredPartition {
+    source_partition: i32,
+    target_partition: i32,
+    records: u64,
+}
+
+#[derive(Debug, Serialize)]
+struct RestoreSummary {
+    topic: String,
+    cluster: String,
+    partitions: Vec<RestoredPartition>,
+}
+
+impl Invoke for RestoreCommand {
+    type E = RestoreError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), RestoreError> {
+        let Self {
+            from,
+            topic,
+            cluster,
+            partition_map,
+            preserve_timestamps,
+        } = self;
+
+        let manifest = BackupManifest::read(&from).change_context(RestoreError::Read)?;
+
+        let topic = topic.unwrap_or(manifest.topic.clone());
+
+        let NamedCluster(cluster_name, cluster_config) = ctx
+            .clusters
+            .cluster_config_or_default_or_select(cluster.as_deref())
+            .change_context(RestoreError::FetchCluster)?;
+
+        let producer = cluster_config
+            .client_config()
+            .set("enable.idempotence", "true")
+            .set_log_level(RDKafkaLogLevel::Emerg)
+            .create_with_context::<_, BaseProducer<DeliveryContext>>(DeliveryContext::default())
+            .change_context(RestoreError::CreateProducer)?;
+
+        let metadata = producer
+            .client()
+            .fetch_metadata(Some(&topic), CLIENT_TIMEOUT)
+            .change_context(RestoreError::Metadata(topic.clone()))?;
+
+        let target_partitions = metadata
+            .topics()
+            .iter()
+            .find(|t| t.name() == topic && t.error().is_none())
+            .ok_or(Report::new(RestoreError::TopicNotExists(topic.clone())))?
+            .partitions()
+            .iter()
+            .map(|p| p.id())
+            .collect::<Vec<_>>();
+
+        let mapping = partition_map
+            .iter()
+            .map(|m| (m.source, m.target))
+            .collect::<HashMap<_, _>>();
+
+        let mut restored = Vec::new();
+
+        for partition in &manifest.partitions {
+            let target = mapping
+                .get(&partition.partition)
+                .copied()
+                .unwrap_or(partition.partition);
+
+            if !target_partitions.contains(&target) {
+                Err(Report::new(RestoreError::PartitionNotExists(target)))?
+            }
+
+            let reader = BackupReader::open(&from.join(&partition.file))
+                .change_context(RestoreError::Read)?;
+
+            let mut records = 0;
+
+            for record in reader {
+                let record = record.change_context(RestoreError::Read)?;
+
+                produce(&producer, &topic, target, &record, preserve_timestamps)?;
+
+                records += 1;
+            }
+
+            info!(
+                "Restored {} records from partition {} into partition {}",
+                records, partition.partition, target
+            );
+
+            restored.push(RestoredPartition {
+                source_partition: partition.partition,
+                target_partition: target,
+                records,
+            });
+        }
+
+        producer
+            .flush(FLUSH_TIMEOUT)
+            .change_context(RestoreError::Delivery(producer.in_flight_count() as u64))?;
+
+        let failed = producer.context().failed();
+
+        if failed > 0 {
+            Err(Report::new(RestoreError::Delivery(failed)))?
+        }
+
+        let display = global_args
+            .out
+            .output_string(&RestoreSummary {
+                topic,
+                cluster: cluster_name,
+                partitions: restored,
+            })
+            .change_context(RestoreError::Output)?;
+
+        println!("{}", display);
+
+        Ok(())
+    }
+}
+
+fn produce(
+    producer: &BaseProducer<DeliveryContext>,
+    topic: &str,
+    partition: i32,
+    record: &BackupRecord,
+    preserve_timestamps: bool,
+) -> error_stack::Result<(), RestoreError> {
+    let headers = record
+        .headers
+        .iter()
+        .fold(OwnedHeaders::new(), |headers, header| {
+            headers.insert(Header {
+                key: &String::from_utf8_lossy(&header.key),
+                value: header.value.as_ref(),
+            })
+        });
+
+    let mut base_record = BaseRecord::<Vec<u8>, Vec<u8>>::to(topic)
+        .partition(partition)
+        .headers(headers);
+
+    if let Some(key) = &record.key {
+        base_record = base_record.key(key);
+    }
+
+    if let Some(value) = &record.value {
+        base_record = base_record.payload(value);
+    }
+
+    if let Some(timestamp) = record.timestamp.to_millis().filter(|_| preserve_timestamps) {
+        base_record = base_record.timestamp(timestamp);
+    }
+
+    send_with_backoff(producer, base_record).change_context(RestoreError::Produce(record.offset))
+}
@@ -0,0 +1,106 @@
+use std::path::PathBuf;
+
+use clap::{Args, ValueHint};
+use error_stack::{Report, ResultExt};
+use serde::Serialize;
+use tabled::{
+    settings::{Panel, Style},
+    Table, Tabled,
+};
+
+use crate::{
+    cli::{GlobalArgs, Invoke},
+    config::Context,
+    error::cli::schema::SchemaError,
+    io::{
+        output::Output,
+        schema_registry::{SchemaType, SchemaVersion},
+    },
+};
+
+use super::{read_schema, registry};
+
+#[derive(Debug, Args)]
+pub(super) struct SchemaCheck {
+    #[arg(index = 1, help = "Subject to check the schema against.")]
+    subject: String,
+    #[arg(short, long, value_hint = ValueHint::FilePath, help = "Schema file to check.")]
+    file: PathBuf,
+    #[arg(
+        short = 't',
+        long,
+        help = "Schema type, taken from the file extension if omitted."
+    )]
+    schema_type: Option<SchemaType>,
+    #[arg(short, long, default_value_t, help = "Version number or 'latest'.")]
+    version: SchemaVersion,
+    #[arg(short, long, help = "Target cluster.")]
+    cluster: Option<String>,
+}
+
+#[derive(Debug, Serialize, Tabled)]
+struct CheckReport {
+    subject: String,
+    version: String,
+    compatible: bool,
+    #[tabled(skip)]
+    messages: Vec<String>,
+}
+
+impl Invoke for SchemaCheck {
+    type E = SchemaError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), SchemaError> {
+        let Self {
+            subject,
+            file,
+            schema_type,
+            version,
+            cluster,
+        } = self;
+
+        let schema = read_schema(&file, schema_type)?;
+
+        let (cluster_name, registry) = registry(ctx, cluster.as_deref())?;
+
+        let check = registry
+            .check(&subject, version, &schema)
+            .change_context(SchemaError::Registry)?;
+
+        let report = CheckReport {
+            subject,
+            version: version.to_string(),
+            compatible: check.is_compatible,
+            messages: check.messages,
+        };
+
+        let display = match global_args.out {
+            Output::Human => {
+                let table = Table::new([&report])
+                    .with(Style::modern_rounded())
+                    .with(Panel::footer(format!("Cluster: {}", cluster_name)))
+                    .to_string();
+
+                std::iter::once(table)
+                    .chain(report.messages.iter().map(|m| format!("- {}", m)))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            }
+            out => out
+                .output_string(&report)
+                .change_context(SchemaError::Output)?,
+        };
+
+        println!("{}", display);
+
+        if !report.compatible {
+            Err(Report::new(SchemaError::Incompatible(report.subject)))?
+        }
+
+        Ok(())
+    }
+}
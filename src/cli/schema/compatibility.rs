@@ -0,0 +1,152 @@
+use clap::{Args, Subcommand};
+use error_stack::ResultExt;
+use serde::Serialize;
+use tabled::{
+    settings::{Panel, Style},
+    Table, Tabled,
+};
+
+use crate::{
+    cli::{GlobalArgs, Invoke},
+    config::Context,
+    error::cli::{schema::SchemaError, ExecutionError},
+    io::{output::Output, schema_registry::Compatibility},
+};
+
+use super::registry;
+
+const GLOBAL_SUBJECT: &str = "<global>";
+
+#[derive(Args, Debug)]
+pub(super) struct CompatibilityCommand {
+    #[command(subcommand)]
+    command: CompatibilitySubCommand,
+}
+
+#[derive(Subcommand, Debug)]
+enum CompatibilitySubCommand {
+    #[command(about = "Show the global or a subject's compatibility level.")]
+    Get(CompatibilityGet),
+    #[command(about = "Set the global or a subject's compatibility level.")]
+    Set(CompatibilitySet),
+}
+
+impl Invoke for CompatibilityCommand {
+    type E = ExecutionError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), ExecutionError> {
+        match self.command {
+            CompatibilitySubCommand::Get(command) => command
+                .invoke(ctx, global_args)
+                .change_context(ExecutionError::ExecutionFailed("schema compatibility get")),
+            CompatibilitySubCommand::Set(command) => command
+                .invoke(ctx, global_args)
+                .change_context(ExecutionError::ExecutionFailed("schema compatibility set")),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Tabled)]
+struct CompatibilityRow {
+    subject: String,
+    compatibility: Compatibility,
+}
+
+fn display_row(
+    row: CompatibilityRow,
+    cluster_name: &str,
+    global_args: &GlobalArgs,
+) -> error_stack::Result<String, SchemaError> {
+    Ok(match global_args.out {
+        Output::Human => Table::new([row])
+            .with(Style::modern_rounded())
+            .with(Panel::footer(format!("Cluster: {}", cluster_name)))
+            .to_string(),
+        out => out
+            .output_string(&row)
+            .change_context(SchemaError::Output)?,
+    })
+}
+
+#[derive(Debug, Args)]
+struct CompatibilityGet {
+    #[arg(
+        index = 1,
+        help = "Subject to show, subjects without their own level show the global one."
+    )]
+    subject: Option<String>,
+    #[arg(short, long, help = "Target cluster.")]
+    cluster: Option<String>,
+}
+
+impl Invoke for CompatibilityGet {
+    type E = SchemaError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), SchemaError> {
+        let Self { subject, cluster } = self;
+
+        let (cluster_name, registry) = registry(ctx, cluster.as_deref())?;
+
+        let compatibility = registry
+            .compatibility(subject.as_deref())
+            .change_context(SchemaError::Registry)?;
+
+        let row = CompatibilityRow {
+            subject: subject.unwrap_or(GLOBAL_SUBJECT.to_owned()),
+            compatibility,
+        };
+
+        println!("{}", display_row(row, &cluster_name, global_args)?);
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Args)]
+struct CompatibilitySet {
+    #[arg(index = 1, help = "Compatibility level to set.")]
+    compatibility: Compatibility,
+    #[arg(index = 2, help = "Subject to set, sets the global level if omitted.")]
+    subject: Option<String>,
+    #[arg(short, long, help = "Target cluster.")]
+    cluster: Option<String>,
+}
+
+impl Invoke for CompatibilitySet {
+    type E = SchemaError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), SchemaError> {
+        let Self {
+            compatibility,
+            subject,
+            cluster,
+        } = self;
+
+        let (cluster_name, registry) = registry(ctx, cluster.as_deref())?;
+
+        let compatibility = registry
+            .set_compatibility(subject.as_deref(), compatibility)
+            .change_context(SchemaError::Registry)?;
+
+        let row = CompatibilityRow {
+            subject: subject.unwrap_or(GLOBAL_SUBJECT.to_owned()),
+            compatibility,
+        };
+
+        println!("{}", display_row(row, &cluster_name, global_args)?);
+
+        Ok(())
+    }
+}
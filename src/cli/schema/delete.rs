@@ -0,0 +1,119 @@
+use clap::Args;
+use error_stack::ResultExt;
+use serde::Serialize;
+use tabled::{
+    settings::{Panel, Style},
+    Table, Tabled,
+};
+
+use crate::{
+    cli::{util::get_user_input_confirmation, GlobalArgs, Invoke},
+    config::Context,
+    error::cli::schema::SchemaError,
+    io::{output::Output, schema_registry::SchemaVersion},
+};
+
+use super::registry;
+
+#[derive(Debug, Args)]
+pub(super) struct SchemaDelete {
+    #[arg(index = 1, help = "Subject to delete.")]
+    subject: String,
+    #[arg(
+        short,
+        long,
+        help = "Only delete this version number or 'latest' instead of the whole subject."
+    )]
+    version: Option<SchemaVersion>,
+    #[arg(
+        long,
+        help = "Hard delete, which the registry only allows after a soft delete."
+    )]
+    permanent: bool,
+    #[arg(short, long, help = "Target cluster.")]
+    cluster: Option<String>,
+    #[arg(short, long, help = "Skip the confirmation prompt.")]
+    yes: bool,
+}
+
+#[derive(Debug, Serialize, Tabled)]
+struct DeleteReport {
+    subject: String,
+    #[tabled(display_with = "display_versions")]
+    versions: Vec<i32>,
+    permanent: bool,
+}
+
+fn display_versions(versions: &[i32]) -> String {
+    versions
+        .iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+impl Invoke for SchemaDelete {
+    type E = SchemaError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), SchemaError> {
+        let Self {
+            subject,
+            version,
+            permanent,
+            cluster,
+            yes,
+        } = self;
+
+        let (cluster_name, registry) = registry(ctx, cluster.as_deref())?;
+
+        let target = match version {
+            Some(version) => format!("version {} of subject '{}'", version, subject),
+            None => format!("subject '{}'", subject),
+        };
+
+        if !yes
+            && !get_user_input_confirmation(&format!(
+                "Are you sure you want to {} delete {} on '{}'?",
+                if permanent { "permanently" } else { "soft" },
+                target,
+                cluster_name
+            ))
+            .change_context(SchemaError::InputError("confirmation"))?
+        {
+            return Ok(());
+        }
+
+        let versions = match version {
+            Some(version) => vec![registry
+                .delete_version(&subject, version, permanent)
+                .change_context(SchemaError::Registry)?],
+            None => registry
+                .delete_subject(&subject, permanent)
+                .change_context(SchemaError::Registry)?,
+        };
+
+        let report = DeleteReport {
+            subject,
+            versions,
+            permanent,
+        };
+
+        let display = match global_args.out {
+            Output::Human => Table::new([&report])
+                .with(Style::modern_rounded())
+                .with(Panel::footer(format!("Cluster: {}", cluster_name)))
+                .to_string(),
+            out => out
+                .output_string(&report)
+                .change_context(SchemaError::Output)?,
+        };
+
+        println!("{}", display);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+use clap::Args;
+use error_stack::ResultExt;
+use serde::Serialize;
+use tabled::{
+    settings::{Panel, Style},
+    Table, Tabled,
+};
+
+use crate::{
+    cli::{GlobalArgs, Invoke},
+    config::Context,
+    error::cli::schema::SchemaError,
+    io::{output::Output, schema_registry::SchemaVersion},
+};
+
+use super::registry;
+
+#[derive(Debug, Args)]
+pub(super) struct SchemaGet {
+    #[arg(index = 1, help = "Subject the schema is registered under.")]
+    subject: String,
+    #[arg(short, long, default_value_t, help = "Version number or 'latest'.")]
+    version: SchemaVersion,
+    #[arg(short, long, help = "Target cluster.")]
+    cluster: Option<String>,
+}
+
+#[derive(Debug, Serialize, Tabled)]
+struct SchemaRow<'a> {
+    subject: &'a str,
+    version: i32,
+    id: i32,
+    schema_type: String,
+    references: usize,
+}
+
+impl Invoke for SchemaGet {
+    type E = SchemaError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), SchemaError> {
+        let Self {
+            subject,
+            version,
+            cluster,
+        } = self;
+
+        let (cluster_name, registry) = registry(ctx, cluster.as_deref())?;
+
+        let schema = registry
+            .schema(&subject, version)
+            .change_context(SchemaError::Registry)?;
+
+        let display = match global_args.out {
+            Output::Human => {
+                let table = Table::new([SchemaRow {
+                    subject: &schema.subject,
+                    version: schema.version,
+                    id: schema.id,
+                    schema_type: schema.schema_type.to_string(),
+                    references: schema.references.len(),
+                }])
+                .with(Style::modern_rounded())
+                .with(Panel::footer(format!("Cluster: {}", cluster_name)))
+                .to_string();
+
+                format!("{}\n{}", table, schema.schema)
+            }
+            out => out
+                .output_string(&schema)
+                .change_context(SchemaError::Output)?,
+        };
+
+        println!("{}", display);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,128 @@
+use std::{fs, path::Path, time::Duration};
+
+use check::SchemaCheck;
+use clap::{Args, Subcommand};
+use compatibility::CompatibilityCommand;
+use delete::SchemaDelete;
+use error_stack::{Report, ResultExt};
+use get::SchemaGet;
+use register::SchemaRegister;
+use subjects::SchemaSubjects;
+use versions::SchemaVersions;
+
+use crate::{
+    config::{clusters::NamedCluster, Context},
+    error::cli::{schema::SchemaError, ExecutionError},
+    io::schema_registry::{NewSchema, SchemaRegistry, SchemaType},
+};
+
+use super::{GlobalArgs, Invoke};
+
+mod check;
+mod compatibility;
+mod delete;
+mod get;
+mod register;
+mod subjects;
+mod versions;
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Args, Debug)]
+pub(super) struct SchemaCommand {
+    #[command(subcommand)]
+    command: SchemaSubCommand,
+}
+
+#[derive(Subcommand, Debug)]
+enum SchemaSubCommand {
+    #[command(about = "Check a local schema against a subject version.")]
+    Check(SchemaCheck),
+    #[command(about = "Show or set global and subject compatibility levels.")]
+    Compatibility(CompatibilityCommand),
+    #[command(about = "Delete a subject or one of its versions.")]
+    Delete(SchemaDelete),
+    #[command(about = "Show a schema by subject and version.")]
+    Get(SchemaGet),
+    #[command(about = "Register a schema under a subject.")]
+    Register(SchemaRegister),
+    #[command(about = "List registered subjects.")]
+    Subjects(SchemaSubjects),
+    #[command(about = "List the versions of a subject.")]
+    Versions(SchemaVersions),
+}
+
+impl Invoke for SchemaCommand {
+    type E = ExecutionError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), ExecutionError> {
+        match self.command {
+            SchemaSubCommand::Check(command) => command
+                .invoke(ctx, global_args)
+                .change_context(ExecutionError::ExecutionFailed("schema check")),
+            SchemaSubCommand::Compatibility(command) => command.invoke(ctx, global_args),
+            SchemaSubCommand::Delete(command) => command
+                .invoke(ctx, global_args)
+                .change_context(ExecutionError::ExecutionFailed("schema delete")),
+            SchemaSubCommand::Get(command) => command
+                .invoke(ctx, global_args)
+                .change_context(ExecutionError::ExecutionFailed("schema get")),
+            SchemaSubCommand::Register(command) => command
+                .invoke(ctx, global_args)
+                .change_context(ExecutionError::ExecutionFailed("schema register")),
+            SchemaSubCommand::Subjects(command) => command
+                .invoke(ctx, global_args)
+                .change_context(ExecutionError::ExecutionFailed("schema subjects")),
+            SchemaSubCommand::Versions(command) => command
+                .invoke(ctx, global_args)
+                .change_context(ExecutionError::ExecutionFailed("schema versions")),
+        }
+    }
+}
+
+fn registry(
+    ctx: &Context,
+    cluster: Option<&str>,
+) -> error_stack::Result<(String, SchemaRegistry), SchemaError> {
+    let NamedCluster(cluster_name, cluster_config) = ctx
+        .clusters
+        .cluster_config_or_default_or_select(cluster)
+        .change_context(SchemaError::FetchCluster)?;
+
+    let endpoint = cluster_config
+        .schema_registry()
+        .ok_or(Report::new(SchemaError::NoRegistry(cluster_name.clone())))
+        .attach_printable(
+            "Set one with `kcli config cluster alter <name> --schema-registry <url>`.",
+        )?;
+
+    Ok((cluster_name, SchemaRegistry::new(endpoint, REQUEST_TIMEOUT)))
+}
+
+/// Without an explicit type the file extension is used, falling back to Avro
+/// like the registry does.
+fn read_schema(
+    path: &Path,
+    schema_type: Option<SchemaType>,
+) -> error_stack::Result<NewSchema, SchemaError> {
+    let schema = fs::read_to_string(path)
+        .change_context(SchemaError::ReadSchema(path.display().to_string()))?;
+
+    let schema_type = schema_type
+        .or_else(|| {
+            path.extension()
+                .and_then(|e| e.to_str())
+                .and_then(SchemaType::from_extension)
+        })
+        .unwrap_or_default();
+
+    Ok(NewSchema {
+        schema,
+        schema_type,
+        references: Vec::new(),
+    })
+}
@@ -0,0 +1,86 @@
+use std::path::PathBuf;
+
+use clap::{Args, ValueHint};
+use error_stack::ResultExt;
+use serde::Serialize;
+use tabled::{
+    settings::{Panel, Style},
+    Table, Tabled,
+};
+
+use crate::{
+    cli::{GlobalArgs, Invoke},
+    config::Context,
+    error::cli::schema::SchemaError,
+    io::{output::Output, schema_registry::SchemaType},
+};
+
+use super::{read_schema, registry};
+
+#[derive(Debug, Args)]
+pub(super) struct SchemaRegister {
+    #[arg(index = 1, help = "Subject to register the schema under.")]
+    subject: String,
+    #[arg(short, long, value_hint = ValueHint::FilePath, help = "Schema file to register.")]
+    file: PathBuf,
+    #[arg(
+        short = 't',
+        long,
+        help = "Schema type, taken from the file extension if omitted."
+    )]
+    schema_type: Option<SchemaType>,
+    #[arg(short, long, help = "Target cluster.")]
+    cluster: Option<String>,
+}
+
+#[derive(Debug, Serialize, Tabled)]
+struct RegisterReport {
+    subject: String,
+    id: i32,
+    schema_type: SchemaType,
+}
+
+impl Invoke for SchemaRegister {
+    type E = SchemaError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), SchemaError> {
+        let Self {
+            subject,
+            file,
+            schema_type,
+            cluster,
+        } = self;
+
+        let schema = read_schema(&file, schema_type)?;
+
+        let (cluster_name, registry) = registry(ctx, cluster.as_deref())?;
+
+        let id = registry
+            .register(&subject, &schema)
+            .change_context(SchemaError::Registry)?;
+
+        let report = RegisterReport {
+            subject,
+            id,
+            schema_type: schema.schema_type,
+        };
+
+        let display = match global_args.out {
+            Output::Human => Table::new([&report])
+                .with(Style::modern_rounded())
+                .with(Panel::footer(format!("Cluster: {}", cluster_name)))
+                .to_string(),
+            out => out
+                .output_string(&report)
+                .change_context(SchemaError::Output)?,
+        };
+
+        println!("{}", display);
+
+        Ok(())
+    }
+}
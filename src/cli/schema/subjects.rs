@@ -0,0 +1,67 @@
+use clap::Args;
+use error_stack::ResultExt;
+use serde::Serialize;
+use tabled::{
+    settings::{Panel, Style},
+    Table, Tabled,
+};
+
+use crate::{
+    cli::{GlobalArgs, Invoke},
+    config::Context,
+    error::cli::schema::SchemaError,
+    io::output::Output,
+};
+
+use super::registry;
+
+#[derive(Debug, Args)]
+pub(super) struct SchemaSubjects {
+    #[arg(short, long, help = "Target cluster.")]
+    cluster: Option<String>,
+    #[arg(short, long, help = "Include soft deleted subjects.")]
+    deleted: bool,
+}
+
+#[derive(Debug, Serialize, Tabled)]
+struct SubjectRow {
+    subject: String,
+}
+
+impl Invoke for SchemaSubjects {
+    type E = SchemaError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), SchemaError> {
+        let Self { cluster, deleted } = self;
+
+        let (cluster_name, registry) = registry(ctx, cluster.as_deref())?;
+
+        let mut subjects = registry
+            .subjects(deleted)
+            .change_context(SchemaError::Registry)?;
+
+        subjects.sort();
+
+        let display = match global_args.out {
+            Output::Human => Table::new(subjects.into_iter().map(|subject| SubjectRow { subject }))
+                .with(Style::modern_rounded())
+                .with(Panel::footer(format!(
+                    "Registry: {}, Cluster: {}",
+                    registry.url(),
+                    cluster_name
+                )))
+                .to_string(),
+            out => out
+                .output_string(&subjects)
+                .change_context(SchemaError::Output)?,
+        };
+
+        println!("{}", display);
+
+        Ok(())
+    }
+}
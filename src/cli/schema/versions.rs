@@ -0,0 +1,68 @@
+use clap::Args;
+use error_stack::ResultExt;
+use serde::Serialize;
+use tabled::{
+    settings::{Panel, Style},
+    Table, Tabled,
+};
+
+use crate::{
+    cli::{GlobalArgs, Invoke},
+    config::Context,
+    error::cli::schema::SchemaError,
+    io::output::Output,
+};
+
+use super::registry;
+
+#[derive(Debug, Args)]
+pub(super) struct SchemaVersions {
+    #[arg(index = 1, help = "Subject to list versions of.")]
+    subject: String,
+    #[arg(short, long, help = "Target cluster.")]
+    cluster: Option<String>,
+}
+
+#[derive(Debug, Serialize, Tabled)]
+struct VersionRow {
+    version: i32,
+}
+
+impl Invoke for SchemaVersions {
+    type E = SchemaError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), SchemaError> {
+        let Self { subject, cluster } = self;
+
+        let (cluster_name, registry) = registry(ctx, cluster.as_deref())?;
+
+        let versions = registry
+            .versions(&subject)
+            .change_context(SchemaError::Registry)?;
+
+        let display = match global_args.out {
+            Output::Human => Table::new(
+                versions
+                    .iter()
+                    .map(|version| VersionRow { version: *version }),
+            )
+            .with(Style::modern_rounded())
+            .with(Panel::footer(format!(
+                "Subject: {}, Cluster: {}",
+                subject, cluster_name
+            )))
+            .to_string(),
+            out => out
+                .output_string(&versions)
+                .change_context(SchemaError::Output)?,
+        };
+
+        println!("{}", display);
+
+        Ok(())
+    }
+}
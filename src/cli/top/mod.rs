@@ -0,0 +1,135 @@
+use std::{
+    io::{stdout, Stdout},
+    time::{Duration, Instant},
+};
+
+use chrono::Local;
+use clap::Args;
+use error_stack::ResultExt;
+use ratatui::{
+    backend::CrosstermBackend,
+    crossterm::{
+        event::{self, Event, KeyCode, KeyEventKind, KeyModifiers},
+        execute,
+        terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+    },
+    Terminal,
+};
+use stats::Collector;
+
+use crate::{
+    config::{clusters::NamedCluster, Context},
+    error::cli::top::TopError,
+};
+
+use super::{GlobalArgs, Invoke};
+
+mod stats;
+mod ui;
+
+#[derive(Args, Debug)]
+pub(super) struct TopCommand {
+    #[arg(short, long, help = "Target cluster to monitor.")]
+    cluster: Option<String>,
+    #[arg(
+        short,
+        long,
+        default_value_t = 5,
+        help = "Seconds between dashboard refreshes."
+    )]
+    interval: u64,
+}
+
+impl Invoke for TopCommand {
+    type E = TopError;
+
+    fn invoke(self, ctx: &mut Context, _: &GlobalArgs) -> error_stack::Result<(), TopError> {
+        let Self { cluster, interval } = self;
+
+        let NamedCluster(cluster_name, cluster_config) = ctx
+            .clusters
+            .cluster_config_or_default_or_select(cluster.as_deref())
+            .change_context(TopError::FetchCluster)?;
+
+        let mut collector = Collector::new(cluster_config)?;
+
+        let _guard = TerminalGuard::enter()?;
+
+        let mut terminal =
+            Terminal::new(CrosstermBackend::new(stdout())).change_context(TopError::Terminal)?;
+
+        run(
+            &mut terminal,
+            &mut collector,
+            &cluster_name,
+            Duration::from_secs(interval.max(1)),
+        )
+    }
+}
+
+/// Restores the terminal when dropped, including while unwinding from a panic.
+struct TerminalGuard;
+
+impl TerminalGuard {
+    fn enter() -> error_stack::Result<Self, TopError> {
+        enable_raw_mode().change_context(TopError::Terminal)?;
+
+        let guard = Self;
+
+        execute!(stdout(), EnterAlternateScreen).change_context(TopError::Terminal)?;
+
+        Ok(guard)
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = disable_raw_mode();
+        let _ = execute!(stdout(), LeaveAlternateScreen);
+    }
+}
+
+fn run(
+    terminal: &mut Terminal<CrosstermBackend<Stdout>>,
+    collector: &mut Collector,
+    cluster_name: &str,
+    interval: Duration,
+) -> error_stack::Result<(), TopError> {
+    loop {
+        let snapshot = collector.snapshot()?;
+        let refreshed_at = Instant::now();
+
+        let status = format!(
+            " Refreshed at {}, every {}s. Press 'r' to refresh, 'q' to quit.",
+            Local::now().format("%H:%M:%S"),
+            interval.as_secs()
+        );
+
+        terminal
+            .draw(|frame| ui::draw(frame, cluster_name, &snapshot, &status))
+            .change_context(TopError::Terminal)?;
+
+        while let Some(remaining) = interval.checked_sub(refreshed_at.elapsed()) {
+            if !event::poll(remaining).change_context(TopError::Terminal)? {
+                break;
+            }
+
+            match event::read().change_context(TopError::Terminal)? {
+                Event::Key(key) if key.kind == KeyEventKind::Press => match key.code {
+                    KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                    KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                        return Ok(())
+                    }
+                    KeyCode::Char('r') => break,
+                    _ => (),
+                },
+                Event::Resize(_, _) => {
+                    terminal
+                        .draw(|frame| ui::draw(frame, cluster_name, &snapshot, &status))
+                        .change_context(TopError::Terminal)?;
+                }
+                _ => (),
+            }
+        }
+    }
+}
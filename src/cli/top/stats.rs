@@ -0,0 +1,232 @@
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+use error_stack::ResultExt;
+use rdkafka::{
+    config::RDKafkaLogLevel,
+    consumer::{BaseConsumer, Consumer},
+    metadata::Metadata,
+    Offset, TopicPartitionList,
+};
+use uuid::Uuid;
+
+use crate::{
+    config::clusters::ClusterConfig, error::cli::top::TopError,
+    io::admin::list_consumer_group_offsets,
+};
+
+const CLIENT_TIMEOUT: Duration = Duration::from_millis(2500);
+
+#[derive(Debug)]
+pub(super) struct BrokerStats {
+    pub(super) id: i32,
+    pub(super) host: String,
+    pub(super) port: i32,
+}
+
+#[derive(Debug)]
+pub(super) struct TopicStats {
+    pub(super) name: String,
+    pub(super) partitions: usize,
+    pub(super) under_replicated: usize,
+    pub(super) offline: usize,
+    pub(super) messages_per_second: Option<f64>,
+}
+
+#[derive(Debug)]
+pub(super) struct GroupStats {
+    pub(super) name: String,
+    pub(super) state: String,
+    pub(super) members: usize,
+    pub(super) lag: i64,
+}
+
+#[derive(Debug)]
+pub(super) struct ClusterSnapshot {
+    pub(super) brokers: Vec<BrokerStats>,
+    pub(super) controller: Option<i32>,
+    pub(super) topics: Vec<TopicStats>,
+    pub(super) groups: Vec<GroupStats>,
+}
+
+impl ClusterSnapshot {
+    pub(super) fn partition_count(&self) -> usize {
+        self.topics.iter().map(|t| t.partitions).sum()
+    }
+
+    pub(super) fn under_replicated(&self) -> usize {
+        self.topics.iter().map(|t| t.under_replicated).sum()
+    }
+
+    pub(super) fn offline(&self) -> usize {
+        self.topics.iter().map(|t| t.offline).sum()
+    }
+}
+
+/// Reuses a single client for every refresh, with one request for all end
+/// offsets and one per group for committed offsets.
+pub(super) struct Collector {
+    consumer: BaseConsumer,
+    previous: Option<(Instant, HashMap<String, i64>)>,
+}
+
+impl Collector {
+    pub(super) fn new(cluster: &ClusterConfig) -> error_stack::Result<Self, TopError> {
+        let consumer = cluster
+            .client_config()
+            .set("group.id", Uuid::new_v4().to_string())
+            .set("enable.auto.commit", "false")
+            .set_log_level(RDKafkaLogLevel::Emerg)
+            .create::<BaseConsumer>()
+            .change_context(TopError::CreateClient)?;
+
+        Ok(Self {
+            consumer,
+            previous: None,
+        })
+    }
+
+    pub(super) fn snapshot(&mut self) -> error_stack::Result<ClusterSnapshot, TopError> {
+        let metadata = self
+            .consumer
+            .fetch_metadata(None, CLIENT_TIMEOUT)
+            .change_context(TopError::Metadata)?;
+
+        let controller = unsafe {
+            rdkafka_sys::rd_kafka_controllerid(
+                self.consumer.client().native_ptr(),
+                CLIENT_TIMEOUT.as_millis() as i32,
+            )
+        };
+
+        let brokers = metadata
+            .brokers()
+            .iter()
+            .map(|b| BrokerStats {
+                id: b.id(),
+                host: b.host().to_owned(),
+                port: b.port(),
+            })
+            .collect::<Vec<_>>();
+
+        let high_watermarks = self.end_offsets(&metadata)?;
+
+        let now = Instant::now();
+        let mut topic_sums = HashMap::new();
+        let mut topics = Vec::new();
+
+        for topic in metadata.topics() {
+            let sum = topic
+                .partitions()
+                .iter()
+                .filter_map(|p| high_watermarks.get(&(topic.name().to_owned(), p.id())))
+                .sum::<i64>();
+
+            let messages_per_second = self.previous.as_ref().and_then(|(taken_at, sums)| {
+                let elapsed = now.duration_since(*taken_at).as_secs_f64();
+
+                sums.get(topic.name())
+                    .filter(|_| elapsed > 0.0)
+                    .map(|previous| (sum - previous).max(0) as f64 / elapsed)
+            });
+
+            topic_sums.insert(topic.name().to_owned(), sum);
+
+            topics.push(TopicStats {
+                name: topic.name().to_owned(),
+                partitions: topic.partitions().len(),
+                under_replicated: topic
+                    .partitions()
+                    .iter()
+                    .filter(|p| p.isr().len() < p.replicas().len())
+                    .count(),
+                offline: topic.partitions().iter().filter(|p| p.leader() < 0).count(),
+                messages_per_second,
+            });
+        }
+
+        self.previous.replace((now, topic_sums));
+
+        let groups = self.group_stats(&high_watermarks)?;
+
+        Ok(ClusterSnapshot {
+            brokers,
+            controller: (controller >= 0).then_some(controller),
+            topics,
+            groups,
+        })
+    }
+
+    /// A LATEST lookup for every partition, which librdkafka batches per
+    /// leader instead of one watermark request per partition.
+    fn end_offsets(
+        &self,
+        metadata: &Metadata,
+    ) -> error_stack::Result<HashMap<(String, i32), i64>, TopError> {
+        let mut tpl = TopicPartitionList::new();
+
+        for topic in metadata.topics() {
+            for partition in topic.partitions() {
+                tpl.add_partition_offset(topic.name(), partition.id(), Offset::End)
+                    .change_context(TopError::EndOffsets)?;
+            }
+        }
+
+        if tpl.count() == 0 {
+            return Ok(HashMap::new());
+        }
+
+        Ok(self
+            .consumer
+            .offsets_for_times(tpl, CLIENT_TIMEOUT)
+            .change_context(TopError::EndOffsets)?
+            .elements()
+            .iter()
+            .filter_map(|e| match e.offset() {
+                Offset::Offset(offset) => Some(((e.topic().to_owned(), e.partition()), offset)),
+                _ => None,
+            })
+            .collect())
+    }
+
+    fn group_stats(
+        &self,
+        high_watermarks: &HashMap<(String, i32), i64>,
+    ) -> error_stack::Result<Vec<GroupStats>, TopError> {
+        let group_list = self
+            .consumer
+            .fetch_group_list(None, CLIENT_TIMEOUT)
+            .change_context(TopError::Groups)?;
+
+        let mut groups = Vec::new();
+
+        for group in group_list.groups() {
+            let committed =
+                list_consumer_group_offsets(self.consumer.client(), group.name(), CLIENT_TIMEOUT)
+                    .change_context_lazy(|| TopError::CommittedOffsets(group.name().to_owned()))?;
+
+            let lag = committed
+                .iter()
+                .filter(|p| p.offset >= 0)
+                .filter_map(|p| {
+                    high_watermarks
+                        .get(&(p.topic.clone(), p.partition))
+                        .map(|high| (high - p.offset).max(0))
+                })
+                .sum();
+
+            groups.push(GroupStats {
+                name: group.name().to_owned(),
+                state: group.state().to_owned(),
+                members: group.members().len(),
+                lag,
+            });
+        }
+
+        groups.sort_by(|a, b| b.lag.cmp(&a.lag).then_with(|| a.name.cmp(&b.name)));
+
+        Ok(groups)
+    }
+}
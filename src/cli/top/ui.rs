@@ -0,0 +1,171 @@
+use ratatui::{
+    layout::{Constraint, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Row, Table},
+    Frame,
+};
+
+use super::stats::ClusterSnapshot;
+
+fn count_style(count: usize) -> Style {
+    if count > 0 {
+        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(Color::Green)
+    }
+}
+
+fn header_row<'a>(cells: Vec<&'a str>) -> Row<'a> {
+    Row::new(cells).style(Style::default().add_modifier(Modifier::BOLD))
+}
+
+pub(super) fn draw(frame: &mut Frame, cluster: &str, snapshot: &ClusterSnapshot, status: &str) {
+    let [summary_area, brokers_area, body_area, footer_area] = Layout::vertical([
+        Constraint::Length(4),
+        Constraint::Length(snapshot.brokers.len() as u16 + 3),
+        Constraint::Min(0),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+
+    let [topics_area, groups_area] =
+        Layout::horizontal([Constraint::Percentage(55), Constraint::Percentage(45)])
+            .areas(body_area);
+
+    let controller = snapshot
+        .controller
+        .map(|c| c.to_string())
+        .unwrap_or("Unknown".to_owned());
+
+    let summary = Paragraph::new(vec![
+        Line::from(vec![
+            Span::raw(format!("Brokers: {}  ", snapshot.brokers.len())),
+            Span::raw(format!("Controller: {}  ", controller)),
+            Span::raw(format!("Topics: {}  ", snapshot.topics.len())),
+            Span::raw(format!("Partitions: {}", snapshot.partition_count())),
+        ]),
+        Line::from(vec![
+            Span::raw("Under replicated: "),
+            Span::styled(
+                snapshot.under_replicated().to_string(),
+                count_style(snapshot.under_replicated()),
+            ),
+            Span::raw("  Offline: "),
+            Span::styled(
+                snapshot.offline().to_string(),
+                count_style(snapshot.offline()),
+            ),
+        ]),
+    ])
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" Cluster: {} ", cluster)),
+    );
+
+    frame.render_widget(summary, summary_area);
+
+    let broker_rows = snapshot.brokers.iter().map(|b| {
+        let role = match snapshot.controller {
+            Some(controller) if controller == b.id => "controller",
+            _ => "",
+        };
+
+        Row::new(vec![
+            b.id.to_string(),
+            format!("{}:{}", b.host, b.port),
+            role.to_owned(),
+        ])
+    });
+
+    let brokers = Table::new(
+        broker_rows,
+        [
+            Constraint::Length(8),
+            Constraint::Min(20),
+            Constraint::Length(12),
+        ],
+    )
+    .header(header_row(vec!["id", "address", "role"]))
+    .block(Block::default().borders(Borders::ALL).title(" Brokers "));
+
+    frame.render_widget(brokers, brokers_area);
+
+    let mut topics = snapshot.topics.iter().collect::<Vec<_>>();
+
+    topics.sort_by(|a, b| {
+        b.messages_per_second
+            .unwrap_or_default()
+            .total_cmp(&a.messages_per_second.unwrap_or_default())
+            .then_with(|| a.name.cmp(&b.name))
+    });
+
+    let topic_rows = topics.into_iter().map(|t| {
+        let row = Row::new(vec![
+            t.name.clone(),
+            t.partitions.to_string(),
+            t.under_replicated.to_string(),
+            t.offline.to_string(),
+            t.messages_per_second
+                .map(|rate| format!("{:.1}", rate))
+                .unwrap_or("-".to_owned()),
+        ]);
+
+        if t.under_replicated + t.offline > 0 {
+            row.style(Style::default().fg(Color::Red))
+        } else {
+            row
+        }
+    });
+
+    let topics = Table::new(
+        topic_rows,
+        [
+            Constraint::Min(20),
+            Constraint::Length(10),
+            Constraint::Length(8),
+            Constraint::Length(8),
+            Constraint::Length(12),
+        ],
+    )
+    .header(header_row(vec![
+        "topic",
+        "partitions",
+        "urp",
+        "offline",
+        "msgs/s",
+    ]))
+    .block(Block::default().borders(Borders::ALL).title(" Topics "));
+
+    frame.render_widget(topics, topics_area);
+
+    let group_rows = snapshot.groups.iter().map(|g| {
+        Row::new(vec![
+            g.name.clone(),
+            g.state.clone(),
+            g.members.to_string(),
+            g.lag.to_string(),
+        ])
+    });
+
+    let groups = Table::new(
+        group_rows,
+        [
+            Constraint::Min(20),
+            Constraint::Length(14),
+            Constraint::Length(8),
+            Constraint::Length(12),
+        ],
+    )
+    .header(header_row(vec!["group", "state", "members", "lag"]))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(" Consumer groups "),
+    );
+
+    frame.render_widget(groups, groups_area);
+
+    frame.render_widget(Paragraph::new(status), footer_area);
+}
@@ -6,18 +6,19 @@ use futures::executor;
 use rdkafka::{
     admin::{AdminClient, AdminOptions, OwnedResourceSpecifier, ResourceSpecifier}, client::DefaultClientContext, config::RDKafkaLogLevel, ClientConfig,
 };
-use regex::Regex;
 use serde::Serialize;
 use tabled::{
     grid::records::ExactRecords, settings::{Panel, Style}, Table, Tabled
 };
 
 use crate::{
-    cli::{topic::INTERNAL_TOPIC_REGEX, GlobalArgs, Invoke},
+    cli::{GlobalArgs, Invoke},
     config::{clusters::NamedCluster, Context},
     error::cli::config::topic::ReadOnlyTopicError,
 };
 
+use super::TopicFilter;
+
 #[derive(Debug, Serialize)]
 struct PartitionRow {
     id: i32,
@@ -45,14 +46,8 @@ pub(super) struct DescribeTopic {
     name: Option<String>,
     #[arg(short, long, help = "Target cluster to consumer from.")]
     cluster: Option<String>,
-    #[arg(long, help = "Whether to exclude internal topics.")]
-    exclude_internal: bool,
-    #[arg(short, long, help = "Exclude topics with prefix")]
-    exclude_prefix: Option<String>,
-    #[arg(short, long, help = "Include topics with prefix.")]
-    include_prefix: Option<String>,
-    #[arg(short, long, help = "Regex to match topics with.")]
-    regex: Option<String>,
+    #[command(flatten)]
+    filter: TopicFilter,
 }
 
 impl Invoke for DescribeTopic {
@@ -62,18 +57,15 @@ impl Invoke for DescribeTopic {
         let Self {
             name,
             mut cluster,
-            exclude_prefix,
-            include_prefix,
-            regex,
-            exclude_internal,
+            filter,
         } = self;
 
         let filters = format!(
             "specific: '{}', exclude: '{}', include: '{}', regex: '{}'", 
             name.as_ref().unwrap_or(&"None".to_owned()), 
-            exclude_prefix.as_ref().unwrap_or(&"None".to_owned()), 
-            include_prefix.as_ref().unwrap_or(&"None".to_owned()), 
-            regex.as_ref().unwrap_or(&"None".to_owned())
+            filter.exclude_prefix.as_ref().unwrap_or(&"None".to_owned()), 
+            filter.include_prefix.as_ref().unwrap_or(&"None".to_owned()), 
+            filter.regex.as_ref().unwrap_or(&"None".to_owned())
         );
 
         let cluster_config = if let Some(cluster_name) = &cluster {
@@ -105,34 +97,14 @@ impl Invoke for DescribeTopic {
             .fetch_metadata(None, Duration::from_millis(2500))
             .change_context(ReadOnlyTopicError::AdminClient)?;
 
-        let internal_topic_regex =
-            Regex::new(INTERNAL_TOPIC_REGEX).expect("Failed to compile inbuilt regex");
-
-        let is_internal_topic = |t: &str| internal_topic_regex.is_match(t);
-
-        let exclude = exclude_prefix.is_some();
-        let exclude_prefix_parse = exclude_prefix.unwrap_or("".to_owned());
-
-        let include = include_prefix.is_some();
-        let include_prefix_parsed = include_prefix.unwrap_or("".to_owned());
-
-        let user_regex = match regex {
-            None => None,
-            Some(s) => Some(Regex::new(&s).change_context(ReadOnlyTopicError::CompileRegex(s))?),
-        };
+        let is_match = filter.matcher()?;
 
         let topics = metadata
             .topics()
             .iter()
             .map(|t|
                 (t.name(), t.partitions()))
-            .filter(|t| t.0.starts_with(&include_prefix_parsed) || !include)
-            .filter(|t| !t.0.starts_with(&exclude_prefix_parse) || !exclude)
-            .filter(|t| !is_internal_topic(t.0) || !exclude_internal)
-            .filter(|t| match &user_regex {
-                None => true,
-                Some(re) => re.is_match(t.0),
-            })
+            .filter(|t| is_match(t.0))
             .filter(|t| match &name {
                 None => true,
                 Some(name) => t.0 == name
@@ -0,0 +1,214 @@
+use std::{path::PathBuf, time::Duration};
+
+use clap::{ArgGroup, Args};
+use error_stack::ResultExt;
+use rdkafka::{
+    config::RDKafkaLogLevel,
+    consumer::{BaseConsumer, Consumer},
+};
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::{
+    cli::{GlobalArgs, Invoke},
+    config::{clusters::NamedCluster, Context},
+    error::cli::config::topic::TopicDiffError,
+    io::{
+        output::Output,
+        spec::{ClusterSpec, TopicChange, TopicSpec},
+    },
+};
+
+use super::TopicFilter;
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+const DEFAULT_VALUE: &str = "<default>";
+
+#[derive(Debug, Args)]
+#[command(group(
+    ArgGroup::new("against")
+        .required(true)
+        .args(["target", "spec"])
+))]
+pub(super) struct TopicDiff {
+    #[arg(short, long, help = "Source cluster to compare from.")]
+    cluster: Option<String>,
+    #[arg(short, long, help = "Target cluster to compare against.")]
+    target: Option<String>,
+    #[arg(
+        short,
+        long,
+        help = "YAML, TOML or JSON topic spec to compare against instead of a cluster."
+    )]
+    spec: Option<PathBuf>,
+    #[command(flatten)]
+    filter: TopicFilter,
+}
+
+#[derive(Debug, Serialize)]
+struct ChangeRow {
+    topic: String,
+    change: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    field: Option<String>,
+    source: Option<String>,
+    target: Option<String>,
+}
+
+fn display_topic(spec: &TopicSpec) -> String {
+    format!(
+        "partitions: {}, replication_factor: {}, configs: {}",
+        spec.partitions,
+        spec.replication_factor,
+        spec.configs.len()
+    )
+}
+
+impl Invoke for TopicDiff {
+    type E = TopicDiffError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), TopicDiffError> {
+        let Self {
+            cluster,
+            target,
+            spec,
+            filter,
+        } = self;
+
+        let is_match = filter.matcher().change_context(TopicDiffError::Filter)?;
+
+        let (source_name, source) = read_cluster(ctx, cluster.as_deref(), &is_match)?;
+
+        let (target_name, target) = match (target, spec) {
+            (Some(target), _) => read_cluster(ctx, Some(&target), &is_match)?,
+            (None, Some(path)) => {
+                let name = path.display().to_string();
+                let mut spec = ClusterSpec::read(&path)
+                    .change_context(TopicDiffError::ReadSpec(name.clone()))?;
+
+                spec.retain(&is_match);
+
+                (name, spec)
+            }
+            (None, None) => unreachable!("Required by the against group."),
+        };
+
+        let changes = source.diff(&target);
+
+        let display = match global_args.out {
+            Output::Human => {
+                let mut lines = Vec::new();
+
+                for (topic, change) in &changes {
+                    match change {
+                        TopicChange::Added(spec) => lines.push(format!(
+                            "+ {} (only in {}; {})",
+                            topic,
+                            target_name,
+                            display_topic(spec)
+                        )),
+                        TopicChange::Removed(spec) => lines.push(format!(
+                            "- {} (only in {}; {})",
+                            topic,
+                            source_name,
+                            display_topic(spec)
+                        )),
+                        TopicChange::Changed(fields) => {
+                            lines.push(format!("~ {}", topic));
+
+                            lines.extend(fields.iter().map(|f| {
+                                format!(
+                                    "    {}: {} -> {}",
+                                    f.field,
+                                    f.from.as_deref().unwrap_or(DEFAULT_VALUE),
+                                    f.to.as_deref().unwrap_or(DEFAULT_VALUE)
+                                )
+                            }));
+                        }
+                    }
+                }
+
+                let count =
+                    |kind: fn(&TopicChange) -> bool| changes.values().filter(|c| kind(c)).count();
+
+                lines.push(format!(
+                    "Source: {}, Target: {}, Only in source: {}, Only in target: {}, Changed: {}",
+                    source_name,
+                    target_name,
+                    count(|c| matches!(c, TopicChange::Removed(_))),
+                    count(|c| matches!(c, TopicChange::Added(_))),
+                    count(|c| matches!(c, TopicChange::Changed(_))),
+                ));
+
+                lines.join("\n")
+            }
+            out => {
+                let rows = changes
+                    .into_iter()
+                    .flat_map(|(topic, change)| match change {
+                        TopicChange::Added(_) => vec![ChangeRow {
+                            topic,
+                            change: "added",
+                            field: None,
+                            source: None,
+                            target: Some(target_name.clone()),
+                        }],
+                        TopicChange::Removed(_) => vec![ChangeRow {
+                            topic,
+                            change: "removed",
+                            field: None,
+                            source: Some(source_name.clone()),
+                            target: None,
+                        }],
+                        TopicChange::Changed(fields) => fields
+                            .into_iter()
+                            .map(|f| ChangeRow {
+                                topic: topic.clone(),
+                                change: "changed",
+                                field: Some(f.field.to_string()),
+                                source: f.from,
+                                target: f.to,
+                            })
+                            .collect(),
+                    })
+                    .collect::<Vec<_>>();
+
+                out.output_string(&rows)
+                    .change_context(TopicDiffError::Output)?
+            }
+        };
+
+        println!("{}", display);
+
+        Ok(())
+    }
+}
+
+fn read_cluster(
+    ctx: &Context,
+    cluster: Option<&str>,
+    is_match: impl Fn(&str) -> bool,
+) -> error_stack::Result<(String, ClusterSpec), TopicDiffError> {
+    let NamedCluster(cluster_name, cluster_config) = ctx
+        .clusters
+        .cluster_config_or_default_or_select(cluster)
+        .change_context(TopicDiffError::FetchCluster(
+            cluster.unwrap_or("default").to_owned(),
+        ))?;
+
+    let consumer = cluster_config
+        .client_config()
+        .set("group.id", Uuid::new_v4().to_string())
+        .set_log_level(RDKafkaLogLevel::Emerg)
+        .create::<BaseConsumer>()
+        .change_context(TopicDiffError::CreateClient(cluster_name.clone()))?;
+
+    let spec = ClusterSpec::from_cluster(consumer.client(), is_match, REQUEST_TIMEOUT)
+        .change_context(TopicDiffError::ReadSpec(cluster_name.clone()))?;
+
+    Ok((cluster_name, spec))
+}
@@ -5,30 +5,25 @@ use error_stack::ResultExt;
 use rdkafka::{
     admin::AdminClient, client::DefaultClientContext, config::RDKafkaLogLevel, ClientConfig,
 };
-use regex::Regex;
 use tabled::{
     builder::Builder,
     settings::{Panel, Style},
 };
 
 use crate::{
-    cli::{topic::INTERNAL_TOPIC_REGEX, GlobalArgs, Invoke},
+    cli::{GlobalArgs, Invoke},
     config::{clusters::NamedCluster, Context},
     error::cli::config::topic::ReadOnlyTopicError,
 };
 
+use super::TopicFilter;
+
 #[derive(Debug, Args)]
 pub(super) struct ListTopics {
     #[arg(short, long, help = "Target cluster to consumer from.")]
     cluster: Option<String>,
-    #[arg(long, help = "Whether to exclude internal topics.")]
-    exclude_internal: bool,
-    #[arg(short, long, help = "Exclude topics with prefix")]
-    exclude_prefix: Option<String>,
-    #[arg(short, long, help = "Include topics with prefix.")]
-    include_prefix: Option<String>,
-    #[arg(short, long, help = "Regex to match topics with.")]
-    regex: Option<String>,
+    #[command(flatten)]
+    filter: TopicFilter,
 }
 
 impl Invoke for ListTopics {
@@ -41,10 +36,7 @@ impl Invoke for ListTopics {
     ) -> error_stack::Result<(), ReadOnlyTopicError> {
         let Self {
             mut cluster,
-            exclude_prefix,
-            include_prefix,
-            regex,
-            exclude_internal,
+            filter,
         } = self;
 
         let cluster_config = if let Some(cluster_name) = &cluster {
@@ -76,21 +68,7 @@ impl Invoke for ListTopics {
             .fetch_metadata(None, Duration::from_millis(2500))
             .change_context(ReadOnlyTopicError::AdminClient)?;
 
-        let internal_topic_regex =
-            Regex::new(INTERNAL_TOPIC_REGEX).expect("Failed to compile inbuilt regex");
-
-        let is_internal_topic = |t: &&str| internal_topic_regex.is_match(t);
-
-        let exclude = exclude_prefix.is_some();
-        let exclude_prefix = exclude_prefix.unwrap_or("".to_owned());
-
-        let include = include_prefix.is_some();
-        let include_prefix = include_prefix.unwrap_or("".to_owned());
-
-        let user_regex = match regex {
-            None => None,
-            Some(s) => Some(Regex::new(&s).change_context(ReadOnlyTopicError::CompileRegex(s))?),
-        };
+        let is_match = filter.matcher()?;
 
         let mut table_builder = Builder::default();
 
@@ -98,13 +76,7 @@ impl Invoke for ListTopics {
             .topics()
             .iter()
             .map(|t| t.name())
-            .filter(|t| t.starts_with(&include_prefix) || !include)
-            .filter(|t| !t.starts_with(&exclude_prefix) || !exclude)
-            .filter(|t| !is_internal_topic(t) || !exclude_internal)
-            .filter(|t| match &user_regex {
-                None => true,
-                Some(re) => re.is_match(t),
-            })
+            .filter(|t| is_match(t))
             .for_each(|t| table_builder.push_record(vec![t]));
 
         let mut table = table_builder.build();
@@ -3,10 +3,17 @@ use clap::{Args, Subcommand};
 use create::CreateTopic;
 use delete::DeleteTopic;
 use describe::DescribeTopic;
+use diff::TopicDiff;
 use error_stack::ResultExt;
 use list::ListTopics;
+use offsets::TopicOffsets;
+use regex::Regex;
+use truncate::TruncateTopic;
 
-use crate::{config::Context, error::cli::ExecutionError};
+use crate::{
+    config::Context,
+    error::cli::{config::topic::ReadOnlyTopicError, ExecutionError},
+};
 
 use super::{GlobalArgs, Invoke};
 
@@ -14,9 +21,12 @@ mod alter;
 mod create;
 mod delete;
 mod describe;
+mod diff;
 mod list;
+mod offsets;
+mod truncate;
 
-const INTERNAL_TOPIC_REGEX: &str =
+pub(super) const INTERNAL_TOPIC_REGEX: &str =
     r"^__consumer_offsets$|^__transaction_state$|^__share_group_state$|^__cluster_metadata$";
 
 #[derive(Args, Debug)]
@@ -35,8 +45,14 @@ enum TopicSubCommand {
     Delete(DeleteTopic),
     #[command(about = "Desribe a kafka topic.")]
     Describe(DescribeTopic),
+    #[command(about = "Compare topics between two clusters or against a spec file.")]
+    Diff(TopicDiff),
     #[command(about = "List available topics on cluster")]
     List(ListTopics),
+    #[command(about = "Show earliest and latest offsets for topic partitions.")]
+    Offsets(TopicOffsets),
+    #[command(about = "Delete records from a topic up to an offset.")]
+    Truncate(TruncateTopic),
 }
 
 impl Invoke for TopicCommand {
@@ -60,9 +76,58 @@ impl Invoke for TopicCommand {
             TopicSubCommand::Describe(command) => command
                 .invoke(ctx, global_args)
                 .change_context(ExecutionError::ExecutionFailed("topic describe")),
+            TopicSubCommand::Diff(command) => command
+                .invoke(ctx, global_args)
+                .change_context(ExecutionError::ExecutionFailed("topic diff")),
             TopicSubCommand::List(command) => command
                 .invoke(ctx, global_args)
                 .change_context(ExecutionError::ExecutionFailed("topic list")),
+            TopicSubCommand::Offsets(command) => command
+                .invoke(ctx, global_args)
+                .change_context(ExecutionError::ExecutionFailed("topic offsets")),
+            TopicSubCommand::Truncate(command) => command
+                .invoke(ctx, global_args)
+                .change_context(ExecutionError::ExecutionFailed("topic truncate")),
         }
     }
 }
+
+#[derive(Args, Debug)]
+pub(super) struct TopicFilter {
+    #[arg(long, help = "Whether to exclude internal topics.")]
+    exclude_internal: bool,
+    #[arg(short, long, help = "Exclude topics with prefix")]
+    exclude_prefix: Option<String>,
+    #[arg(short, long, help = "Include topics with prefix.")]
+    include_prefix: Option<String>,
+    #[arg(short, long, help = "Regex to match topics with.")]
+    regex: Option<String>,
+}
+
+impl TopicFilter {
+    pub(super) fn matcher(
+        &self,
+    ) -> error_stack::Result<impl Fn(&str) -> bool + '_, ReadOnlyTopicError> {
+        let internal_topic_regex =
+            Regex::new(INTERNAL_TOPIC_REGEX).expect("Failed to compile inbuilt regex");
+
+        let user_regex = match &self.regex {
+            None => None,
+            Some(s) => {
+                Some(Regex::new(s).change_context(ReadOnlyTopicError::CompileRegex(s.to_owned()))?)
+            }
+        };
+
+        Ok(move |topic: &str| {
+            self.include_prefix
+                .as_ref()
+                .is_none_or(|prefix| topic.starts_with(prefix))
+                && self
+                    .exclude_prefix
+                    .as_ref()
+                    .is_none_or(|prefix| !topic.starts_with(prefix))
+                && !(self.exclude_internal && internal_topic_regex.is_match(topic))
+                && user_regex.as_ref().is_none_or(|re| re.is_match(topic))
+        })
+    }
+}
@@ -0,0 +1,188 @@
+use std::{collections::HashMap, time::Duration};
+
+use clap::Args;
+use error_stack::{Report, ResultExt};
+use rdkafka::{
+    config::RDKafkaLogLevel,
+    consumer::{BaseConsumer, Consumer},
+    Offset, TopicPartitionList,
+};
+use serde::Serialize;
+use tabled::{
+    settings::{location::ByColumnName, Panel, Remove, Style},
+    Table, Tabled,
+};
+use uuid::Uuid;
+
+use crate::{
+    cli::{util::parse_timestamp, GlobalArgs, Invoke},
+    config::{clusters::NamedCluster, Context},
+    error::cli::config::topic::ReadOnlyTopicError,
+    io::output::Output,
+};
+
+use super::TopicFilter;
+
+const CLIENT_TIMEOUT: Duration = Duration::from_millis(2500);
+
+#[derive(Debug, Args)]
+pub(super) struct TopicOffsets {
+    #[arg(
+        index = 1,
+        conflicts_with_all = ["exclude_internal", "exclude_prefix", "include_prefix", "regex"],
+        help = "Topic to show offsets for, defaults to all topics matching the filters."
+    )]
+    name: Option<String>,
+    #[arg(short, long, help = "Target cluster.")]
+    cluster: Option<String>,
+    #[command(flatten)]
+    filter: TopicFilter,
+    #[arg(
+        short,
+        long,
+        value_parser = parse_timestamp,
+        help = "Also show the first offset at or after this time, as epoch millis or RFC 3339."
+    )]
+    time: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Tabled)]
+struct OffsetRow {
+    topic: String,
+    partition: i32,
+    earliest: i64,
+    latest: i64,
+    messages: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[tabled(display_with = "display_offset")]
+    offset_for_time: Option<i64>,
+}
+
+fn display_offset(offset: &Option<i64>) -> String {
+    offset.map(|o| o.to_string()).unwrap_or_default()
+}
+
+impl Invoke for TopicOffsets {
+    type E = ReadOnlyTopicError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), ReadOnlyTopicError> {
+        let Self {
+            name,
+            cluster,
+            filter,
+            time,
+        } = self;
+
+        let NamedCluster(cluster_name, cluster_config) = ctx
+            .clusters
+            .cluster_config_or_default_or_select(cluster.as_deref())
+            .change_context(ReadOnlyTopicError::FetchDefaultOrSelect)?;
+
+        let consumer = cluster_config
+            .client_config()
+            .set("group.id", Uuid::new_v4().to_string())
+            .set_log_level(RDKafkaLogLevel::Emerg)
+            .create::<BaseConsumer>()
+            .change_context(ReadOnlyTopicError::AdminClient)?;
+
+        let metadata = consumer
+            .fetch_metadata(name.as_deref(), CLIENT_TIMEOUT)
+            .change_context(ReadOnlyTopicError::AdminClient)?;
+
+        let is_match = filter.matcher()?;
+
+        let partitions = metadata
+            .topics()
+            .iter()
+            .filter(|t| t.error().is_none())
+            .filter(|t| match &name {
+                None => is_match(t.name()),
+                Some(name) => t.name() == name,
+            })
+            .flat_map(|t| t.partitions().iter().map(|p| (t.name(), p.id())))
+            .collect::<Vec<_>>();
+
+        if partitions.is_empty() {
+            Err(Report::new(ReadOnlyTopicError::NotExists(
+                name.unwrap_or("no topics match the filters".to_owned()),
+            )))?
+        }
+
+        let times = match time {
+            Some(time) => {
+                let mut tpl = TopicPartitionList::new();
+
+                for (topic, partition) in &partitions {
+                    tpl.add_partition_offset(topic, *partition, Offset::Offset(time))
+                        .change_context(ReadOnlyTopicError::AdminClient)?;
+                }
+
+                consumer
+                    .offsets_for_times(tpl, CLIENT_TIMEOUT)
+                    .change_context(ReadOnlyTopicError::AdminClient)?
+                    .elements()
+                    .iter()
+                    .map(|e| ((e.topic().to_owned(), e.partition()), e.offset()))
+                    .collect::<HashMap<_, _>>()
+            }
+            None => HashMap::new(),
+        };
+
+        let mut rows = Vec::new();
+
+        for (topic, partition) in partitions {
+            let (earliest, latest) = consumer
+                .fetch_watermarks(topic, partition, CLIENT_TIMEOUT)
+                .change_context(ReadOnlyTopicError::AdminClient)?;
+
+            let offset_for_time = match times.get(&(topic.to_owned(), partition)) {
+                Some(Offset::Offset(offset)) => Some(*offset),
+                Some(Offset::End) => Some(latest),
+                _ => None,
+            };
+
+            rows.push(OffsetRow {
+                topic: topic.to_owned(),
+                partition,
+                earliest,
+                latest,
+                messages: latest - earliest,
+                offset_for_time,
+            });
+        }
+
+        rows.sort_by(|a, b| (&a.topic, a.partition).cmp(&(&b.topic, b.partition)));
+
+        let display = match global_args.out {
+            Output::Human => {
+                let mut table = Table::new(&rows);
+
+                table
+                    .with(Style::modern_rounded())
+                    .with(Panel::footer(format!(
+                        "Count: {}, Messages: {}, Cluster: {}",
+                        rows.len(),
+                        rows.iter().map(|r| r.messages).sum::<i64>(),
+                        cluster_name
+                    )));
+
+                if time.is_none() {
+                    table.with(Remove::column(ByColumnName::new("offset_for_time")));
+                }
+
+                table.to_string()
+            }
+            out => out
+                .output_string(&rows)
+                .change_context(ReadOnlyTopicError::Output)?,
+        };
+
+        println!("{}", display);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,281 @@
+use std::{collections::HashMap, fs::File, io::BufReader, path::PathBuf, time::Duration};
+
+use clap::{ArgGroup, Args, ValueHint};
+use error_stack::{Report, ResultExt};
+use rdkafka::{
+    config::RDKafkaLogLevel,
+    consumer::{BaseConsumer, Consumer},
+    Offset, TopicPartitionList,
+};
+use serde::{Deserialize, Serialize};
+use tabled::Tabled;
+use uuid::Uuid;
+
+use crate::{
+    cli::{
+        util::{get_user_input_confirmation, parse_timestamp, print_rows},
+        GlobalArgs, Invoke,
+    },
+    config::{clusters::NamedCluster, Context},
+    error::cli::config::topic::TruncateTopicError,
+    io::admin::delete_records,
+};
+
+const CLIENT_TIMEOUT: Duration = Duration::from_millis(2500);
+const DELETE_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Args)]
+#[command(group(
+    ArgGroup::new("target")
+        .required(true)
+        .args(["before_offset", "before_time", "offsets_file"])
+))]
+pub(super) struct TruncateTopic {
+    #[arg(index = 1, help = "Topic to delete records from.")]
+    topic: String,
+    #[arg(short, long, help = "Target cluster.")]
+    cluster: Option<String>,
+    #[arg(
+        short,
+        long,
+        value_delimiter = ',',
+        conflicts_with = "offsets_file",
+        help = "Partitions to truncate, defaults to all partitions."
+    )]
+    partition: Vec<i32>,
+    #[arg(
+        long,
+        allow_negative_numbers = true,
+        help = "Delete records before this offset, -1 deletes up to the high watermark."
+    )]
+    before_offset: Option<i64>,
+    #[arg(
+        long,
+        value_parser = parse_timestamp,
+        help = "Delete records before this time, as epoch millis or RFC 3339."
+    )]
+    before_time: Option<i64>,
+    #[arg(
+        long,
+        value_hint = ValueHint::FilePath,
+        help = "JSON offsets file in the kafka-delete-records format."
+    )]
+    offsets_file: Option<PathBuf>,
+    #[arg(short, long, help = "Skip the confirmation prompt.")]
+    yes: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct OffsetsFile {
+    partitions: Vec<OffsetsFileEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OffsetsFileEntry {
+    topic: String,
+    partition: i32,
+    offset: i64,
+}
+
+#[derive(Debug, Serialize, Tabled)]
+struct TruncatePlanRow {
+    partition: i32,
+    low_watermark: i64,
+    new_low_watermark: i64,
+    high_watermark: i64,
+    deleted_records: i64,
+}
+
+#[derive(Debug, Serialize, Tabled)]
+struct TruncateResultRow {
+    partition: i32,
+    low_watermark: i64,
+    #[tabled(display_with = "display_error")]
+    error: Option<String>,
+}
+
+fn display_error(error: &Option<String>) -> String {
+    error.clone().unwrap_or_default()
+}
+
+impl Invoke for TruncateTopic {
+    type E = TruncateTopicError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), TruncateTopicError> {
+        let Self {
+            topic,
+            cluster,
+            partition,
+            before_offset,
+            before_time,
+            offsets_file,
+            yes,
+        } = self;
+
+        let NamedCluster(cluster_name, cluster_config) = ctx
+            .clusters
+            .cluster_config_or_default_or_select(cluster.as_deref())
+            .change_context(TruncateTopicError::FetchCluster)?;
+
+        let consumer = cluster_config
+            .client_config()
+            .set("group.id", Uuid::new_v4().to_string())
+            .set_log_level(RDKafkaLogLevel::Emerg)
+            .create::<BaseConsumer>()
+            .change_context(TruncateTopicError::CreateClient)?;
+
+        let metadata = consumer
+            .fetch_metadata(Some(&topic), CLIENT_TIMEOUT)
+            .change_context(TruncateTopicError::Metadata(topic.clone()))?;
+
+        let available = metadata
+            .topics()
+            .iter()
+            .find(|t| t.name() == topic && t.error().is_none())
+            .ok_or(Report::new(TruncateTopicError::TopicNotExists(
+                topic.clone(),
+            )))?
+            .partitions()
+            .iter()
+            .map(|p| p.id())
+            .collect::<Vec<_>>();
+
+        let requested = match &offsets_file {
+            Some(path) => {
+                let file = File::open(path)
+                    .change_context(TruncateTopicError::ReadFile(path.display().to_string()))?;
+
+                let offsets: OffsetsFile = serde_json::from_reader(BufReader::new(file))
+                    .change_context(TruncateTopicError::ReadFile(path.display().to_string()))?;
+
+                if let Some(other) = offsets.partitions.iter().find(|e| e.topic != topic) {
+                    Err(Report::new(TruncateTopicError::TopicMismatch(
+                        other.topic.clone(),
+                    )))?
+                }
+
+                offsets
+                    .partitions
+                    .into_iter()
+                    .map(|e| (e.partition, Some(e.offset)))
+                    .collect::<Vec<_>>()
+            }
+            None if partition.is_empty() => available.iter().map(|p| (*p, before_offset)).collect(),
+            None => partition.iter().map(|p| (*p, before_offset)).collect(),
+        };
+
+        if let Some((missing, _)) = requested.iter().find(|(p, _)| !available.contains(p)) {
+            Err(Report::new(TruncateTopicError::PartitionNotExists(
+                *missing,
+            )))?
+        }
+
+        let times = match before_time {
+            Some(time) => {
+                let mut tpl = TopicPartitionList::new();
+
+                for (partition, _) in &requested {
+                    tpl.add_partition_offset(&topic, *partition, Offset::Offset(time))
+                        .change_context(TruncateTopicError::OffsetsForTimes(time))?;
+                }
+
+                consumer
+                    .offsets_for_times(tpl, CLIENT_TIMEOUT)
+                    .change_context(TruncateTopicError::OffsetsForTimes(time))?
+                    .elements()
+                    .iter()
+                    .map(|e| (e.partition(), e.offset()))
+                    .collect::<HashMap<_, _>>()
+            }
+            None => HashMap::new(),
+        };
+
+        let mut plan = Vec::new();
+
+        for (partition, offset) in requested {
+            let (low, high) = consumer
+                .fetch_watermarks(&topic, partition, CLIENT_TIMEOUT)
+                .change_context(TruncateTopicError::Metadata(topic.clone()))?;
+
+            let target = match (offset, before_time) {
+                (Some(-1), _) => high,
+                (Some(offset), _) => offset,
+                (None, Some(time)) => match times.get(&partition) {
+                    Some(Offset::Offset(offset)) => *offset,
+                    Some(Offset::End) => high,
+                    _ => Err(Report::new(TruncateTopicError::OffsetsForTimes(time)))?,
+                },
+                (None, None) => unreachable!("clap requires a truncation target"),
+            };
+
+            if target < 0 || target > high {
+                Err(Report::new(TruncateTopicError::OffsetOutOfRange {
+                    partition,
+                    offset: target,
+                    high,
+                }))?
+            }
+
+            plan.push(TruncatePlanRow {
+                partition,
+                low_watermark: low,
+                new_low_watermark: target.max(low),
+                high_watermark: high,
+                deleted_records: (target - low).max(0),
+            });
+        }
+
+        plan.sort_by_key(|p| p.partition);
+
+        print_rows(global_args.out, &plan, None, TruncateTopicError::Output)?;
+
+        let deleted = plan.iter().map(|p| p.deleted_records).sum::<i64>();
+
+        if deleted == 0 {
+            return Ok(());
+        }
+
+        if !yes
+            && !get_user_input_confirmation(&format!(
+                "Are you sure you want to delete {} records from '{}' on '{}'?",
+                deleted, topic, cluster_name
+            ))
+            .change_context(TruncateTopicError::InputError("confirmation"))?
+        {
+            return Ok(());
+        }
+
+        let mut tpl = TopicPartitionList::new();
+
+        for row in plan.iter().filter(|p| p.deleted_records > 0) {
+            tpl.add_partition_offset(&topic, row.partition, Offset::Offset(row.new_low_watermark))
+                .change_context(TruncateTopicError::DeleteRecords)?;
+        }
+
+        let mut results = delete_records(consumer.client(), &tpl, DELETE_TIMEOUT)
+            .change_context(TruncateTopicError::DeleteRecords)?
+            .into_iter()
+            .map(|r| TruncateResultRow {
+                partition: r.partition,
+                low_watermark: r.offset,
+                error: r.error,
+            })
+            .collect::<Vec<_>>();
+
+        results.sort_by_key(|r| r.partition);
+
+        print_rows(global_args.out, &results, None, TruncateTopicError::Output)?;
+
+        let failed = results.iter().filter(|r| r.error.is_some()).count();
+
+        if failed > 0 {
+            Err(Report::new(TruncateTopicError::PartitionsFailed(failed)))?
+        }
+
+        Ok(())
+    }
+}
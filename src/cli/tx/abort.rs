@@ -0,0 +1,203 @@
+use std::collections::BTreeMap;
+
+use clap::{ArgGroup, Args};
+use error_stack::{Report, ResultExt};
+use serde::Serialize;
+use tabled::{
+    settings::{Panel, Style},
+    Table, Tabled,
+};
+
+use crate::{
+    cli::{util::get_user_input_confirmation, GlobalArgs, Invoke},
+    config::{clusters::NamedCluster, Context},
+    error::cli::tx::TxError,
+    io::{
+        output::Output,
+        protocol::{
+            error_string,
+            transactions::{DescribeProducersRequest, WriteTxnMarkersRequest},
+            ProtocolClient,
+        },
+    },
+};
+
+use super::{create_consumer, partition_leader, REQUEST_TIMEOUT};
+
+#[derive(Debug, Args)]
+#[command(group(
+    ArgGroup::new("producer")
+        .required(true)
+        .args(["start_offset", "producer_id"])
+))]
+pub(super) struct TxAbort {
+    #[arg(short, long, help = "Target cluster.")]
+    cluster: Option<String>,
+    #[arg(short, long, help = "Topic of the open transaction.")]
+    topic: String,
+    #[arg(short, long, help = "Partition of the open transaction.")]
+    partition: i32,
+    #[arg(
+        long,
+        help = "First offset of the open transaction, used to look up its producer."
+    )]
+    start_offset: Option<i64>,
+    #[arg(
+        long,
+        requires_all = ["producer_epoch", "coordinator_epoch"],
+        help = "Producer id of the open transaction."
+    )]
+    producer_id: Option<i64>,
+    #[arg(
+        long,
+        requires = "producer_id",
+        help = "Producer epoch of the open transaction."
+    )]
+    producer_epoch: Option<i16>,
+    #[arg(
+        long,
+        requires = "producer_id",
+        help = "Coordinator epoch of the open transaction."
+    )]
+    coordinator_epoch: Option<i32>,
+    #[arg(short, long, help = "Skip the confirmation prompt.")]
+    yes: bool,
+}
+
+#[derive(Debug, Serialize, Tabled)]
+struct AbortRow {
+    topic: String,
+    partition: i32,
+    producer_id: i64,
+    producer_epoch: i16,
+    coordinator_epoch: i32,
+    result: String,
+}
+
+impl Invoke for TxAbort {
+    type E = TxError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), TxError> {
+        let Self {
+            cluster,
+            topic,
+            partition,
+            start_offset,
+            producer_id,
+            producer_epoch,
+            coordinator_epoch,
+            yes,
+        } = self;
+
+        let NamedCluster(cluster_name, cluster_config) = ctx
+            .clusters
+            .cluster_config_or_default_or_select(cluster.as_deref())
+            .change_context(TxError::FetchCluster)?;
+
+        let consumer = create_consumer(cluster_config)?;
+        let leader = partition_leader(&consumer, &topic, partition)?;
+
+        let mut client = ProtocolClient::connect(cluster_config, REQUEST_TIMEOUT)
+            .change_context(TxError::Protocol)?;
+
+        let topics = BTreeMap::from([(topic.clone(), vec![partition])]);
+
+        let (producer_id, producer_epoch, coordinator_epoch) = match start_offset {
+            Some(start_offset) => {
+                let producers = client
+                    .send(
+                        leader,
+                        &DescribeProducersRequest {
+                            topics: topics.clone(),
+                        },
+                    )
+                    .change_context(TxError::Protocol)?
+                    .into_iter()
+                    .next()
+                    .ok_or(Report::new(TxError::PartitionNotExists(
+                        topic.clone(),
+                        partition,
+                    )))?;
+
+                if let Some(error) = error_string(producers.error_code) {
+                    Err(Report::new(TxError::Rejected(
+                        producers.error_message.unwrap_or(error),
+                    )))?
+                }
+
+                let producer = producers
+                    .producers
+                    .into_iter()
+                    .find(|p| p.current_txn_start_offset == start_offset)
+                    .ok_or(Report::new(TxError::NoOpenTransaction(start_offset)))?;
+
+                (
+                    producer.producer_id,
+                    producer.producer_epoch as i16,
+                    producer.coordinator_epoch,
+                )
+            }
+            None => (
+                producer_id.expect("Required by the producer group."),
+                producer_epoch.expect("Required by producer id."),
+                coordinator_epoch.expect("Required by producer id."),
+            ),
+        };
+
+        if !yes
+            && !get_user_input_confirmation(&format!(
+                "Abort the transaction of producer {} (epoch {}) on {}-{} in '{}'?",
+                producer_id, producer_epoch, topic, partition, cluster_name
+            ))
+            .change_context(TxError::InputError("confirmation"))?
+        {
+            return Ok(());
+        }
+
+        let results = client
+            .send(
+                leader,
+                &WriteTxnMarkersRequest {
+                    producer_id,
+                    producer_epoch,
+                    coordinator_epoch,
+                    topics,
+                },
+            )
+            .change_context(TxError::Protocol)?;
+
+        let error = results.iter().find_map(|r| error_string(r.error_code));
+
+        let row = AbortRow {
+            topic,
+            partition,
+            producer_id,
+            producer_epoch,
+            coordinator_epoch,
+            result: error.clone().unwrap_or_else(|| "aborted".to_owned()),
+        };
+
+        let display = match global_args.out {
+            Output::Human => Table::new([&row])
+                .with(Style::modern_rounded())
+                .with(Panel::footer(format!(
+                    "Leader: {}, Cluster: {}",
+                    leader, cluster_name
+                )))
+                .to_string(),
+            out => out.output_string(&row).change_context(TxError::Output)?,
+        };
+
+        println!("{}", display);
+
+        if let Some(error) = error {
+            Err(Report::new(TxError::Rejected(error)))?
+        }
+
+        Ok(())
+    }
+}
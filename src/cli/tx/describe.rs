@@ -0,0 +1,129 @@
+use clap::Args;
+use error_stack::{Report, ResultExt};
+use serde::Serialize;
+use tabled::{
+    settings::{Panel, Style},
+    Table, Tabled,
+};
+
+use crate::{
+    cli::{GlobalArgs, Invoke},
+    config::{clusters::NamedCluster, Context},
+    error::cli::tx::TxError,
+    io::{
+        output::Output,
+        protocol::{
+            coordinator::FindTransactionCoordinatorRequest, error_string,
+            transactions::DescribeTransactionsRequest, ProtocolClient,
+        },
+    },
+};
+
+use super::{display_timestamp, REQUEST_TIMEOUT};
+
+#[derive(Debug, Args)]
+pub(super) struct TxDescribe {
+    #[arg(index = 1, help = "Transactional id to describe.")]
+    transactional_id: String,
+    #[arg(short, long, help = "Target cluster.")]
+    cluster: Option<String>,
+}
+
+#[derive(Debug, Serialize, Tabled)]
+struct DescribeRow {
+    transactional_id: String,
+    coordinator: i32,
+    state: String,
+    producer_id: i64,
+    producer_epoch: i16,
+    timeout_ms: i32,
+    #[tabled(display_with = "display_timestamp")]
+    start_time_ms: i64,
+    #[tabled(display_with = "display_partitions")]
+    partitions: Vec<(String, i32)>,
+}
+
+fn display_partitions(partitions: &[(String, i32)]) -> String {
+    partitions
+        .iter()
+        .map(|(t, p)| format!("{}-{}", t, p))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+impl Invoke for TxDescribe {
+    type E = TxError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), TxError> {
+        let Self {
+            transactional_id,
+            cluster,
+        } = self;
+
+        let NamedCluster(cluster_name, cluster_config) = ctx
+            .clusters
+            .cluster_config_or_default_or_select(cluster.as_deref())
+            .change_context(TxError::FetchCluster)?;
+
+        let mut client = ProtocolClient::connect(cluster_config, REQUEST_TIMEOUT)
+            .change_context(TxError::Protocol)?;
+
+        // Any broker can answer where a transactional id is coordinated.
+        let coordinator = client
+            .send_to_controller(&FindTransactionCoordinatorRequest {
+                transactional_id: &transactional_id,
+            })
+            .change_context(TxError::Protocol)?;
+
+        if let Some(error) = error_string(coordinator.error_code) {
+            Err(Report::new(TxError::Rejected(
+                coordinator.error_message.unwrap_or(error),
+            )))?
+        }
+
+        let description = client
+            .send(
+                coordinator.node_id,
+                &DescribeTransactionsRequest {
+                    transactional_ids: vec![transactional_id],
+                },
+            )
+            .change_context(TxError::Protocol)?
+            .into_iter()
+            .next()
+            .ok_or(Report::new(TxError::Rejected(
+                "empty describe response".to_owned(),
+            )))?;
+
+        if let Some(error) = error_string(description.error_code) {
+            Err(Report::new(TxError::Rejected(error)))?
+        }
+
+        let row = DescribeRow {
+            transactional_id: description.transactional_id,
+            coordinator: coordinator.node_id,
+            state: description.state,
+            producer_id: description.producer_id,
+            producer_epoch: description.producer_epoch,
+            timeout_ms: description.timeout_ms,
+            start_time_ms: description.start_time_ms,
+            partitions: description.partitions,
+        };
+
+        let display = match global_args.out {
+            Output::Human => Table::new([&row])
+                .with(Style::modern_rounded())
+                .with(Panel::footer(format!("Cluster: {}", cluster_name)))
+                .to_string(),
+            out => out.output_string(&row).change_context(TxError::Output)?,
+        };
+
+        println!("{}", display);
+
+        Ok(())
+    }
+}
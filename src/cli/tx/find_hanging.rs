@@ -0,0 +1,224 @@
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::Utc;
+use clap::Args;
+use error_stack::{Report, ResultExt};
+use rdkafka::consumer::Consumer;
+use serde::Serialize;
+use tabled::{
+    settings::{Panel, Style},
+    Table, Tabled,
+};
+
+use crate::{
+    cli::{GlobalArgs, Invoke},
+    config::{clusters::NamedCluster, Context},
+    error::cli::tx::TxError,
+    io::{
+        output::Output,
+        protocol::{
+            coordinator::FindTransactionCoordinatorRequest,
+            error_string,
+            transactions::{ActiveProducer, DescribeProducersRequest, DescribeTransactionsRequest},
+            ProtocolClient,
+        },
+    },
+};
+
+use super::{
+    create_consumer, display_timestamp, list_transactions, CLIENT_TIMEOUT, REQUEST_TIMEOUT,
+};
+
+const ONGOING: &str = "Ongoing";
+const NO_TRANSACTION: i64 = -1;
+
+#[derive(Debug, Args)]
+pub(super) struct TxFindHanging {
+    #[arg(short, long, help = "Target cluster.")]
+    cluster: Option<String>,
+    #[arg(
+        short,
+        long,
+        required = true,
+        help = "Broker id whose replicas to check."
+    )]
+    broker: i32,
+    #[arg(
+        long,
+        default_value_t = 15,
+        help = "Transactions open longer than this many minutes are checked with their coordinator."
+    )]
+    max_transaction_timeout: i64,
+}
+
+#[derive(Debug, Serialize, Tabled)]
+struct HangingRow {
+    topic: String,
+    partition: i32,
+    producer_id: i64,
+    producer_epoch: i32,
+    coordinator_epoch: i32,
+    start_offset: i64,
+    #[tabled(display_with = "display_timestamp")]
+    last_timestamp: i64,
+    duration_minutes: i64,
+}
+
+impl Invoke for TxFindHanging {
+    type E = TxError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), TxError> {
+        let Self {
+            cluster,
+            broker,
+            max_transaction_timeout,
+        } = self;
+
+        let NamedCluster(cluster_name, cluster_config) = ctx
+            .clusters
+            .cluster_config_or_default_or_select(cluster.as_deref())
+            .change_context(TxError::FetchCluster)?;
+
+        let consumer = create_consumer(cluster_config)?;
+
+        let metadata = consumer
+            .fetch_metadata(None, CLIENT_TIMEOUT)
+            .change_context(TxError::Metadata)?;
+
+        if !metadata.brokers().iter().any(|b| b.id() == broker) {
+            Err(Report::new(TxError::BrokerNotExists(broker)))?
+        }
+
+        let mut topics = BTreeMap::<String, Vec<i32>>::new();
+
+        for topic in metadata.topics().iter().filter(|t| t.error().is_none()) {
+            for partition in topic.partitions() {
+                if partition.replicas().contains(&broker) {
+                    topics
+                        .entry(topic.name().to_owned())
+                        .or_default()
+                        .push(partition.id());
+                }
+            }
+        }
+
+        let mut client = ProtocolClient::connect(cluster_config, REQUEST_TIMEOUT)
+            .change_context(TxError::Protocol)?;
+
+        // Followers answer too when asked directly, which lets a single broker
+        // be checked for replicas it has been left holding.
+        let partitions = match topics.is_empty() {
+            true => Vec::new(),
+            false => client
+                .send(broker, &DescribeProducersRequest { topics })
+                .change_context(TxError::Protocol)?,
+        };
+
+        let now = Utc::now().timestamp_millis();
+        let max_duration = max_transaction_timeout * 60 * 1000;
+
+        let mut candidates = Vec::<(String, i32, ActiveProducer)>::new();
+
+        for partition in partitions {
+            if let Some(error) = error_string(partition.error_code) {
+                Err(Report::new(TxError::Rejected(
+                    partition.error_message.unwrap_or(error),
+                )))
+                .attach_printable(format!("{}-{}", partition.topic, partition.partition))?
+            }
+
+            candidates.extend(
+                partition
+                    .producers
+                    .into_iter()
+                    .filter(|p| p.current_txn_start_offset != NO_TRANSACTION)
+                    .filter(|p| now - p.last_timestamp > max_duration)
+                    .map(|p| (partition.topic.clone(), partition.partition, p)),
+            );
+        }
+
+        // An open transaction is only hanging if its coordinator no longer
+        // has it ongoing for this partition with the same producer epoch.
+        let listings = match candidates.is_empty() {
+            true => Vec::new(),
+            false => list_transactions(
+                &mut client,
+                Vec::new(),
+                candidates.iter().map(|(_, _, p)| p.producer_id).collect(),
+            )?,
+        };
+
+        let mut descriptions = HashMap::new();
+
+        for (_, listing) in listings {
+            let coordinator = client
+                .send_to_controller(&FindTransactionCoordinatorRequest {
+                    transactional_id: &listing.transactional_id,
+                })
+                .change_context(TxError::Protocol)?;
+
+            if error_string(coordinator.error_code).is_some() {
+                continue;
+            }
+
+            let description = client
+                .send(
+                    coordinator.node_id,
+                    &DescribeTransactionsRequest {
+                        transactional_ids: vec![listing.transactional_id],
+                    },
+                )
+                .change_context(TxError::Protocol)?
+                .into_iter()
+                .find(|d| error_string(d.error_code).is_none());
+
+            if let Some(description) = description {
+                descriptions.insert(listing.producer_id, description);
+            }
+        }
+
+        let rows = candidates
+            .into_iter()
+            .filter(|(topic, partition, producer)| {
+                !descriptions.get(&producer.producer_id).is_some_and(|d| {
+                    d.state == ONGOING
+                        && d.producer_epoch as i32 == producer.producer_epoch
+                        && d.partitions
+                            .iter()
+                            .any(|(t, p)| t == topic && p == partition)
+                })
+            })
+            .map(|(topic, partition, producer)| HangingRow {
+                topic,
+                partition,
+                producer_id: producer.producer_id,
+                producer_epoch: producer.producer_epoch,
+                coordinator_epoch: producer.coordinator_epoch,
+                start_offset: producer.current_txn_start_offset,
+                last_timestamp: producer.last_timestamp,
+                duration_minutes: (now - producer.last_timestamp) / 60_000,
+            })
+            .collect::<Vec<_>>();
+
+        let display = match global_args.out {
+            Output::Human => Table::new(&rows)
+                .with(Style::modern_rounded())
+                .with(Panel::footer(format!(
+                    "Hanging: {}, Broker: {}, Cluster: {}",
+                    rows.len(),
+                    broker,
+                    cluster_name
+                )))
+                .to_string(),
+            out => out.output_string(&rows).change_context(TxError::Output)?,
+        };
+
+        println!("{}", display);
+
+        Ok(())
+    }
+}
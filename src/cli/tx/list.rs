@@ -0,0 +1,96 @@
+use clap::Args;
+use error_stack::ResultExt;
+use serde::Serialize;
+use tabled::{
+    settings::{Panel, Style},
+    Table, Tabled,
+};
+
+use crate::{
+    cli::{GlobalArgs, Invoke},
+    config::{clusters::NamedCluster, Context},
+    error::cli::tx::TxError,
+    io::{output::Output, protocol::ProtocolClient},
+};
+
+use super::{list_transactions, REQUEST_TIMEOUT};
+
+#[derive(Debug, Args)]
+pub(super) struct TxList {
+    #[arg(short, long, help = "Target cluster.")]
+    cluster: Option<String>,
+    #[arg(
+        short,
+        long,
+        value_delimiter = ',',
+        help = "Only list transactions in these states, e.g. Ongoing,PrepareAbort."
+    )]
+    state: Vec<String>,
+    #[arg(
+        short,
+        long,
+        value_delimiter = ',',
+        help = "Only list transactions of these producer ids."
+    )]
+    producer_id: Vec<i64>,
+}
+
+#[derive(Debug, Serialize, Tabled)]
+struct ListRow {
+    transactional_id: String,
+    producer_id: i64,
+    state: String,
+    coordinator: i32,
+}
+
+impl Invoke for TxList {
+    type E = TxError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), TxError> {
+        let Self {
+            cluster,
+            state,
+            producer_id,
+        } = self;
+
+        let NamedCluster(cluster_name, cluster_config) = ctx
+            .clusters
+            .cluster_config_or_default_or_select(cluster.as_deref())
+            .change_context(TxError::FetchCluster)?;
+
+        let mut client = ProtocolClient::connect(cluster_config, REQUEST_TIMEOUT)
+            .change_context(TxError::Protocol)?;
+
+        let mut rows = list_transactions(&mut client, state, producer_id)?
+            .into_iter()
+            .map(|(coordinator, t)| ListRow {
+                transactional_id: t.transactional_id,
+                producer_id: t.producer_id,
+                state: t.state,
+                coordinator,
+            })
+            .collect::<Vec<_>>();
+
+        rows.sort_by(|a, b| a.transactional_id.cmp(&b.transactional_id));
+
+        let display = match global_args.out {
+            Output::Human => Table::new(&rows)
+                .with(Style::modern_rounded())
+                .with(Panel::footer(format!(
+                    "Count: {}, Cluster: {}",
+                    rows.len(),
+                    cluster_name
+                )))
+                .to_string(),
+            out => out.output_string(&rows).change_context(TxError::Output)?,
+        };
+
+        println!("{}", display);
+
+        Ok(())
+    }
+}
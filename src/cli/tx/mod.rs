@@ -0,0 +1,164 @@
+use std::time::Duration;
+
+use abort::TxAbort;
+use chrono::DateTime;
+use clap::{Args, Subcommand};
+use describe::TxDescribe;
+use error_stack::{Report, ResultExt};
+use find_hanging::TxFindHanging;
+use list::TxList;
+use rdkafka::{
+    config::RDKafkaLogLevel,
+    consumer::{BaseConsumer, Consumer},
+};
+use uuid::Uuid;
+
+use crate::{
+    config::{clusters::ClusterConfig, Context},
+    error::cli::{tx::TxError, ExecutionError},
+    io::protocol::{
+        error_string,
+        transactions::{ListTransactionsRequest, TransactionListing},
+        ProtocolClient,
+    },
+};
+
+use super::{GlobalArgs, Invoke};
+
+mod abort;
+mod describe;
+mod find_hanging;
+mod list;
+
+const CLIENT_TIMEOUT: Duration = Duration::from_millis(2500);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+const NO_LEADER: i32 = -1;
+
+#[derive(Args, Debug)]
+pub(super) struct TxCommand {
+    #[command(subcommand)]
+    command: TxSubCommand,
+}
+
+#[derive(Subcommand, Debug)]
+enum TxSubCommand {
+    #[command(about = "Abort an open transaction on a partition.")]
+    Abort(TxAbort),
+    #[command(about = "Describe a transaction by transactional id.")]
+    Describe(TxDescribe),
+    #[command(about = "Find open transactions the coordinator no longer tracks.")]
+    FindHanging(TxFindHanging),
+    #[command(about = "List transactions known to every coordinator.")]
+    List(TxList),
+}
+
+impl Invoke for TxCommand {
+    type E = ExecutionError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), ExecutionError> {
+        match self.command {
+            TxSubCommand::Abort(command) => command
+                .invoke(ctx, global_args)
+                .change_context(ExecutionError::ExecutionFailed("tx abort")),
+            TxSubCommand::Describe(command) => command
+                .invoke(ctx, global_args)
+                .change_context(ExecutionError::ExecutionFailed("tx describe")),
+            TxSubCommand::FindHanging(command) => command
+                .invoke(ctx, global_args)
+                .change_context(ExecutionError::ExecutionFailed("tx find-hanging")),
+            TxSubCommand::List(command) => command
+                .invoke(ctx, global_args)
+                .change_context(ExecutionError::ExecutionFailed("tx list")),
+        }
+    }
+}
+
+fn create_consumer(cluster_config: &ClusterConfig) -> error_stack::Result<BaseConsumer, TxError> {
+    cluster_config
+        .client_config()
+        .set("group.id", Uuid::new_v4().to_string())
+        .set_log_level(RDKafkaLogLevel::Emerg)
+        .create::<BaseConsumer>()
+        .change_context(TxError::CreateClient)
+}
+
+/// Returns the leader of a partition from cluster metadata.
+fn partition_leader(
+    consumer: &BaseConsumer,
+    topic: &str,
+    partition: i32,
+) -> error_stack::Result<i32, TxError> {
+    let metadata = consumer
+        .fetch_metadata(Some(topic), CLIENT_TIMEOUT)
+        .change_context(TxError::Metadata)?;
+
+    let found = metadata
+        .topics()
+        .iter()
+        .filter(|t| t.error().is_none())
+        .flat_map(|t| t.partitions())
+        .find(|p| p.id() == partition)
+        .ok_or(Report::new(TxError::PartitionNotExists(
+            topic.to_owned(),
+            partition,
+        )))?;
+
+    if found.leader() == NO_LEADER {
+        Err(Report::new(TxError::NoLeader(topic.to_owned(), partition)))?
+    }
+
+    Ok(found.leader())
+}
+
+/// Every broker coordinates a share of transactional ids, so listing needs to
+/// ask each of them. Returns listings paired with their coordinator.
+fn list_transactions(
+    client: &mut ProtocolClient,
+    state_filters: Vec<String>,
+    producer_id_filters: Vec<i64>,
+) -> error_stack::Result<Vec<(i32, TransactionListing)>, TxError> {
+    let brokers = client.brokers().keys().copied().collect::<Vec<_>>();
+    let mut listings = Vec::new();
+
+    for broker in brokers {
+        let response = client
+            .send(
+                broker,
+                &ListTransactionsRequest {
+                    state_filters: state_filters.clone(),
+                    producer_id_filters: producer_id_filters.clone(),
+                },
+            )
+            .change_context(TxError::Protocol)
+            .attach_printable_lazy(|| format!("Broker: {}", broker))?;
+
+        if let Some(error) = error_string(response.error_code) {
+            Err(Report::new(TxError::Rejected(error)))
+                .attach_printable(format!("Broker: {}", broker))?
+        }
+
+        if !response.unknown_state_filters.is_empty() {
+            Err(Report::new(TxError::Rejected(format!(
+                "unknown states: {}",
+                response.unknown_state_filters.join(",")
+            ))))?
+        }
+
+        listings.extend(response.transactions.into_iter().map(|t| (broker, t)));
+    }
+
+    Ok(listings)
+}
+
+fn display_timestamp(millis: &i64) -> String {
+    match *millis {
+        millis if millis < 0 => String::new(),
+        millis => DateTime::from_timestamp_millis(millis)
+            .map(|t| t.to_rfc3339())
+            .unwrap_or_default(),
+    }
+}
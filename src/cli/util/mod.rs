@@ -1,9 +1,100 @@
-use error_stack::ResultExt;
+use chrono::DateTime;
+use error_stack::{Context, ResultExt};
+use serde::Serialize;
 use std::{any::type_name, error::Error, fmt::Display, str::FromStr};
+use tabled::{
+    settings::{Panel, Style},
+    Table, Tabled,
+};
 
 use inquire::{Confirm, Select, Text};
 
-use crate::error::cli::util::UserInputError;
+use crate::{error::cli::util::UserInputError, io::output::Output};
+
+pub mod producer;
+
+#[derive(Clone, Copy, Debug)]
+pub struct PartitionMapping {
+    pub source: i32,
+    pub target: i32,
+}
+
+impl FromStr for PartitionMapping {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (source, target) = s
+            .split_once('=')
+            .ok_or(format!("expected 'source=target', got: {s}"))?;
+
+        Ok(Self {
+            source: source
+                .trim()
+                .parse()
+                .map_err(|_| format!("invalid source partition: {source}"))?,
+            target: target
+                .trim()
+                .parse()
+                .map_err(|_| format!("invalid target partition: {target}"))?,
+        })
+    }
+}
+
+pub fn display_replicas(replicas: &[i32]) -> String {
+    format!(
+        "[{}]",
+        replicas
+            .iter()
+            .map(|r| r.to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    )
+}
+
+/// Prints `rows` as a table, or serialised for other outputs, reporting write
+/// failures as `context`.
+pub fn print_rows<T, C>(
+    out: Output,
+    rows: &[T],
+    footer: Option<String>,
+    context: C,
+) -> error_stack::Result<(), C>
+where
+    T: Serialize + Tabled,
+    C: Context,
+{
+    let display = match out {
+        Output::Human => {
+            let mut table = Table::new(rows);
+
+            table.with(Style::modern_rounded());
+
+            if let Some(footer) = footer {
+                table.with(Panel::footer(footer));
+            }
+
+            table.to_string()
+        }
+        out => out.output_string(&rows).change_context(context)?,
+    };
+
+    println!("{}", display);
+
+    Ok(())
+}
+
+pub fn parse_key_value(s: &str) -> Result<(String, String), String> {
+    s.split_once('=')
+        .filter(|(key, _)| !key.trim().is_empty())
+        .map(|(key, value)| (key.trim().to_owned(), value.to_owned()))
+        .ok_or(format!("expected 'key=value', got: {s}"))
+}
+
+pub fn parse_timestamp(s: &str) -> Result<i64, String> {
+    s.parse::<i64>()
+        .or_else(|_| DateTime::parse_from_rfc3339(s).map(|t| t.timestamp_millis()))
+        .map_err(|_| format!("expected epoch millis or an RFC 3339 timestamp, got: {s}"))
+}
 
 pub fn get_user_input_vec<T>(prompt: &str) -> error_stack::Result<Vec<T>, UserInputError>
 where
@@ -0,0 +1,117 @@
+use std::{
+    fmt::Display,
+    str::FromStr,
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+
+use clap::{builder::PossibleValue, ValueEnum};
+use rdkafka::{
+    error::{KafkaError, RDKafkaErrorCode},
+    message::DeliveryResult,
+    producer::{BaseProducer, BaseRecord, ProducerContext},
+    ClientContext,
+};
+
+const QUEUE_FULL_BACKOFF: Duration = Duration::from_millis(100);
+
+#[derive(Default)]
+pub struct DeliveryContext {
+    failed: AtomicU64,
+}
+
+impl DeliveryContext {
+    pub fn failed(&self) -> u64 {
+        self.failed.load(Ordering::Relaxed)
+    }
+}
+
+impl ClientContext for DeliveryContext {}
+
+impl ProducerContext for DeliveryContext {
+    type DeliveryOpaque = ();
+
+    fn delivery(&self, delivery_result: &DeliveryResult<'_>, _: Self::DeliveryOpaque) {
+        if delivery_result.is_err() {
+            self.failed.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+pub fn send_with_backoff<C>(
+    producer: &BaseProducer<C>,
+    mut record: BaseRecord<'_, Vec<u8>, Vec<u8>, C::DeliveryOpaque>,
+) -> Result<(), KafkaError>
+where
+    C: ProducerContext,
+{
+    loop {
+        match producer.send(record) {
+            Ok(_) => break,
+            Err((KafkaError::MessageProduction(RDKafkaErrorCode::QueueFull), rejected)) => {
+                producer.poll(QUEUE_FULL_BACKOFF);
+
+                record = rejected;
+            }
+            Err((e, _)) => return Err(e),
+        }
+    }
+
+    producer.poll(Duration::ZERO);
+
+    Ok(())
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Acks {
+    None,
+    Leader,
+    #[default]
+    All,
+}
+
+impl Acks {
+    pub fn config_value(&self) -> &'static str {
+        match self {
+            Self::None => "0",
+            Self::Leader => "1",
+            Self::All => "all",
+        }
+    }
+}
+
+impl Display for Acks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.to_possible_value()
+            .expect("no values are skipped")
+            .get_name()
+            .fmt(f)
+    }
+}
+
+impl FromStr for Acks {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        for variant in Self::value_variants() {
+            if variant.to_possible_value().unwrap().matches(s, false) {
+                return Ok(*variant);
+            }
+        }
+        Err(format!("invalid variant: {s}"))
+    }
+}
+
+impl ValueEnum for Acks {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[Self::None, Self::Leader, Self::All]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        Some(match self {
+            Self::None => PossibleValue::new("0"),
+            Self::Leader => PossibleValue::new("1"),
+            Self::All => PossibleValue::new("all").alias("-1"),
+        })
+    }
+}
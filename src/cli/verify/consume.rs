@@ -0,0 +1,322 @@
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    time::{Duration, Instant},
+};
+
+use clap::Args;
+use error_stack::{Report, ResultExt};
+use rdkafka::{
+    config::RDKafkaLogLevel,
+    consumer::{BaseConsumer, Consumer},
+    Message, Offset, TopicPartitionList,
+};
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::{
+    cli::{GlobalArgs, Invoke},
+    config::{clusters::NamedCluster, Context},
+    error::cli::verify::VerifyConsumeError,
+};
+
+use super::SequencedRecord;
+
+const CLIENT_TIMEOUT: Duration = Duration::from_millis(2500);
+const POLL_TIMEOUT: Duration = Duration::from_millis(100);
+
+#[derive(Debug, Args)]
+pub(super) struct VerifyConsume {
+    #[arg(index = 1, help = "Topic to verify.")]
+    topic: String,
+    #[arg(short, long, help = "Target cluster to consume from.")]
+    cluster: Option<String>,
+    #[arg(long, help = "Only check records from this run.")]
+    run_id: Option<String>,
+    #[arg(
+        short,
+        long,
+        help = "Join this consumer group instead of reading every partition from the beginning."
+    )]
+    group: Option<String>,
+    #[arg(short, long, help = "Stop after this many records.")]
+    num_records: Option<u64>,
+    #[arg(
+        long,
+        default_value_t = 10,
+        help = "Stop after this many seconds without receiving a record."
+    )]
+    timeout: u64,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum ViolationKind {
+    Gap,
+    Duplicate,
+    Reordered,
+}
+
+#[derive(Debug, Serialize)]
+struct Violation {
+    kind: ViolationKind,
+    run_id: String,
+    key: String,
+    partition: i32,
+    offset: i64,
+    expected: u64,
+    actual: u64,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct PartitionReport {
+    records: u64,
+    gaps: u64,
+    duplicates: u64,
+    reordered: u64,
+}
+
+#[derive(Debug, Serialize)]
+struct VerifyConsumeReport {
+    topic: String,
+    records: u64,
+    unparsed: u64,
+    keys: usize,
+    passed: bool,
+    violations_by_kind: BTreeMap<ViolationKind, u64>,
+    partitions: BTreeMap<i32, PartitionReport>,
+    violations: Vec<Violation>,
+}
+
+#[derive(Default)]
+struct KeyState {
+    next: u64,
+    seen: HashSet<u64>,
+}
+
+impl KeyState {
+    fn check(&mut self, seq: u64) -> Option<(ViolationKind, u64)> {
+        let expected = self.next;
+
+        if !self.seen.insert(seq) {
+            return Some((ViolationKind::Duplicate, expected));
+        }
+
+        if seq < expected {
+            return Some((ViolationKind::Reordered, expected));
+        }
+
+        self.next = seq + 1;
+
+        (seq > expected).then_some((ViolationKind::Gap, expected))
+    }
+}
+
+impl Invoke for VerifyConsume {
+    type E = VerifyConsumeError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), VerifyConsumeError> {
+        let Self {
+            topic,
+            cluster,
+            run_id,
+            group,
+            num_records,
+            timeout,
+        } = self;
+
+        let NamedCluster(_, cluster_config) = ctx
+            .clusters
+            .cluster_config_or_default_or_select(cluster.as_deref())
+            .change_context(VerifyConsumeError::FetchCluster)?;
+
+        let consumer = cluster_config
+            .client_config()
+            .set(
+                "group.id",
+                group.clone().unwrap_or(Uuid::new_v4().to_string()),
+            )
+            .set("enable.auto.commit", "false")
+            .set("auto.offset.reset", "earliest")
+            .set_log_level(RDKafkaLogLevel::Emerg)
+            .create::<BaseConsumer>()
+            .change_context(VerifyConsumeError::CreateConsumer)?;
+
+        if group.is_some() {
+            consumer
+                .subscribe(&[&topic])
+                .change_context(VerifyConsumeError::ConsumerFailure)?;
+        } else {
+            let metadata = consumer
+                .fetch_metadata(Some(&topic), CLIENT_TIMEOUT)
+                .change_context(VerifyConsumeError::Metadata(topic.clone()))?;
+
+            let mut tpl = TopicPartitionList::new();
+
+            for partition in metadata
+                .topics()
+                .iter()
+                .find(|t| t.name() == topic && t.error().is_none())
+                .ok_or(Report::new(VerifyConsumeError::TopicNotExists(
+                    topic.clone(),
+                )))?
+                .partitions()
+            {
+                tpl.add_partition_offset(&topic, partition.id(), Offset::Beginning)
+                    .change_context(VerifyConsumeError::ConsumerFailure)?;
+            }
+
+            consumer
+                .assign(&tpl)
+                .change_context(VerifyConsumeError::ConsumerFailure)?;
+        }
+
+        let timeout = Duration::from_secs(timeout);
+
+        let mut keys = HashMap::<(String, String), KeyState>::new();
+        let mut partitions = BTreeMap::<i32, PartitionReport>::new();
+        let mut violations = Vec::new();
+        let mut records = 0;
+        let mut unparsed = 0;
+        let mut last_record = Instant::now();
+
+        while num_records.is_none_or(|n| records < n) && last_record.elapsed() < timeout {
+            let message = match consumer.poll(POLL_TIMEOUT) {
+                None => continue,
+                Some(Err(e)) => Err(e).change_context(VerifyConsumeError::ConsumerFailure)?,
+                Some(Ok(message)) => message,
+            };
+
+            last_record = Instant::now();
+
+            let Some(record) = message
+                .payload()
+                .and_then(|p| serde_json::from_slice::<SequencedRecord>(p).ok())
+            else {
+                unparsed += 1;
+                continue;
+            };
+
+            if run_id.as_ref().is_some_and(|id| *id != record.run_id) {
+                continue;
+            }
+
+            records += 1;
+
+            let partition = partitions.entry(message.partition()).or_default();
+
+            partition.records += 1;
+
+            let state = keys
+                .entry((record.run_id.clone(), record.key.clone()))
+                .or_default();
+
+            if let Some((kind, expected)) = state.check(record.seq) {
+                match kind {
+                    ViolationKind::Gap => partition.gaps += 1,
+                    ViolationKind::Duplicate => partition.duplicates += 1,
+                    ViolationKind::Reordered => partition.reordered += 1,
+                }
+
+                violations.push(Violation {
+                    kind,
+                    run_id: record.run_id,
+                    key: record.key,
+                    partition: message.partition(),
+                    offset: message.offset(),
+                    expected,
+                    actual: record.seq,
+                });
+            }
+        }
+
+        let mut violations_by_kind = BTreeMap::new();
+
+        for violation in &violations {
+            *violations_by_kind.entry(violation.kind).or_default() += 1;
+        }
+
+        let violation_count = violations.len();
+
+        let display = global_args
+            .out
+            .output_string(&VerifyConsumeReport {
+                topic,
+                records,
+                unparsed,
+                keys: keys.len(),
+                passed: violations.is_empty(),
+                violations_by_kind,
+                partitions,
+                violations,
+            })
+            .change_context(VerifyConsumeError::Output)?;
+
+        println!("{}", display);
+
+        if violation_count > 0 {
+            Err(Report::new(VerifyConsumeError::Violations(violation_count)))?
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{KeyState, ViolationKind};
+
+    type Case = (
+        &'static str,
+        &'static [u64],
+        &'static [Option<(ViolationKind, u64)>],
+    );
+
+    #[test]
+    fn sequences_are_classified() {
+        use ViolationKind::*;
+
+        let cases: &[Case] = &[
+            ("in order", &[0, 1, 2, 3], &[None, None, None, None]),
+            ("gap", &[0, 1, 4, 5], &[None, None, Some((Gap, 2)), None]),
+            (
+                "duplicate",
+                &[0, 1, 1, 2],
+                &[None, None, Some((Duplicate, 2)), None],
+            ),
+            (
+                "out of order",
+                &[0, 2, 1, 3],
+                &[None, Some((Gap, 1)), Some((Reordered, 3)), None],
+            ),
+            (
+                "duplicate after reorder",
+                &[0, 2, 1, 1],
+                &[
+                    None,
+                    Some((Gap, 1)),
+                    Some((Reordered, 3)),
+                    Some((Duplicate, 3)),
+                ],
+            ),
+            (
+                "first seen after zero",
+                &[3, 4, 5],
+                &[Some((Gap, 0)), None, None],
+            ),
+        ];
+
+        for (name, sequence, expected) in cases {
+            let mut state = KeyState::default();
+            let actual = sequence
+                .iter()
+                .map(|seq| state.check(*seq))
+                .collect::<Vec<_>>();
+
+            assert_eq!(actual, *expected, "{}", name);
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use clap::{Args, Subcommand};
+use consume::VerifyConsume;
+use error_stack::ResultExt;
+use produce::VerifyProduce;
+use serde::{Deserialize, Serialize};
+
+use crate::{config::Context, error::cli::ExecutionError};
+
+use super::{GlobalArgs, Invoke};
+
+mod consume;
+mod produce;
+
+#[derive(Args, Debug)]
+pub(super) struct VerifyCommand {
+    #[command(subcommand)]
+    command: VerifySubCommand,
+}
+
+#[derive(Subcommand, Debug)]
+enum VerifySubCommand {
+    #[command(about = "Check sequenced records for gaps, duplicates and reordering.")]
+    Consume(VerifyConsume),
+    #[command(about = "Produce records with per key sequence numbers.")]
+    Produce(VerifyProduce),
+}
+
+impl Invoke for VerifyCommand {
+    type E = ExecutionError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), ExecutionError> {
+        match self.command {
+            VerifySubCommand::Consume(command) => command
+                .invoke(ctx, global_args)
+                .change_context(ExecutionError::ExecutionFailed("verify consume")),
+            VerifySubCommand::Produce(command) => command
+                .invoke(ctx, global_args)
+                .change_context(ExecutionError::ExecutionFailed("verify produce")),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct SequencedRecord {
+    run_id: String,
+    key: String,
+    seq: u64,
+    timestamp: i64,
+}
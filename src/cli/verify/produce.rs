@@ -0,0 +1,162 @@
+use std::time::{Duration, Instant};
+
+use chrono::Utc;
+use clap::Args;
+use error_stack::{Report, ResultExt};
+use log::info;
+use rdkafka::{
+    config::RDKafkaLogLevel,
+    producer::{BaseProducer, BaseRecord, Producer},
+};
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::{
+    cli::{
+        util::producer::{send_with_backoff, Acks, DeliveryContext},
+        GlobalArgs, Invoke,
+    },
+    config::{clusters::NamedCluster, Context},
+    error::cli::verify::VerifyProduceError,
+};
+
+use super::SequencedRecord;
+
+const FLUSH_TIMEOUT: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Args)]
+pub(super) struct VerifyProduce {
+    #[arg(index = 1, help = "Topic to produce to.")]
+    topic: String,
+    #[arg(short, long, help = "Target cluster to produce to.")]
+    cluster: Option<String>,
+    #[arg(short, long, help = "Number of records to produce.")]
+    num_records: u64,
+    #[arg(
+        short,
+        long,
+        default_value_t = 10,
+        help = "Number of distinct keys to spread records over."
+    )]
+    keys: u64,
+    #[arg(long, help = "Identifier for this run, defaults to a random UUID.")]
+    run_id: Option<String>,
+    #[arg(
+        short,
+        long,
+        value_parser = clap::value_parser!(u64).range(1..),
+        help = "Maximum records per second, unthrottled if omitted."
+    )]
+    throughput: Option<u64>,
+    #[arg(
+        long,
+        default_value_t,
+        help = "Acknowledgements required from the broker."
+    )]
+    acks: Acks,
+    #[arg(long, help = "Enable the idempotent producer.")]
+    idempotent: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct VerifyProduceReport {
+    run_id: String,
+    topic: String,
+    keys: u64,
+    sent: u64,
+    acknowledged: u64,
+    failed: u64,
+}
+
+impl Invoke for VerifyProduce {
+    type E = VerifyProduceError;
+
+    fn invoke(
+        self,
+        ctx: &mut Context,
+        global_args: &GlobalArgs,
+    ) -> error_stack::Result<(), VerifyProduceError> {
+        let Self {
+            topic,
+            cluster,
+            num_records,
+            keys,
+            run_id,
+            throughput,
+            acks,
+            idempotent,
+        } = self;
+
+        let NamedCluster(_, cluster_config) = ctx
+            .clusters
+            .cluster_config_or_default_or_select(cluster.as_deref())
+            .change_context(VerifyProduceError::FetchCluster)?;
+
+        let producer = cluster_config
+            .client_config()
+            .set("acks", acks.config_value())
+            .set("enable.idempotence", idempotent.to_string())
+            .set_log_level(RDKafkaLogLevel::Emerg)
+            .create_with_context::<_, BaseProducer<DeliveryContext>>(DeliveryContext::default())
+            .change_context(VerifyProduceError::CreateProducer)?;
+
+        let run_id = run_id.unwrap_or(Uuid::new_v4().to_string());
+        let keys = keys.max(1);
+        let start = Instant::now();
+
+        for record in 0..num_records {
+            let sequenced = SequencedRecord {
+                run_id: run_id.clone(),
+                key: format!("key-{}", record % keys),
+                seq: record / keys,
+                timestamp: Utc::now().timestamp_millis(),
+            };
+
+            let value = serde_json::to_vec(&sequenced)
+                .change_context(VerifyProduceError::Produce(record))?;
+            let key = sequenced.key.into_bytes();
+
+            send_with_backoff(&producer, BaseRecord::to(&topic).key(&key).payload(&value))
+                .change_context(VerifyProduceError::Produce(record))?;
+
+            if let Some(throughput) = throughput {
+                let expected = Duration::from_secs_f64((record + 1) as f64 / throughput as f64);
+                let elapsed = start.elapsed();
+
+                if expected > elapsed {
+                    producer.poll(expected - elapsed);
+                }
+            }
+
+            if (record + 1) % 10_000 == 0 {
+                info!("Sent {} of {} records", record + 1, num_records);
+            }
+        }
+
+        producer
+            .flush(FLUSH_TIMEOUT)
+            .change_context(VerifyProduceError::Flush)?;
+
+        let failed = producer.context().failed();
+
+        let display = global_args
+            .out
+            .output_string(&VerifyProduceReport {
+                run_id,
+                topic,
+                keys,
+                sent: num_records,
+                acknowledged: num_records - failed,
+                failed,
+            })
+            .change_context(VerifyProduceError::Output)?;
+
+        println!("{}", display);
+
+        if failed > 0 {
+            Err(Report::new(VerifyProduceError::Delivery(failed)))?
+        }
+
+        Ok(())
+    }
+}
@@ -1,6 +1,7 @@
 use std::{fmt::Display, str::FromStr};
 
 use clap::{builder::PossibleValue, ValueEnum};
+use rdkafka::ClientConfig;
 use sasl_plain::SaslPlain;
 use sasl_ssl::SaslSsl;
 use serde::{Deserialize, Serialize};
@@ -21,6 +22,16 @@ pub enum AuthType {
     SaslSsl(SaslSsl),
 }
 
+impl AuthType {
+    pub fn configure(&self, config: &mut ClientConfig) {
+        match self {
+            Self::Plain => (),
+            Self::SaslPlain(auth) => auth.configure(config),
+            Self::SaslSsl(auth) => auth.configure(config),
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
 pub enum AuthTypeNames {
     #[default]
@@ -40,7 +51,7 @@ impl FromUserInputForVariant for AuthType {
         match variant {
             AuthTypeNames::Plain => Ok(Self::Plain),
             AuthTypeNames::SaslPlain => SaslPlain::from_user_input().map(AuthType::SaslPlain),
-            AuthTypeNames::SaslSsl => todo!("Todo"),
+            AuthTypeNames::SaslSsl => SaslSsl::from_user_input().map(AuthType::SaslSsl),
         }
     }
 }
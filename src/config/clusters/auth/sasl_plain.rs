@@ -1,3 +1,4 @@
+use rdkafka::ClientConfig;
 use serde::{Deserialize, Serialize};
 
 use crate::{cli::util::get_user_input, config::FromUserInput, error::cli::util::UserInputError};
@@ -11,6 +12,24 @@ pub struct SaslPlain {
     password: String,
 }
 
+impl SaslPlain {
+    pub fn username(&self) -> &str {
+        &self.username
+    }
+
+    pub fn password(&self) -> &str {
+        &self.password
+    }
+
+    pub fn configure(&self, config: &mut ClientConfig) {
+        config
+            .set("security.protocol", "sasl_plaintext")
+            .set("sasl.mechanisms", "PLAIN")
+            .set("sasl.username", &self.username)
+            .set("sasl.password", &self.password);
+    }
+}
+
 impl FromUserInput for SaslPlain {
     type E = UserInputError;
 
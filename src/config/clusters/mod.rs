@@ -3,6 +3,8 @@ use std::collections::HashMap;
 use auth::AuthType;
 use error_stack::{Report, ResultExt};
 use log::warn;
+use rdkafka::ClientConfig;
+use rest::RestEndpoint;
 use serde::{Deserialize, Serialize};
 
 use crate::{
@@ -13,6 +15,7 @@ use crate::{
 use super::ConfigFile;
 
 pub mod auth;
+pub mod rest;
 
 pub(super) const CLUSTER_CONFIG_FILE: &str = "clusters.toml";
 const SELECT_CLUSTER_PROMPT: &str = "Select cluster";
@@ -95,6 +98,19 @@ impl ClustersConfig {
         self.cluster_configs.contains_key(cluster)
     }
 
+    pub fn cluster_config_or_default_or_select(
+        &self,
+        cluster: Option<&str>,
+    ) -> error_stack::Result<NamedCluster<'_>, FetchClusterError> {
+        match cluster {
+            Some(name) => self
+                .cluster_config(name)
+                .map(|cluster_config| NamedCluster(name.to_lowercase(), cluster_config))
+                .ok_or(Report::new(FetchClusterError::NotExists(name.to_owned()))),
+            None => self.cluster_config_default_or_select(),
+        }
+    }
+
     pub fn cluster_config_default_or_select(
         &self,
     ) -> error_stack::Result<NamedCluster<'_>, FetchClusterError> {
@@ -155,6 +171,8 @@ impl ConfigFile for ClustersConfig {
 pub struct ClusterConfig {
     pub bootstrap_servers: Vec<String>,
     pub auth: Option<AuthType>,
+    pub schema_registry: Option<RestEndpoint>,
+    pub connect: Option<RestEndpoint>,
 }
 
 impl ClusterConfig {
@@ -162,10 +180,32 @@ impl ClusterConfig {
         Self {
             bootstrap_servers,
             auth: None,
+            schema_registry: None,
+            connect: None,
         }
     }
 
     pub fn auth(&self) -> Option<&AuthType> {
         self.auth.as_ref()
     }
+
+    pub fn schema_registry(&self) -> Option<&RestEndpoint> {
+        self.schema_registry.as_ref()
+    }
+
+    pub fn connect(&self) -> Option<&RestEndpoint> {
+        self.connect.as_ref()
+    }
+
+    pub fn client_config(&self) -> ClientConfig {
+        let mut config = ClientConfig::new();
+
+        config.set("bootstrap.servers", self.bootstrap_servers.join(","));
+
+        if let Some(auth) = self.auth() {
+            auth.configure(&mut config);
+        }
+
+        config
+    }
 }
@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{cli::util::get_user_input, config::FromUserInput, error::cli::util::UserInputError};
+
+const GET_USERNAME_PROMPT: &str = "Enter username:";
+const GET_PASSWORD_PROMPT: &str = "Enter password:";
+
+/// An HTTP service running alongside the cluster, such as a schema registry.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RestEndpoint {
+    pub url: String,
+    pub auth: Option<BasicAuth>,
+}
+
+impl RestEndpoint {
+    pub fn new(url: String) -> Self {
+        Self { url, auth: None }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BasicAuth {
+    username: String,
+    password: String,
+}
+
+impl BasicAuth {
+    pub fn username(&self) -> &str {
+        &self.username
+    }
+
+    pub fn password(&self) -> &str {
+        &self.password
+    }
+}
+
+impl FromUserInput for BasicAuth {
+    type E = UserInputError;
+
+    fn from_user_input() -> error_stack::Result<Self, Self::E>
+    where
+        Self: Sized,
+    {
+        let username = get_user_input(GET_USERNAME_PROMPT)?;
+        let password = get_user_input(GET_PASSWORD_PROMPT)?;
+
+        Ok(Self { username, password })
+    }
+}
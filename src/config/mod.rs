@@ -26,6 +26,8 @@ pub struct Context {
     pub clusters: ClustersConfig,
     pub topics: TopicsConfig,
     pub profiles: ProfilesConfig,
+    /// Exit code for commands that succeed but report a failed outcome.
+    pub exit_code: i32,
 }
 
 impl Context {
@@ -74,6 +76,7 @@ impl Context {
             clusters,
             topics,
             profiles,
+            exit_code: 0,
         })
     }
 
@@ -0,0 +1,23 @@
+#[derive(Debug, thiserror::Error)]
+pub enum ApplyError {
+    #[error("Failed to get target cluster.")]
+    FetchCluster,
+    #[error("Failed to create client.")]
+    CreateClient,
+    #[error("Failed to read spec file: {0}")]
+    ReadSpec(String),
+    #[error("Failed to read topics of cluster: {0}")]
+    ReadCluster(String),
+    #[error("Failed to describe topic ACLs.")]
+    DescribeAcls,
+    #[error("Failed to compile topic filter.")]
+    Filter,
+    #[error("Admin request failed: {0}")]
+    Admin(&'static str),
+    #[error("Failed to apply {0} changes.")]
+    ChangesFailed(usize),
+    #[error("Failed to get input for args: {0}")]
+    InputError(&'static str),
+    #[error("Error while writing output.")]
+    Output,
+}
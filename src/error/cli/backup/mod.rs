@@ -0,0 +1,47 @@
+#[derive(Debug, thiserror::Error)]
+pub enum BackupError {
+    #[error("Failed to get target cluster.")]
+    FetchCluster,
+    #[error("Failed to create consumer.")]
+    CreateConsumer,
+    #[error("Failed to fetch metadata for topic: {0}")]
+    Metadata(String),
+    #[error("Topic does not exist: {0}")]
+    TopicNotExists(String),
+    #[error("Partition does not exist: {0}")]
+    PartitionNotExists(i32),
+    #[error("Failed to create backup directory: {0}")]
+    CreateDirectory(String),
+    #[error("Failed to get input for args: {0}")]
+    InputError(&'static str),
+    #[error("Backup already exists in: {0}")]
+    AlreadyExists(String),
+    #[error("Consumer failed while reading.")]
+    ConsumerFailure,
+    #[error("Failed to write backup.")]
+    Write,
+    #[error("Error while writing output.")]
+    Output,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RestoreError {
+    #[error("Failed to get target cluster.")]
+    FetchCluster,
+    #[error("Failed to read backup.")]
+    Read,
+    #[error("Failed to create producer.")]
+    CreateProducer,
+    #[error("Failed to fetch metadata for topic: {0}")]
+    Metadata(String),
+    #[error("Topic does not exist: {0}")]
+    TopicNotExists(String),
+    #[error("Target partition does not exist: {0}")]
+    PartitionNotExists(i32),
+    #[error("Failed to produce record from offset: {0}")]
+    Produce(i64),
+    #[error("Failed to deliver {0} records.")]
+    Delivery(u64),
+    #[error("Error while writing output.")]
+    Output,
+}
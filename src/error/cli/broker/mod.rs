@@ -0,0 +1,21 @@
+#[derive(Debug, thiserror::Error)]
+pub enum BrokerError {
+    #[error("Failed to get target cluster.")]
+    FetchCluster,
+    #[error("Failed to create client.")]
+    CreateClient,
+    #[error("Failed to fetch cluster metadata.")]
+    Metadata,
+    #[error("Broker does not exist in cluster: {0}")]
+    BrokerNotExists(i32),
+    #[error("Failed to describe configs.")]
+    DescribeConfigs,
+    #[error("Failed to alter configs.")]
+    AlterConfigs,
+    #[error("Cluster rejected config request for {0}: {1}")]
+    Rejected(String, String),
+    #[error("Failed to get input for args: {0}")]
+    InputError(&'static str),
+    #[error("Error while writing output.")]
+    Output,
+}
@@ -0,0 +1,21 @@
+#[derive(Debug, thiserror::Error)]
+pub enum ClusterError {
+    #[error("Failed to get target cluster.")]
+    FetchCluster,
+    #[error("Failed to create client.")]
+    CreateClient,
+    #[error("Failed to send request to cluster.")]
+    Protocol,
+    #[error("Cluster rejected request: {0}")]
+    Rejected(String),
+    #[error("Failed to fetch committed offsets of group: {0}")]
+    CommittedOffsets(String),
+    #[error("Failed to look up end offsets.")]
+    EndOffsets,
+    #[error("Failed to update features: {0}")]
+    FeaturesFailed(String),
+    #[error("Failed to get input for args: {0}")]
+    InputError(&'static str),
+    #[error("Error while writing output.")]
+    Output,
+}
@@ -39,3 +39,51 @@ pub enum TopicError {
     #[error("Default profile not set.")]
     NotSet,
 }
+
+#[derive(Debug, thiserror::Error)]
+pub enum TruncateTopicError {
+    #[error("Failed to get target cluster.")]
+    FetchCluster,
+    #[error("Failed to create client.")]
+    CreateClient,
+    #[error("Failed to fetch metadata for topic: {0}")]
+    Metadata(String),
+    #[error("Topic does not exist: {0}")]
+    TopicNotExists(String),
+    #[error("Partition does not exist: {0}")]
+    PartitionNotExists(i32),
+    #[error("Failed to read offsets file: {0}")]
+    ReadFile(String),
+    #[error("Offsets file references a different topic: {0}")]
+    TopicMismatch(String),
+    #[error("Failed to look up offsets for timestamp: {0}")]
+    OffsetsForTimes(i64),
+    #[error("Offset {offset} is out of range for partition {partition}, high watermark is {high}")]
+    OffsetOutOfRange {
+        partition: i32,
+        offset: i64,
+        high: i64,
+    },
+    #[error("Failed to get input for args: {0}")]
+    InputError(&'static str),
+    #[error("Failed to delete records.")]
+    DeleteRecords,
+    #[error("Failed to delete records from {0} partitions.")]
+    PartitionsFailed(usize),
+    #[error("Error while writing output.")]
+    Output,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum TopicDiffError {
+    #[error("Failed to get cluster: {0}")]
+    FetchCluster(String),
+    #[error("Failed to create client for cluster: {0}")]
+    CreateClient(String),
+    #[error("Failed to read topics of: {0}")]
+    ReadSpec(String),
+    #[error("Failed to compile topic filter.")]
+    Filter,
+    #[error("Error while writing output.")]
+    Output,
+}
@@ -0,0 +1,17 @@
+#[derive(Debug, thiserror::Error)]
+pub enum ConnectError {
+    #[error("Failed to get target cluster.")]
+    FetchCluster,
+    #[error("No Kafka Connect endpoint configured for cluster: {0}")]
+    NoConnect(String),
+    #[error("Kafka Connect request failed.")]
+    Connect,
+    #[error("Failed to read connector config: {0}")]
+    ReadConfig(String),
+    #[error("Connector name missing, pass it or set 'name' in the config.")]
+    MissingName,
+    #[error("Failed to get input for args: {0}")]
+    InputError(&'static str),
+    #[error("Error while writing output.")]
+    Output,
+}
@@ -16,4 +16,14 @@ pub enum ConsumerError {
     KeyDeserialisationFailure,
     #[error("Failed to deserialise message key.")]
     ValueDeserialisationFailure,
+    #[error("Failed to fetch metadata for topic: {0}")]
+    Metadata(String),
+    #[error("Partition has no leader: {0}-{1}")]
+    NoLeader(String, i32),
+    #[error("Failed to send protocol request.")]
+    Protocol,
+    #[error("Broker rejected request: {0}")]
+    Rejected(String),
+    #[error("Failed to read record batch of partition: {0}-{1}")]
+    CorruptBatch(String, i32),
 }
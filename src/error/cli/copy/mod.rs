@@ -0,0 +1,31 @@
+#[derive(Debug, thiserror::Error)]
+pub enum CopyError {
+    #[error("Failed to get {0} cluster.")]
+    FetchCluster(&'static str),
+    #[error("Failed to create consumer.")]
+    CreateConsumer,
+    #[error("Failed to create producer.")]
+    CreateProducer,
+    #[error("Failed to fetch metadata for topic: {0}")]
+    Metadata(String),
+    #[error("Topic does not exist: {0}")]
+    TopicNotExists(String),
+    #[error("Target partition does not exist: {0}")]
+    PartitionNotExists(i32),
+    #[error("Failed to look up offsets for timestamp: {0}")]
+    OffsetsForTimes(i64),
+    #[error("Failed to compile regex: {0}")]
+    CompileRegex(String),
+    #[error("Consumer failed while reading.")]
+    ConsumerFailure,
+    #[error("Failed to deserialise record at offset: {0}")]
+    Deserialise(i64),
+    #[error("Failed to serialise record at offset: {0}")]
+    Serialise(i64),
+    #[error("Failed to produce record from offset: {0}")]
+    Produce(i64),
+    #[error("Failed to deliver {0} records.")]
+    Delivery(u64),
+    #[error("Error while writing output.")]
+    Output,
+}
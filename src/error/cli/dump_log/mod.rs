@@ -0,0 +1,13 @@
+#[derive(Debug, thiserror::Error)]
+pub enum DumpLogError {
+    #[error("Failed to read file: {0}")]
+    ReadFile(String),
+    #[error("Unsupported segment file, expected '.log', '.index' or '.timeindex': {0}")]
+    UnsupportedFile(String),
+    #[error("Failed to get base offset from file name: {0}")]
+    InvalidFileName(String),
+    #[error("Failed to parse segment file: {0}")]
+    Segment(String),
+    #[error("Error while writing output.")]
+    Output,
+}
@@ -0,0 +1,23 @@
+#[derive(Debug, thiserror::Error)]
+pub enum LeaderElectionError {
+    #[error("Failed to get target cluster.")]
+    FetchCluster,
+    #[error("Failed to create client.")]
+    CreateClient,
+    #[error("Failed to fetch cluster metadata.")]
+    Metadata,
+    #[error("Invalid topic filter.")]
+    Filter,
+    #[error("Failed to read partitions file: {0}")]
+    ReadFile(String),
+    #[error("Partition does not exist: {0}-{1}")]
+    PartitionNotExists(String, i32),
+    #[error("Failed to run leader election.")]
+    Election,
+    #[error("Failed to elect leaders for {0} partitions.")]
+    PartitionsFailed(usize),
+    #[error("Failed to get input for args: {0}")]
+    InputError(&'static str),
+    #[error("Error while writing output.")]
+    Output,
+}
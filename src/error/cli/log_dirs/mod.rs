@@ -0,0 +1,15 @@
+#[derive(Debug, thiserror::Error)]
+pub enum LogDirsError {
+    #[error("Failed to get target cluster.")]
+    FetchCluster,
+    #[error("Failed to build topic filter.")]
+    Filter,
+    #[error("Broker does not exist in cluster: {0}")]
+    BrokerNotExists(i32),
+    #[error("Failed to describe log dirs on broker {0}.")]
+    Protocol(i32),
+    #[error("Failed to connect to cluster.")]
+    Connect,
+    #[error("Error while writing output.")]
+    Output,
+}
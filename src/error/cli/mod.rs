@@ -1,6 +1,23 @@
+pub mod apply;
+pub mod backup;
+pub mod broker;
+pub mod cluster;
 pub mod config;
+pub mod connect;
 pub mod consume;
+pub mod copy;
+pub mod dump_log;
+pub mod leader_election;
+pub mod log_dirs;
+pub mod perf;
+pub mod produce;
+pub mod quota;
+pub mod reassign;
+pub mod schema;
+pub mod top;
+pub mod tx;
 pub mod util;
+pub mod verify;
 
 #[derive(Debug, thiserror::Error)]
 pub enum ExecutionError {
@@ -0,0 +1,55 @@
+#[derive(Debug, thiserror::Error)]
+pub enum PerfProduceError {
+    #[error("Failed to get target cluster.")]
+    FetchCluster,
+    #[error("Failed to create producer.")]
+    CreateProducer,
+    #[error("Failed to produce record.")]
+    Produce,
+    #[error("Failed to flush outstanding records.")]
+    Flush,
+    #[error("Error while writing output.")]
+    Output,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum PerfConsumeError {
+    #[error("Failed to get target cluster.")]
+    FetchCluster,
+    #[error("Failed to create consumer.")]
+    CreateConsumer,
+    #[error("Failed to fetch metadata for topic: {0}")]
+    Metadata(String),
+    #[error("Topic does not exist: {0}")]
+    TopicNotExists(String),
+    #[error("Partition does not exist: {0}")]
+    PartitionNotExists(i32),
+    #[error("Consumer failed while reading.")]
+    ConsumerFailure,
+    #[error("Error while writing output.")]
+    Output,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum PerfE2eError {
+    #[error("Failed to get target cluster.")]
+    FetchCluster,
+    #[error("Failed to create producer.")]
+    CreateProducer,
+    #[error("Failed to create consumer.")]
+    CreateConsumer,
+    #[error("Failed to fetch metadata for topic: {0}")]
+    Metadata(String),
+    #[error("Topic does not exist: {0}")]
+    TopicNotExists(String),
+    #[error("Consumer failed while reading.")]
+    ConsumerFailure,
+    #[error("Failed to produce probe: {0}")]
+    Produce(u64),
+    #[error("Timed out waiting for probe: {0}")]
+    Timeout(u64),
+    #[error("Failed to write histogram to: {0}")]
+    WriteHistogram(String),
+    #[error("Error while writing output.")]
+    Output,
+}
@@ -0,0 +1,29 @@
+#[derive(Debug, thiserror::Error)]
+pub enum ProduceError {
+    #[error("Failed to get target cluster.")]
+    FetchCluster,
+    #[error("Failed to read input: {0}")]
+    ReadInput(String),
+    #[error("Failed to serialise key on line: {0}")]
+    KeySerialisation(u64),
+    #[error("Failed to serialise value on line: {0}")]
+    ValueSerialisation(u64),
+    #[error("Failed to create producer.")]
+    CreateProducer,
+    #[error("Failed to initialise transactions for: {0}")]
+    InitTransactions(String),
+    #[error("Failed to begin transaction: {0}")]
+    BeginTransaction(u64),
+    #[error("Failed to commit transaction: {0}")]
+    CommitTransaction(u64),
+    #[error("Failed to abort transaction: {0}")]
+    AbortTransaction(u64),
+    #[error("Failed to produce record on line: {0}")]
+    Produce(u64),
+    #[error("Failed to flush outstanding records.")]
+    Flush,
+    #[error("Failed to deliver {0} records.")]
+    Delivery(u64),
+    #[error("Error while writing output.")]
+    Output,
+}
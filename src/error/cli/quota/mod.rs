@@ -0,0 +1,17 @@
+#[derive(Debug, thiserror::Error)]
+pub enum QuotaError {
+    #[error("Failed to get target cluster.")]
+    FetchCluster,
+    #[error("No quota entity given, use --user, --client-id, --ip or their defaults.")]
+    MissingEntity,
+    #[error("Failed to send request to cluster.")]
+    Protocol,
+    #[error("Cluster rejected quota request: {0}")]
+    Rejected(String),
+    #[error("Failed to alter quotas for {0} entities.")]
+    EntitiesFailed(usize),
+    #[error("Failed to get input for args: {0}")]
+    InputError(&'static str),
+    #[error("Error while writing output.")]
+    Output,
+}
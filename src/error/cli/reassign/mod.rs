@@ -0,0 +1,37 @@
+#[derive(Debug, thiserror::Error)]
+pub enum ReassignError {
+    #[error("Failed to get target cluster.")]
+    FetchCluster,
+    #[error("Failed to create client.")]
+    CreateClient,
+    #[error("Failed to fetch cluster metadata.")]
+    Metadata,
+    #[error("Topic does not exist: {0}")]
+    TopicNotExists(String),
+    #[error("Broker does not exist in cluster: {0}")]
+    BrokerNotExists(i32),
+    #[error("Failed to read reassignment file: {0}")]
+    ReadFile(String),
+    #[error("Failed to write reassignment file: {0}")]
+    WriteFile(String),
+    #[error("Invalid reassignment plan: {0}")]
+    InvalidPlan(String),
+    #[error("Topic '{topic}' has replication factor {replication_factor} but only {brokers} brokers were given.")]
+    NotEnoughBrokers {
+        topic: String,
+        replication_factor: usize,
+        brokers: usize,
+    },
+    #[error("Failed to send request to cluster.")]
+    Protocol,
+    #[error("Cluster rejected reassignment request: {0}")]
+    Rejected(String),
+    #[error("Failed to update replication throttles.")]
+    Throttle,
+    #[error("Failed to reassign {0} partitions.")]
+    PartitionsFailed(usize),
+    #[error("Failed to get input for args: {0}")]
+    InputError(&'static str),
+    #[error("Error while writing output.")]
+    Output,
+}
@@ -0,0 +1,17 @@
+#[derive(Debug, thiserror::Error)]
+pub enum SchemaError {
+    #[error("Failed to get target cluster.")]
+    FetchCluster,
+    #[error("No schema registry configured for cluster: {0}")]
+    NoRegistry(String),
+    #[error("Schema registry request failed.")]
+    Registry,
+    #[error("Failed to read schema file: {0}")]
+    ReadSchema(String),
+    #[error("Schema is not compatible with subject: {0}")]
+    Incompatible(String),
+    #[error("Failed to get input for args: {0}")]
+    InputError(&'static str),
+    #[error("Error while writing output.")]
+    Output,
+}
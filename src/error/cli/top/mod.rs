@@ -0,0 +1,17 @@
+#[derive(Debug, thiserror::Error)]
+pub enum TopError {
+    #[error("Failed to get target cluster.")]
+    FetchCluster,
+    #[error("Failed to create client.")]
+    CreateClient,
+    #[error("Failed to fetch cluster metadata.")]
+    Metadata,
+    #[error("Failed to fetch end offsets.")]
+    EndOffsets,
+    #[error("Failed to fetch consumer groups.")]
+    Groups,
+    #[error("Failed to fetch committed offsets for group: {0}")]
+    CommittedOffsets(String),
+    #[error("Failed while drawing to terminal.")]
+    Terminal,
+}
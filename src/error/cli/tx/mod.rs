@@ -0,0 +1,25 @@
+#[derive(Debug, thiserror::Error)]
+pub enum TxError {
+    #[error("Failed to get target cluster.")]
+    FetchCluster,
+    #[error("Failed to create client.")]
+    CreateClient,
+    #[error("Failed to fetch cluster metadata.")]
+    Metadata,
+    #[error("Failed to send request to cluster.")]
+    Protocol,
+    #[error("Broker does not exist in cluster: {0}")]
+    BrokerNotExists(i32),
+    #[error("Partition does not exist: {0}-{1}")]
+    PartitionNotExists(String, i32),
+    #[error("Partition has no leader: {0}-{1}")]
+    NoLeader(String, i32),
+    #[error("No open transaction starts at offset {0}.")]
+    NoOpenTransaction(i64),
+    #[error("Cluster rejected transaction request: {0}")]
+    Rejected(String),
+    #[error("Failed to get input for args: {0}")]
+    InputError(&'static str),
+    #[error("Error while writing output.")]
+    Output,
+}
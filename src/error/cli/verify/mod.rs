@@ -0,0 +1,33 @@
+#[derive(Debug, thiserror::Error)]
+pub enum VerifyProduceError {
+    #[error("Failed to get target cluster.")]
+    FetchCluster,
+    #[error("Failed to create producer.")]
+    CreateProducer,
+    #[error("Failed to produce record: {0}")]
+    Produce(u64),
+    #[error("Failed to flush outstanding records.")]
+    Flush,
+    #[error("Failed to deliver {0} records.")]
+    Delivery(u64),
+    #[error("Error while writing output.")]
+    Output,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum VerifyConsumeError {
+    #[error("Failed to get target cluster.")]
+    FetchCluster,
+    #[error("Failed to create consumer.")]
+    CreateConsumer,
+    #[error("Failed to fetch metadata for topic: {0}")]
+    Metadata(String),
+    #[error("Topic does not exist: {0}")]
+    TopicNotExists(String),
+    #[error("Consumer failed while reading.")]
+    ConsumerFailure,
+    #[error("Found {0} sequence violations.")]
+    Violations(usize),
+    #[error("Error while writing output.")]
+    Output,
+}
@@ -17,3 +17,93 @@ pub enum SerdeError {
     #[error("Failed to deserialise object.")]
     Deserialise,
 }
+
+#[derive(Debug, thiserror::Error)]
+pub enum SegmentError {
+    #[error("Segment data ended unexpectedly.")]
+    Truncated,
+    #[error("Invalid varint in segment data.")]
+    Varint,
+    #[error("Unsupported record batch magic: {0}")]
+    UnsupportedMagic(i8),
+    #[error("Unknown compression codec: {0}")]
+    UnknownCompression(i16),
+    #[error("Failed to decompress {0} records.")]
+    Decompress(crate::io::segment::Compression),
+    #[error("Corrupt segment data: {0}")]
+    Corrupt(&'static str),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum BackupFormatError {
+    #[error("Failed to read or write backup file: {0}")]
+    Io(String),
+    #[error("Failed to read or write backup manifest: {0}")]
+    Manifest(String),
+    #[error("Unsupported backup format version: {0}")]
+    UnsupportedVersion(u32),
+    #[error("File is not a kcli backup: {0}")]
+    InvalidMagic(String),
+    #[error("Corrupt backup file: {0}")]
+    Corrupt(String),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum AdminError {
+    #[error("Failed to set admin options: {0}")]
+    Options(String),
+    #[error("Invalid admin request argument: {0}")]
+    InvalidArgument(String),
+    #[error("Timed out waiting for admin result.")]
+    Timeout,
+    #[error("Admin request failed: {0}")]
+    Request(String),
+    #[error("Admin result was not of the expected type.")]
+    UnexpectedResult,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SpecError {
+    #[error("Failed to read spec file: {0}")]
+    ReadFile(String),
+    #[error("Unknown spec file format: {0}")]
+    UnknownFormat(String),
+    #[error("Failed to parse spec file: {0}")]
+    Parse(String),
+    #[error("Failed to fetch cluster metadata.")]
+    Metadata,
+    #[error("Failed to describe topic configs.")]
+    DescribeConfigs,
+    #[error("Failed to describe configs of topic {0}: {1}")]
+    Rejected(String, String),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ProtocolError {
+    #[error("Failed to connect to broker: {0}")]
+    Connect(String),
+    #[error("Failed to read or write broker connection.")]
+    Io,
+    #[error("Broker response ended unexpectedly.")]
+    Truncated,
+    #[error("Invalid varint in broker response.")]
+    Varint,
+    #[error("Failed to decode broker response: {0}")]
+    Decode(&'static str),
+    #[error("Authentication type is not supported by the protocol client: {0}")]
+    UnsupportedAuth(&'static str),
+    #[error("Failed to authenticate with broker: {0}")]
+    Authentication(String),
+    #[error("Broker does not exist in cluster metadata: {0}")]
+    UnknownBroker(i32),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RestError {
+    #[error("Request to {0} failed.")]
+    Transport(String),
+    #[error("{0} returned {1}: {2}")]
+    Status(String, u16, String),
+    #[error("Failed to decode response from {0}.")]
+    Decode(String),
+}
@@ -0,0 +1,391 @@
+//! Literal topic ACLs, which is all the declarative specs manage.
+
+use std::{
+    ffi::{c_char, CString},
+    fmt::Display,
+    ptr,
+    time::Duration,
+};
+
+use error_stack::{Report, ResultExt};
+use rdkafka::{client::Client, ClientContext};
+use rdkafka_sys::{
+    rd_kafka_AclBinding_t, rd_kafka_AclOperation_t, rd_kafka_AclPermissionType_t,
+    rd_kafka_ResourcePatternType_t, rd_kafka_error_t, RDKafkaAdminOp, RDKafkaResourceType,
+    RDKafkaRespErr,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::error::io::AdminError;
+
+use super::{c_string, cstr_to_string, run, ERR_BUF_SIZE};
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AclOperation {
+    All,
+    Read,
+    Write,
+    Create,
+    Delete,
+    Alter,
+    Describe,
+    ClusterAction,
+    DescribeConfigs,
+    AlterConfigs,
+    IdempotentWrite,
+}
+
+impl AclOperation {
+    fn native(&self) -> rd_kafka_AclOperation_t {
+        match self {
+            Self::All => rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_ALL,
+            Self::Read => rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_READ,
+            Self::Write => rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_WRITE,
+            Self::Create => rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_CREATE,
+            Self::Delete => rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_DELETE,
+            Self::Alter => rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_ALTER,
+            Self::Describe => rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_DESCRIBE,
+            Self::ClusterAction => rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_CLUSTER_ACTION,
+            Self::DescribeConfigs => {
+                rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_DESCRIBE_CONFIGS
+            }
+            Self::AlterConfigs => rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_ALTER_CONFIGS,
+            Self::IdempotentWrite => {
+                rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_IDEMPOTENT_WRITE
+            }
+        }
+    }
+
+    fn from_native(operation: rd_kafka_AclOperation_t) -> Option<Self> {
+        Some(match operation {
+            rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_ALL => Self::All,
+            rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_READ => Self::Read,
+            rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_WRITE => Self::Write,
+            rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_CREATE => Self::Create,
+            rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_DELETE => Self::Delete,
+            rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_ALTER => Self::Alter,
+            rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_DESCRIBE => Self::Describe,
+            rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_CLUSTER_ACTION => Self::ClusterAction,
+            rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_DESCRIBE_CONFIGS => {
+                Self::DescribeConfigs
+            }
+            rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_ALTER_CONFIGS => Self::AlterConfigs,
+            rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_IDEMPOTENT_WRITE => {
+                Self::IdempotentWrite
+            }
+            _ => None?,
+        })
+    }
+}
+
+impl Display for AclOperation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::All => "all",
+            Self::Read => "read",
+            Self::Write => "write",
+            Self::Create => "create",
+            Self::Delete => "delete",
+            Self::Alter => "alter",
+            Self::Describe => "describe",
+            Self::ClusterAction => "cluster_action",
+            Self::DescribeConfigs => "describe_configs",
+            Self::AlterConfigs => "alter_configs",
+            Self::IdempotentWrite => "idempotent_write",
+        })
+    }
+}
+
+#[derive(
+    Clone, Copy, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum AclPermission {
+    #[default]
+    Allow,
+    Deny,
+}
+
+impl AclPermission {
+    fn native(&self) -> rd_kafka_AclPermissionType_t {
+        match self {
+            Self::Allow => rd_kafka_AclPermissionType_t::RD_KAFKA_ACL_PERMISSION_TYPE_ALLOW,
+            Self::Deny => rd_kafka_AclPermissionType_t::RD_KAFKA_ACL_PERMISSION_TYPE_DENY,
+        }
+    }
+
+    fn from_native(permission: rd_kafka_AclPermissionType_t) -> Option<Self> {
+        match permission {
+            rd_kafka_AclPermissionType_t::RD_KAFKA_ACL_PERMISSION_TYPE_ALLOW => Some(Self::Allow),
+            rd_kafka_AclPermissionType_t::RD_KAFKA_ACL_PERMISSION_TYPE_DENY => Some(Self::Deny),
+            _ => None,
+        }
+    }
+}
+
+impl Display for AclPermission {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Allow => f.write_str("allow"),
+            Self::Deny => f.write_str("deny"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct TopicAcl {
+    pub topic: String,
+    pub principal: String,
+    pub host: String,
+    pub operation: AclOperation,
+    pub permission: AclPermission,
+}
+
+impl Display for TopicAcl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} {} from {}",
+            self.permission, self.principal, self.operation, self.host
+        )
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct AclResult {
+    pub acl: TopicAcl,
+    pub error: Option<String>,
+}
+
+struct AclBindings(Vec<*mut rd_kafka_AclBinding_t>);
+
+impl Drop for AclBindings {
+    fn drop(&mut self) {
+        for binding in &self.0 {
+            unsafe { rdkafka_sys::rd_kafka_AclBinding_destroy(*binding) }
+        }
+    }
+}
+
+/// Builds a binding, or a filter matching exactly one binding, for `acl`.
+/// Filters additionally accept `None` to match any topic.
+fn new_binding(
+    acl: Option<&TopicAcl>,
+    filter: bool,
+) -> error_stack::Result<*mut rd_kafka_AclBinding_t, AdminError> {
+    let name = acl.map(|a| c_string(&a.topic)).transpose()?;
+    let principal = acl.map(|a| c_string(&a.principal)).transpose()?;
+    let host = acl.map(|a| c_string(&a.host)).transpose()?;
+
+    let as_ptr = |s: &Option<CString>| s.as_ref().map_or(ptr::null(), |s| s.as_ptr());
+
+    let operation = acl.map_or(rd_kafka_AclOperation_t::RD_KAFKA_ACL_OPERATION_ANY, |a| {
+        a.operation.native()
+    });
+    let permission = acl.map_or(
+        rd_kafka_AclPermissionType_t::RD_KAFKA_ACL_PERMISSION_TYPE_ANY,
+        |a| a.permission.native(),
+    );
+
+    let mut err_buf = [0 as c_char; ERR_BUF_SIZE];
+
+    let binding = unsafe {
+        let new = match filter {
+            true => rdkafka_sys::rd_kafka_AclBindingFilter_new,
+            false => rdkafka_sys::rd_kafka_AclBinding_new,
+        };
+
+        new(
+            RDKafkaResourceType::RD_KAFKA_RESOURCE_TOPIC,
+            as_ptr(&name),
+            rd_kafka_ResourcePatternType_t::RD_KAFKA_RESOURCE_PATTERN_LITERAL,
+            as_ptr(&principal),
+            as_ptr(&host),
+            operation,
+            permission,
+            err_buf.as_mut_ptr(),
+            ERR_BUF_SIZE,
+        )
+    };
+
+    if binding.is_null() {
+        Err(Report::new(AdminError::InvalidArgument(unsafe {
+            cstr_to_string(err_buf.as_ptr())
+        })))?
+    }
+
+    Ok(binding)
+}
+
+unsafe fn read_binding(binding: *const rd_kafka_AclBinding_t) -> Option<TopicAcl> {
+    Some(TopicAcl {
+        topic: cstr_to_string(rdkafka_sys::rd_kafka_AclBinding_name(binding)),
+        principal: cstr_to_string(rdkafka_sys::rd_kafka_AclBinding_principal(binding)),
+        host: cstr_to_string(rdkafka_sys::rd_kafka_AclBinding_host(binding)),
+        operation: AclOperation::from_native(rdkafka_sys::rd_kafka_AclBinding_operation(binding))?,
+        permission: AclPermission::from_native(rdkafka_sys::rd_kafka_AclBinding_permission_type(
+            binding,
+        ))?,
+    })
+}
+
+unsafe fn read_error(error: *const rd_kafka_error_t) -> Option<String> {
+    match error.is_null()
+        || rdkafka_sys::rd_kafka_error_code(error) == RDKafkaRespErr::RD_KAFKA_RESP_ERR_NO_ERROR
+    {
+        true => None,
+        false => Some(cstr_to_string(rdkafka_sys::rd_kafka_error_string(error))),
+    }
+}
+
+/// Lists the literal ACLs of every topic.
+pub fn describe_topic_acls<C>(
+    client: &Client<C>,
+    timeout: Duration,
+) -> error_stack::Result<Vec<TopicAcl>, AdminError>
+where
+    C: ClientContext,
+{
+    let filter = AclBindings(vec![new_binding(None, true)?]);
+
+    run(
+        client,
+        RDKafkaAdminOp::RD_KAFKA_ADMIN_OP_DESCRIBEACLS,
+        timeout,
+        |native, options, queue| unsafe {
+            rdkafka_sys::rd_kafka_DescribeAcls(native, filter.0[0], options, queue);
+        },
+        |event| unsafe {
+            let result = rdkafka_sys::rd_kafka_event_DescribeAcls_result(event.ptr());
+
+            if result.is_null() {
+                Err(Report::new(AdminError::UnexpectedResult))?
+            }
+
+            let mut count = 0;
+            let bindings = rdkafka_sys::rd_kafka_DescribeAcls_result_acls(result, &mut count);
+
+            if bindings.is_null() || count == 0 {
+                return Ok(Vec::new());
+            }
+
+            Ok(std::slice::from_raw_parts(bindings, count)
+                .iter()
+                .filter_map(|binding| read_binding(*binding))
+                .collect())
+        },
+    )
+    .attach_printable("DescribeAcls")
+}
+
+pub fn create_topic_acls<C>(
+    client: &Client<C>,
+    acls: &[TopicAcl],
+    timeout: Duration,
+) -> error_stack::Result<Vec<AclResult>, AdminError>
+where
+    C: ClientContext,
+{
+    let mut bindings = AclBindings(Vec::with_capacity(acls.len()));
+
+    for acl in acls {
+        bindings.0.push(new_binding(Some(acl), false)?);
+    }
+
+    run(
+        client,
+        RDKafkaAdminOp::RD_KAFKA_ADMIN_OP_CREATEACLS,
+        timeout,
+        |native, options, queue| unsafe {
+            rdkafka_sys::rd_kafka_CreateAcls(
+                native,
+                bindings.0.as_mut_ptr(),
+                bindings.0.len(),
+                options,
+                queue,
+            );
+        },
+        |event| unsafe {
+            let result = rdkafka_sys::rd_kafka_event_CreateAcls_result(event.ptr());
+
+            if result.is_null() {
+                Err(Report::new(AdminError::UnexpectedResult))?
+            }
+
+            let mut count = 0;
+            let results = rdkafka_sys::rd_kafka_CreateAcls_result_acls(result, &mut count);
+
+            if results.is_null() || count == 0 {
+                return Ok(Vec::new());
+            }
+
+            // Results come back in request order.
+            Ok(std::slice::from_raw_parts(results, count)
+                .iter()
+                .zip(acls)
+                .map(|(result, acl)| AclResult {
+                    acl: acl.clone(),
+                    error: read_error(rdkafka_sys::rd_kafka_acl_result_error(*result)),
+                })
+                .collect())
+        },
+    )
+    .attach_printable("CreateAcls")
+}
+
+pub fn delete_topic_acls<C>(
+    client: &Client<C>,
+    acls: &[TopicAcl],
+    timeout: Duration,
+) -> error_stack::Result<Vec<AclResult>, AdminError>
+where
+    C: ClientContext,
+{
+    let mut filters = AclBindings(Vec::with_capacity(acls.len()));
+
+    for acl in acls {
+        filters.0.push(new_binding(Some(acl), true)?);
+    }
+
+    run(
+        client,
+        RDKafkaAdminOp::RD_KAFKA_ADMIN_OP_DELETEACLS,
+        timeout,
+        |native, options, queue| unsafe {
+            rdkafka_sys::rd_kafka_DeleteAcls(
+                native,
+                filters.0.as_mut_ptr(),
+                filters.0.len(),
+                options,
+                queue,
+            );
+        },
+        |event| unsafe {
+            let result = rdkafka_sys::rd_kafka_event_DeleteAcls_result(event.ptr());
+
+            if result.is_null() {
+                Err(Report::new(AdminError::UnexpectedResult))?
+            }
+
+            let mut count = 0;
+            let responses = rdkafka_sys::rd_kafka_DeleteAcls_result_responses(result, &mut count);
+
+            if responses.is_null() || count == 0 {
+                return Ok(Vec::new());
+            }
+
+            Ok(std::slice::from_raw_parts(responses, count)
+                .iter()
+                .zip(acls)
+                .map(|(response, acl)| AclResult {
+                    acl: acl.clone(),
+                    error: read_error(rdkafka_sys::rd_kafka_DeleteAcls_result_response_error(
+                        *response,
+                    )),
+                })
+                .collect())
+        },
+    )
+    .attach_printable("DeleteAcls")
+}
@@ -0,0 +1,708 @@
+//! Blocking wrappers around librdkafka admin operations that the `rdkafka` crate
+//! does not expose.
+//!
+//! Every operation follows the same pattern: create admin options bound to the
+//! client, submit the request onto a private queue, then poll that queue for the
+//! result event.
+
+use std::{
+    ffi::{c_char, CStr, CString},
+    fmt::Display,
+    str::FromStr,
+    time::Duration,
+};
+
+use clap::{builder::PossibleValue, ValueEnum};
+use error_stack::{Report, ResultExt};
+use rdkafka::{client::Client, error::RDKafkaErrorCode, ClientContext, TopicPartitionList};
+use rdkafka_sys::{
+    rd_kafka_AlterConfigOpType_t as RDKafkaAlterConfigOpType, rd_kafka_ConfigSource_t,
+    rd_kafka_ElectionType_t, rd_kafka_topic_partition_list_t, RDKafka, RDKafkaAdminOp,
+    RDKafkaAdminOptions, RDKafkaConfigResource, RDKafkaEvent, RDKafkaQueue, RDKafkaResourceType,
+    RDKafkaRespErr,
+};
+use serde::Serialize;
+
+use crate::error::io::AdminError;
+
+pub mod acl;
+
+const ERR_BUF_SIZE: usize = 512;
+
+// The broker enforces the request timeout, so give it a little longer to answer
+// before treating the request as lost.
+const QUEUE_GRACE: Duration = Duration::from_secs(1);
+
+struct AdminOptions(*mut RDKafkaAdminOptions);
+
+impl Drop for AdminOptions {
+    fn drop(&mut self) {
+        unsafe { rdkafka_sys::rd_kafka_AdminOptions_destroy(self.0) }
+    }
+}
+
+struct Queue(*mut RDKafkaQueue);
+
+impl Drop for Queue {
+    fn drop(&mut self) {
+        unsafe { rdkafka_sys::rd_kafka_queue_destroy(self.0) }
+    }
+}
+
+pub(crate) struct Event(*mut RDKafkaEvent);
+
+impl Event {
+    pub(crate) fn ptr(&self) -> *mut RDKafkaEvent {
+        self.0
+    }
+}
+
+impl Drop for Event {
+    fn drop(&mut self) {
+        unsafe { rdkafka_sys::rd_kafka_event_destroy(self.0) }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct PartitionResult {
+    pub topic: String,
+    pub partition: i32,
+    pub offset: i64,
+    pub error: Option<String>,
+}
+
+pub(crate) unsafe fn cstr_to_string(ptr: *const c_char) -> String {
+    if ptr.is_null() {
+        String::new()
+    } else {
+        CStr::from_ptr(ptr).to_string_lossy().into_owned()
+    }
+}
+
+pub(crate) fn error_string(err: RDKafkaRespErr) -> Option<String> {
+    match err {
+        RDKafkaRespErr::RD_KAFKA_RESP_ERR_NO_ERROR => None,
+        err => Some(unsafe { cstr_to_string(rdkafka_sys::rd_kafka_err2str(err)) }),
+    }
+}
+
+pub(crate) unsafe fn read_partition_list(
+    list: *const rd_kafka_topic_partition_list_t,
+) -> Vec<PartitionResult> {
+    if list.is_null() || (*list).cnt <= 0 {
+        return Vec::new();
+    }
+
+    std::slice::from_raw_parts((*list).elems, (*list).cnt as usize)
+        .iter()
+        .map(|elem| PartitionResult {
+            topic: cstr_to_string(elem.topic),
+            partition: elem.partition,
+            offset: elem.offset,
+            error: error_string(elem.err),
+        })
+        .collect()
+}
+
+pub(crate) fn run<C, T>(
+    client: &Client<C>,
+    op: RDKafkaAdminOp,
+    timeout: Duration,
+    request: impl FnOnce(*mut RDKafka, *mut RDKafkaAdminOptions, *mut RDKafkaQueue),
+    read: impl FnOnce(&Event) -> error_stack::Result<T, AdminError>,
+) -> error_stack::Result<T, AdminError>
+where
+    C: ClientContext,
+{
+    let native = client.native_ptr();
+    let options = AdminOptions(unsafe { rdkafka_sys::rd_kafka_AdminOptions_new(native, op) });
+
+    let mut err_buf = [0 as c_char; ERR_BUF_SIZE];
+
+    let err = unsafe {
+        rdkafka_sys::rd_kafka_AdminOptions_set_request_timeout(
+            options.0,
+            timeout.as_millis() as i32,
+            err_buf.as_mut_ptr(),
+            ERR_BUF_SIZE,
+        )
+    };
+
+    if err != RDKafkaRespErr::RD_KAFKA_RESP_ERR_NO_ERROR {
+        Err(Report::new(AdminError::Options(unsafe {
+            cstr_to_string(err_buf.as_ptr())
+        })))?
+    }
+
+    let queue = Queue(unsafe { rdkafka_sys::rd_kafka_queue_new(native) });
+
+    request(native, options.0, queue.0);
+
+    let event = unsafe {
+        rdkafka_sys::rd_kafka_queue_poll(queue.0, (timeout + QUEUE_GRACE).as_millis() as i32)
+    };
+
+    if event.is_null() {
+        Err(Report::new(AdminError::Timeout))?
+    }
+
+    let event = Event(event);
+
+    let err = unsafe { rdkafka_sys::rd_kafka_event_error(event.0) };
+
+    if err != RDKafkaRespErr::RD_KAFKA_RESP_ERR_NO_ERROR {
+        Err(Report::new(AdminError::Request(unsafe {
+            cstr_to_string(rdkafka_sys::rd_kafka_event_error_string(event.0))
+        })))?
+    }
+
+    read(&event)
+}
+
+pub fn delete_records<C>(
+    client: &Client<C>,
+    before_offsets: &TopicPartitionList,
+    timeout: Duration,
+) -> error_stack::Result<Vec<PartitionResult>, AdminError>
+where
+    C: ClientContext,
+{
+    run(
+        client,
+        RDKafkaAdminOp::RD_KAFKA_ADMIN_OP_DELETERECORDS,
+        timeout,
+        |native, options, queue| unsafe {
+            let mut delete = rdkafka_sys::rd_kafka_DeleteRecords_new(before_offsets.ptr());
+
+            rdkafka_sys::rd_kafka_DeleteRecords(native, &mut delete, 1, options, queue);
+            rdkafka_sys::rd_kafka_DeleteRecords_destroy(delete);
+        },
+        |event| unsafe {
+            let result = rdkafka_sys::rd_kafka_event_DeleteRecords_result(event.ptr());
+
+            if result.is_null() {
+                Err(Report::new(AdminError::UnexpectedResult))?
+            }
+
+            Ok(read_partition_list(
+                rdkafka_sys::rd_kafka_DeleteRecords_result_offsets(result),
+            ))
+        },
+    )
+    .attach_printable("DeleteRecords")
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfigResource {
+    Topic(String),
+    Broker(i32),
+    /// Cluster-wide dynamic broker defaults, addressed by an empty broker name.
+    BrokerDefault,
+}
+
+impl ConfigResource {
+    fn resource_type(&self) -> RDKafkaResourceType {
+        match self {
+            Self::Topic(_) => RDKafkaResourceType::RD_KAFKA_RESOURCE_TOPIC,
+            Self::Broker(_) | Self::BrokerDefault => RDKafkaResourceType::RD_KAFKA_RESOURCE_BROKER,
+        }
+    }
+
+    fn name(&self) -> String {
+        match self {
+            Self::Topic(name) => name.to_owned(),
+            Self::Broker(id) => id.to_string(),
+            Self::BrokerDefault => String::new(),
+        }
+    }
+
+    unsafe fn read(
+        resource: *const RDKafkaConfigResource,
+    ) -> error_stack::Result<Self, AdminError> {
+        let name = cstr_to_string(rdkafka_sys::rd_kafka_ConfigResource_name(resource));
+
+        Ok(match rdkafka_sys::rd_kafka_ConfigResource_type(resource) {
+            RDKafkaResourceType::RD_KAFKA_RESOURCE_TOPIC => Self::Topic(name),
+            RDKafkaResourceType::RD_KAFKA_RESOURCE_BROKER if name.is_empty() => Self::BrokerDefault,
+            RDKafkaResourceType::RD_KAFKA_RESOURCE_BROKER => Self::Broker(
+                name.parse::<i32>()
+                    .change_context(AdminError::UnexpectedResult)?,
+            ),
+            _ => Err(Report::new(AdminError::UnexpectedResult))?,
+        })
+    }
+}
+
+impl Display for ConfigResource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Topic(name) => write!(f, "topic:{}", name),
+            Self::Broker(id) => write!(f, "broker:{}", id),
+            Self::BrokerDefault => f.write_str("broker:<default>"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum AlterConfigOp {
+    Set(String),
+    Delete,
+}
+
+#[derive(Clone, Debug)]
+pub struct ConfigAlteration {
+    pub resource: ConfigResource,
+    pub entries: Vec<(String, AlterConfigOp)>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ConfigResult {
+    pub resource: ConfigResource,
+    pub error: Option<String>,
+}
+
+struct ConfigResources(Vec<*mut RDKafkaConfigResource>);
+
+impl Drop for ConfigResources {
+    fn drop(&mut self) {
+        for resource in &self.0 {
+            unsafe { rdkafka_sys::rd_kafka_ConfigResource_destroy(*resource) }
+        }
+    }
+}
+
+unsafe fn config_resource_error(resource: *const RDKafkaConfigResource) -> Option<String> {
+    match rdkafka_sys::rd_kafka_ConfigResource_error(resource) {
+        RDKafkaRespErr::RD_KAFKA_RESP_ERR_NO_ERROR => None,
+        _ => Some(cstr_to_string(
+            rdkafka_sys::rd_kafka_ConfigResource_error_string(resource),
+        )),
+    }
+}
+
+fn c_string(s: &str) -> error_stack::Result<CString, AdminError> {
+    CString::new(s).change_context(AdminError::InvalidArgument(s.to_owned()))
+}
+
+pub fn incremental_alter_configs<C>(
+    client: &Client<C>,
+    alterations: &[ConfigAlteration],
+    timeout: Duration,
+) -> error_stack::Result<Vec<ConfigResult>, AdminError>
+where
+    C: ClientContext,
+{
+    let mut resources = ConfigResources(Vec::with_capacity(alterations.len()));
+
+    for alteration in alterations {
+        let name = c_string(&alteration.resource.name())?;
+        let resource = unsafe {
+            rdkafka_sys::rd_kafka_ConfigResource_new(
+                alteration.resource.resource_type(),
+                name.as_ptr(),
+            )
+        };
+
+        resources.0.push(resource);
+
+        for (key, op) in &alteration.entries {
+            let key = c_string(key)?;
+            let (op_type, value) = match op {
+                AlterConfigOp::Set(value) => (
+                    RDKafkaAlterConfigOpType::RD_KAFKA_ALTER_CONFIG_OP_TYPE_SET,
+                    Some(c_string(value)?),
+                ),
+                AlterConfigOp::Delete => (
+                    RDKafkaAlterConfigOpType::RD_KAFKA_ALTER_CONFIG_OP_TYPE_DELETE,
+                    None,
+                ),
+            };
+
+            let error = unsafe {
+                rdkafka_sys::rd_kafka_ConfigResource_add_incremental_config(
+                    resource,
+                    key.as_ptr(),
+                    op_type,
+                    value.as_ref().map_or(std::ptr::null(), |v| v.as_ptr()),
+                )
+            };
+
+            if !error.is_null() {
+                let message = unsafe { cstr_to_string(rdkafka_sys::rd_kafka_error_string(error)) };
+
+                unsafe { rdkafka_sys::rd_kafka_error_destroy(error) };
+
+                Err(Report::new(AdminError::InvalidArgument(message)))?
+            }
+        }
+    }
+
+    run(
+        client,
+        RDKafkaAdminOp::RD_KAFKA_ADMIN_OP_INCREMENTALALTERCONFIGS,
+        timeout,
+        |native, options, queue| unsafe {
+            rdkafka_sys::rd_kafka_IncrementalAlterConfigs(
+                native,
+                resources.0.as_mut_ptr(),
+                resources.0.len(),
+                options,
+                queue,
+            );
+        },
+        |event| unsafe {
+            let result = rdkafka_sys::rd_kafka_event_IncrementalAlterConfigs_result(event.ptr());
+
+            if result.is_null() {
+                Err(Report::new(AdminError::UnexpectedResult))?
+            }
+
+            let mut count = 0;
+            let results =
+                rdkafka_sys::rd_kafka_IncrementalAlterConfigs_result_resources(result, &mut count);
+
+            if results.is_null() || count == 0 {
+                return Ok(Vec::new());
+            }
+
+            std::slice::from_raw_parts(results, count)
+                .iter()
+                .map(|resource| {
+                    Ok(ConfigResult {
+                        resource: ConfigResource::read(*resource)?,
+                        error: config_resource_error(*resource),
+                    })
+                })
+                .collect()
+        },
+    )
+    .attach_printable("IncrementalAlterConfigs")
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfigSource {
+    Unknown,
+    DynamicTopic,
+    DynamicBroker,
+    DynamicClusterDefault,
+    StaticBroker,
+    Default,
+    Group,
+}
+
+impl From<rd_kafka_ConfigSource_t> for ConfigSource {
+    fn from(source: rd_kafka_ConfigSource_t) -> Self {
+        match source {
+            rd_kafka_ConfigSource_t::RD_KAFKA_CONFIG_SOURCE_DYNAMIC_TOPIC_CONFIG => {
+                Self::DynamicTopic
+            }
+            rd_kafka_ConfigSource_t::RD_KAFKA_CONFIG_SOURCE_DYNAMIC_BROKER_CONFIG => {
+                Self::DynamicBroker
+            }
+            rd_kafka_ConfigSource_t::RD_KAFKA_CONFIG_SOURCE_DYNAMIC_DEFAULT_BROKER_CONFIG => {
+                Self::DynamicClusterDefault
+            }
+            rd_kafka_ConfigSource_t::RD_KAFKA_CONFIG_SOURCE_STATIC_BROKER_CONFIG => {
+                Self::StaticBroker
+            }
+            rd_kafka_ConfigSource_t::RD_KAFKA_CONFIG_SOURCE_DEFAULT_CONFIG => Self::Default,
+            rd_kafka_ConfigSource_t::RD_KAFKA_CONFIG_SOURCE_GROUP_CONFIG => Self::Group,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+impl Display for ConfigSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unknown => f.write_str("unknown"),
+            Self::DynamicTopic => f.write_str("dynamic topic"),
+            Self::DynamicBroker => f.write_str("dynamic broker"),
+            Self::DynamicClusterDefault => f.write_str("dynamic cluster default"),
+            Self::StaticBroker => f.write_str("static broker"),
+            Self::Default => f.write_str("default"),
+            Self::Group => f.write_str("group"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ConfigEntry {
+    pub name: String,
+    pub value: Option<String>,
+    pub source: ConfigSource,
+    pub sensitive: bool,
+    pub read_only: bool,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ConfigDescription {
+    pub resource: ConfigResource,
+    pub error: Option<String>,
+    pub entries: Vec<ConfigEntry>,
+}
+
+/// Unlike `AdminClient::describe_configs` this can address the cluster-wide
+/// broker defaults and reports whether each entry is sensitive or read-only.
+pub fn describe_configs<C>(
+    client: &Client<C>,
+    resources: &[ConfigResource],
+    timeout: Duration,
+) -> error_stack::Result<Vec<ConfigDescription>, AdminError>
+where
+    C: ClientContext,
+{
+    let mut natives = ConfigResources(Vec::with_capacity(resources.len()));
+
+    for resource in resources {
+        let name = c_string(&resource.name())?;
+
+        natives.0.push(unsafe {
+            rdkafka_sys::rd_kafka_ConfigResource_new(resource.resource_type(), name.as_ptr())
+        });
+    }
+
+    run(
+        client,
+        RDKafkaAdminOp::RD_KAFKA_ADMIN_OP_DESCRIBECONFIGS,
+        timeout,
+        |native, options, queue| unsafe {
+            rdkafka_sys::rd_kafka_DescribeConfigs(
+                native,
+                natives.0.as_mut_ptr(),
+                natives.0.len(),
+                options,
+                queue,
+            );
+        },
+        |event| unsafe {
+            let result = rdkafka_sys::rd_kafka_event_DescribeConfigs_result(event.ptr());
+
+            if result.is_null() {
+                Err(Report::new(AdminError::UnexpectedResult))?
+            }
+
+            let mut count = 0;
+            let results =
+                rdkafka_sys::rd_kafka_DescribeConfigs_result_resources(result, &mut count);
+
+            if results.is_null() || count == 0 {
+                return Ok(Vec::new());
+            }
+
+            std::slice::from_raw_parts(results, count)
+                .iter()
+                .map(|resource| {
+                    let mut count = 0;
+                    let entries =
+                        rdkafka_sys::rd_kafka_ConfigResource_configs(*resource, &mut count);
+
+                    let entries = match entries.is_null() {
+                        true => Vec::new(),
+                        false => std::slice::from_raw_parts(entries, count)
+                            .iter()
+                            .map(|entry| {
+                                let value = rdkafka_sys::rd_kafka_ConfigEntry_value(*entry);
+
+                                ConfigEntry {
+                                    name: cstr_to_string(rdkafka_sys::rd_kafka_ConfigEntry_name(
+                                        *entry,
+                                    )),
+                                    value: (!value.is_null()).then(|| cstr_to_string(value)),
+                                    source: rdkafka_sys::rd_kafka_ConfigEntry_source(*entry).into(),
+                                    sensitive: rdkafka_sys::rd_kafka_ConfigEntry_is_sensitive(
+                                        *entry,
+                                    ) != 0,
+                                    read_only: rdkafka_sys::rd_kafka_ConfigEntry_is_read_only(
+                                        *entry,
+                                    ) != 0,
+                                }
+                            })
+                            .collect(),
+                    };
+
+                    Ok(ConfigDescription {
+                        resource: ConfigResource::read(*resource)?,
+                        error: config_resource_error(*resource),
+                        entries,
+                    })
+                })
+                .collect()
+        },
+    )
+    .attach_printable("DescribeConfigs")
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum ElectionType {
+    #[default]
+    Preferred,
+    Unclean,
+}
+
+impl ElectionType {
+    fn native(&self) -> rd_kafka_ElectionType_t {
+        match self {
+            Self::Preferred => rd_kafka_ElectionType_t::RD_KAFKA_ELECTION_TYPE_PREFERRED,
+            Self::Unclean => rd_kafka_ElectionType_t::RD_KAFKA_ELECTION_TYPE_UNCLEAN,
+        }
+    }
+}
+
+impl Display for ElectionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.to_possible_value()
+            .expect("no values are skipped")
+            .get_name()
+            .fmt(f)
+    }
+}
+
+impl FromStr for ElectionType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        for variant in Self::value_variants() {
+            if variant.to_possible_value().unwrap().matches(s, false) {
+                return Ok(*variant);
+            }
+        }
+        Err(format!("invalid variant: {s}"))
+    }
+}
+
+impl ValueEnum for ElectionType {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[Self::Preferred, Self::Unclean]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        Some(match self {
+            Self::Preferred => PossibleValue::new("preferred"),
+            Self::Unclean => PossibleValue::new("unclean"),
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ElectionResult {
+    pub topic: String,
+    pub partition: i32,
+    pub error: Option<RDKafkaErrorCode>,
+}
+
+pub fn elect_leaders<C>(
+    client: &Client<C>,
+    election_type: ElectionType,
+    partitions: &TopicPartitionList,
+    timeout: Duration,
+) -> error_stack::Result<Vec<ElectionResult>, AdminError>
+where
+    C: ClientContext,
+{
+    run(
+        client,
+        RDKafkaAdminOp::RD_KAFKA_ADMIN_OP_ELECTLEADERS,
+        timeout,
+        |native, options, queue| unsafe {
+            let elect =
+                rdkafka_sys::rd_kafka_ElectLeaders_new(election_type.native(), partitions.ptr());
+
+            rdkafka_sys::rd_kafka_ElectLeaders(native, elect, options, queue);
+            rdkafka_sys::rd_kafka_ElectLeaders_destroy(elect);
+        },
+        |event| unsafe {
+            let result = rdkafka_sys::rd_kafka_event_ElectLeaders_result(event.ptr());
+
+            if result.is_null() {
+                Err(Report::new(AdminError::UnexpectedResult))?
+            }
+
+            let mut count = 0;
+            let results = rdkafka_sys::rd_kafka_ElectLeaders_result_partitions(result, &mut count);
+
+            if results.is_null() || count == 0 {
+                return Ok(Vec::new());
+            }
+
+            Ok(std::slice::from_raw_parts(results, count)
+                .iter()
+                .map(|result| {
+                    let partition = rdkafka_sys::rd_kafka_topic_partition_result_partition(*result);
+                    let error = rdkafka_sys::rd_kafka_topic_partition_result_error(*result);
+
+                    ElectionResult {
+                        topic: cstr_to_string((*partition).topic),
+                        partition: (*partition).partition,
+                        error: match error.is_null() {
+                            true => None,
+                            false => match rdkafka_sys::rd_kafka_error_code(error) {
+                                RDKafkaRespErr::RD_KAFKA_RESP_ERR_NO_ERROR => None,
+                                code => Some(code.into()),
+                            },
+                        },
+                    }
+                })
+                .collect())
+        },
+    )
+    .attach_printable("ElectLeaders")
+}
+
+/// Committed offsets of every partition `group` has committed to.
+pub fn list_consumer_group_offsets<C>(
+    client: &Client<C>,
+    group: &str,
+    timeout: Duration,
+) -> error_stack::Result<Vec<PartitionResult>, AdminError>
+where
+    C: ClientContext,
+{
+    let group_id = c_string(group)?;
+
+    run(
+        client,
+        RDKafkaAdminOp::RD_KAFKA_ADMIN_OP_LISTCONSUMERGROUPOFFSETS,
+        timeout,
+        |native, options, queue| unsafe {
+            let mut list = rdkafka_sys::rd_kafka_ListConsumerGroupOffsets_new(
+                group_id.as_ptr(),
+                std::ptr::null(),
+            );
+
+            rdkafka_sys::rd_kafka_ListConsumerGroupOffsets(native, &mut list, 1, options, queue);
+            rdkafka_sys::rd_kafka_ListConsumerGroupOffsets_destroy(list);
+        },
+        |event| unsafe {
+            let result = rdkafka_sys::rd_kafka_event_ListConsumerGroupOffsets_result(event.ptr());
+
+            if result.is_null() {
+                Err(Report::new(AdminError::UnexpectedResult))?
+            }
+
+            let mut count = 0;
+            let groups =
+                rdkafka_sys::rd_kafka_ListConsumerGroupOffsets_result_groups(result, &mut count);
+
+            if groups.is_null() || count == 0 {
+                Err(Report::new(AdminError::UnexpectedResult))?
+            }
+
+            let group = *groups;
+            let error = rdkafka_sys::rd_kafka_group_result_error(group);
+
+            if !error.is_null() {
+                Err(Report::new(AdminError::Request(cstr_to_string(
+                    rdkafka_sys::rd_kafka_error_string(error),
+                ))))?
+            }
+
+            Ok(read_partition_list(
+                rdkafka_sys::rd_kafka_group_result_partitions(group),
+            ))
+        },
+    )
+    .attach_printable("ListConsumerGroupOffsets")
+}
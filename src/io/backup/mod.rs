@@ -0,0 +1,527 @@
+//! Backup directory format written by `kcli backup` and read by `kcli restore`.
+//!
+//! A backup is a directory holding a `manifest.json` describing the source topic and one
+//! gzip compressed file per partition named `partition-<n>.bin.gz`.
+//!
+//! Each partition file starts with the 8 byte magic `KCLIBAK1` followed by records. All
+//! integers are big endian, and byte arrays are an `i32` length followed by that many bytes,
+//! with a length of `-1` for null.
+//!
+//! ```text
+//! record         => offset timestamp_type timestamp key value header_count [header]
+//!   offset         => i64, offset of the record in the source partition
+//!   timestamp_type => i8, -1 not available, 0 create time, 1 log append time
+//!   timestamp      => i64, milliseconds since epoch
+//!   key            => bytes
+//!   value          => bytes
+//!   header_count   => i32
+//! header         => header_key header_value
+//!   header_key     => bytes, never null
+//!   header_value   => bytes
+//! ```
+
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, ErrorKind, Read, Write},
+    path::{Path, PathBuf},
+};
+
+use error_stack::{Report, ResultExt};
+use flate2::{read::GzDecoder, write::GzEncoder};
+use rdkafka::message::Timestamp;
+use serde::{Deserialize, Serialize};
+
+use crate::error::io::BackupFormatError;
+
+pub const FORMAT_VERSION: u32 = 1;
+pub const MANIFEST_FILE: &str = "manifest.json";
+const MAGIC: &[u8; 8] = b"KCLIBAK1";
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct BackupManifest {
+    pub format_version: u32,
+    pub topic: String,
+    pub cluster: String,
+    pub created_at: String,
+    pub partitions: Vec<PartitionManifest>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PartitionManifest {
+    pub partition: i32,
+    pub file: String,
+    pub start_offset: i64,
+    pub end_offset: i64,
+    pub records: u64,
+}
+
+impl BackupManifest {
+    pub fn partition_file(partition: i32) -> String {
+        format!("partition-{}.bin.gz", partition)
+    }
+
+    pub fn read(dir: &Path) -> error_stack::Result<Self, BackupFormatError> {
+        let path = dir.join(MANIFEST_FILE);
+
+        let file =
+            File::open(&path).change_context(BackupFormatError::Io(path.display().to_string()))?;
+
+        let manifest: Self = serde_json::from_reader(BufReader::new(file))
+            .change_context(BackupFormatError::Manifest(path.display().to_string()))?;
+
+        if manifest.format_version != FORMAT_VERSION {
+            Err(Report::new(BackupFormatError::UnsupportedVersion(
+                manifest.format_version,
+            )))?
+        }
+
+        Ok(manifest)
+    }
+
+    pub fn write(&self, dir: &Path) -> error_stack::Result<(), BackupFormatError> {
+        let path = dir.join(MANIFEST_FILE);
+
+        let file = File::create(&path)
+            .change_context(BackupFormatError::Io(path.display().to_string()))?;
+
+        serde_json::to_writer_pretty(BufWriter::new(file), self)
+            .change_context(BackupFormatError::Manifest(path.display().to_string()))
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct BackupHeader {
+    pub key: Vec<u8>,
+    pub value: Option<Vec<u8>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct BackupRecord {
+    pub offset: i64,
+    pub timestamp: Timestamp,
+    pub key: Option<Vec<u8>>,
+    pub value: Option<Vec<u8>>,
+    pub headers: Vec<BackupHeader>,
+}
+
+pub struct BackupWriter {
+    path: PathBuf,
+    encoder: GzEncoder<BufWriter<File>>,
+}
+
+impl BackupWriter {
+    pub fn create(path: &Path) -> error_stack::Result<Self, BackupFormatError> {
+        let file =
+            File::create(path).change_context(BackupFormatError::Io(path.display().to_string()))?;
+
+        let mut writer = Self {
+            path: path.to_owned(),
+            encoder: GzEncoder::new(BufWriter::new(file), flate2::Compression::default()),
+        };
+
+        writer.write_all(MAGIC)?;
+
+        Ok(writer)
+    }
+
+    fn write_all(&mut self, bytes: &[u8]) -> error_stack::Result<(), BackupFormatError> {
+        self.encoder
+            .write_all(bytes)
+            .change_context_lazy(|| BackupFormatError::Io(self.path.display().to_string()))
+    }
+
+    fn write_bytes(&mut self, bytes: Option<&[u8]>) -> error_stack::Result<(), BackupFormatError> {
+        match bytes {
+            None => self.write_all(&(-1i32).to_be_bytes()),
+            Some(bytes) => {
+                self.write_all(&(bytes.len() as i32).to_be_bytes())?;
+                self.write_all(bytes)
+            }
+        }
+    }
+
+    pub fn write(&mut self, record: &BackupRecord) -> error_stack::Result<(), BackupFormatError> {
+        let (timestamp_type, timestamp) = match record.timestamp {
+            Timestamp::NotAvailable => (-1i8, -1i64),
+            Timestamp::CreateTime(t) => (0, t),
+            Timestamp::LogAppendTime(t) => (1, t),
+        };
+
+        self.write_all(&record.offset.to_be_bytes())?;
+        self.write_all(&timestamp_type.to_be_bytes())?;
+        self.write_all(&timestamp.to_be_bytes())?;
+        self.write_bytes(record.key.as_deref())?;
+        self.write_bytes(record.value.as_deref())?;
+        self.write_all(&(record.headers.len() as i32).to_be_bytes())?;
+
+        for header in &record.headers {
+            self.write_bytes(Some(&header.key))?;
+            self.write_bytes(header.value.as_deref())?;
+        }
+
+        Ok(())
+    }
+
+    pub fn finish(self) -> error_stack::Result<(), BackupFormatError> {
+        self.encoder
+            .finish()
+            .and_then(|mut writer| writer.flush())
+            .change_context(BackupFormatError::Io(self.path.display().to_string()))
+    }
+}
+
+pub struct BackupReader {
+    path: PathBuf,
+    decoder: GzDecoder<BufReader<File>>,
+}
+
+impl BackupReader {
+    pub fn open(path: &Path) -> error_stack::Result<Self, BackupFormatError> {
+        let file =
+            File::open(path).change_context(BackupFormatError::Io(path.display().to_string()))?;
+
+        let mut reader = Self {
+            path: path.to_owned(),
+            decoder: GzDecoder::new(BufReader::new(file)),
+        };
+
+        if &reader.read_array::<8>()? != MAGIC {
+            Err(Report::new(BackupFormatError::InvalidMagic(
+                path.display().to_string(),
+            )))?
+        }
+
+        Ok(reader)
+    }
+
+    fn corrupt(&self, reason: &'static str) -> Report<BackupFormatError> {
+        Report::new(BackupFormatError::Corrupt(self.path.display().to_string()))
+            .attach_printable(reason)
+    }
+
+    /// Running out of data partway through a record, or through the gzip
+    /// stream, means the file is corrupt rather than that a read failed.
+    fn read_error(&self, error: std::io::Error) -> Report<BackupFormatError> {
+        match error.kind() {
+            ErrorKind::UnexpectedEof => self
+                .corrupt("File ends partway through a record.")
+                .attach_printable(error),
+            _ => Report::new(error)
+                .change_context(BackupFormatError::Io(self.path.display().to_string())),
+        }
+    }
+
+    fn read_exact(&mut self, buff: &mut [u8]) -> error_stack::Result<(), BackupFormatError> {
+        self.decoder
+            .read_exact(buff)
+            .map_err(|e| self.read_error(e))
+    }
+
+    fn read_array<const N: usize>(&mut self) -> error_stack::Result<[u8; N], BackupFormatError> {
+        let mut buff = [0; N];
+
+        self.read_exact(&mut buff)?;
+
+        Ok(buff)
+    }
+
+    // Lengths come from the file, so the buffer only grows with the bytes that
+    // are actually there.
+    fn read_bytes(&mut self) -> error_stack::Result<Option<Vec<u8>>, BackupFormatError> {
+        match i32::from_be_bytes(self.read_array()?) {
+            -1 => Ok(None),
+            length if length < 0 => Err(self.corrupt("Negative byte array length.")),
+            length => {
+                let mut buff = Vec::new();
+
+                (&mut self.decoder)
+                    .take(length as u64)
+                    .read_to_end(&mut buff)
+                    .map_err(|e| self.read_error(e))?;
+
+                if buff.len() < length as usize {
+                    Err(self.corrupt("File ends partway through a record."))?
+                }
+
+                Ok(Some(buff))
+            }
+        }
+    }
+
+    fn read_record(&mut self, offset: i64) -> error_stack::Result<BackupRecord, BackupFormatError> {
+        let timestamp_type = i8::from_be_bytes(self.read_array()?);
+        let timestamp = i64::from_be_bytes(self.read_array()?);
+
+        let timestamp = match timestamp_type {
+            0 => Timestamp::CreateTime(timestamp),
+            1 => Timestamp::LogAppendTime(timestamp),
+            _ => Timestamp::NotAvailable,
+        };
+
+        let key = self.read_bytes()?;
+        let value = self.read_bytes()?;
+
+        let header_count = i32::from_be_bytes(self.read_array()?);
+        let mut headers = Vec::new();
+
+        if header_count < 0 {
+            Err(self.corrupt("Negative header count."))?
+        }
+
+        for _ in 0..header_count {
+            headers.push(BackupHeader {
+                key: self
+                    .read_bytes()?
+                    .ok_or_else(|| self.corrupt("Null header key."))?,
+                value: self.read_bytes()?,
+            });
+        }
+
+        Ok(BackupRecord {
+            offset,
+            timestamp,
+            key,
+            value,
+            headers,
+        })
+    }
+}
+
+impl Iterator for BackupReader {
+    type Item = error_stack::Result<BackupRecord, BackupFormatError>;
+
+    // Only running out of data before the first byte of a record is a clean
+    // end, and reading until the decoder reports it also checks the gzip
+    // trailer.
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut buff = [0; 8];
+
+        loop {
+            match self.decoder.read(&mut buff[..1]) {
+                Ok(0) => return None,
+                Ok(_) => break,
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => return Some(Err(self.read_error(e))),
+            }
+        }
+
+        Some(
+            self.read_exact(&mut buff[1..])
+                .and_then(|_| self.read_record(i64::from_be_bytes(buff))),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fs,
+        io::{Read, Write},
+        path::PathBuf,
+    };
+
+    use flate2::{read::GzDecoder, write::GzEncoder};
+    use rdkafka::message::Timestamp;
+    use uuid::Uuid;
+
+    use super::{BackupHeader, BackupReader, BackupRecord, BackupWriter, MAGIC};
+    use crate::error::io::BackupFormatError;
+
+    struct TempFile(PathBuf);
+
+    impl TempFile {
+        fn new() -> Self {
+            Self(std::env::temp_dir().join(format!("kcli-backup-{}.bin.gz", Uuid::new_v4())))
+        }
+    }
+
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            let _ = fs::remove_file(&self.0);
+        }
+    }
+
+    fn records() -> Vec<BackupRecord> {
+        vec![
+            BackupRecord {
+                offset: 0,
+                timestamp: Timestamp::CreateTime(1_000),
+                key: Some(b"key".to_vec()),
+                value: Some(b"value".to_vec()),
+                headers: vec![
+                    BackupHeader {
+                        key: b"trace".to_vec(),
+                        value: Some(b"abc".to_vec()),
+                    },
+                    BackupHeader {
+                        key: b"empty".to_vec(),
+                        value: None,
+                    },
+                ],
+            },
+            BackupRecord {
+                offset: 5,
+                timestamp: Timestamp::LogAppendTime(2_000),
+                key: None,
+                value: None,
+                headers: vec![],
+            },
+            BackupRecord {
+                offset: 6,
+                timestamp: Timestamp::NotAvailable,
+                key: Some(vec![]),
+                value: Some(vec![0; 100_000]),
+                headers: vec![],
+            },
+        ]
+    }
+
+    fn write(file: &TempFile, records: &[BackupRecord]) {
+        let mut writer = BackupWriter::create(&file.0).unwrap();
+
+        for record in records {
+            writer.write(record).unwrap();
+        }
+
+        writer.finish().unwrap();
+    }
+
+    fn write_raw(file: &TempFile, bytes: &[u8]) {
+        let mut encoder = GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(bytes).unwrap();
+        fs::write(&file.0, encoder.finish().unwrap()).unwrap();
+    }
+
+    fn decompressed(file: &TempFile) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        GzDecoder::new(fs::File::open(&file.0).unwrap())
+            .read_to_end(&mut bytes)
+            .unwrap();
+        bytes
+    }
+
+    fn read_all(file: &TempFile) -> error_stack::Result<Vec<BackupRecord>, BackupFormatError> {
+        BackupReader::open(&file.0)?.collect()
+    }
+
+    #[test]
+    fn records_round_trip() {
+        let file = TempFile::new();
+        let expected = records();
+
+        write(&file, &expected);
+
+        let read = read_all(&file).unwrap();
+
+        assert_eq!(read.len(), expected.len());
+
+        for (read, expected) in read.iter().zip(&expected) {
+            assert_eq!(read.offset, expected.offset);
+            assert_eq!(read.timestamp, expected.timestamp);
+            assert_eq!(read.key, expected.key);
+            assert_eq!(read.value, expected.value);
+            assert_eq!(
+                read.headers
+                    .iter()
+                    .map(|h| (&h.key, &h.value))
+                    .collect::<Vec<_>>(),
+                expected
+                    .headers
+                    .iter()
+                    .map(|h| (&h.key, &h.value))
+                    .collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    fn empty_backup_has_no_records() {
+        let file = TempFile::new();
+
+        write(&file, &[]);
+
+        assert_eq!(BackupReader::open(&file.0).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn truncated_records_are_corrupt() {
+        let file = TempFile::new();
+
+        write(&file, &records());
+
+        let bytes = decompressed(&file);
+
+        // Cut inside the first record's offset, inside its value and inside the
+        // last record's value.
+        for length in [MAGIC.len() + 3, MAGIC.len() + 30, bytes.len() - 1] {
+            write_raw(&file, &bytes[..length]);
+
+            assert!(matches!(
+                read_all(&file).unwrap_err().current_context(),
+                BackupFormatError::Corrupt(_)
+            ));
+        }
+    }
+
+    #[test]
+    fn truncated_gzip_stream_is_corrupt() {
+        let file = TempFile::new();
+
+        write(&file, &records());
+
+        let bytes = fs::read(&file.0).unwrap();
+
+        for length in [bytes.len() / 2, bytes.len() - 4] {
+            fs::write(&file.0, &bytes[..length]).unwrap();
+
+            assert!(matches!(
+                read_all(&file).unwrap_err().current_context(),
+                BackupFormatError::Corrupt(_)
+            ));
+        }
+    }
+
+    #[test]
+    fn gzip_checksum_is_verified() {
+        let file = TempFile::new();
+
+        write(&file, &records());
+
+        let mut bytes = fs::read(&file.0).unwrap();
+        let crc = bytes.len() - 8;
+        bytes[crc] ^= 0xff;
+        fs::write(&file.0, &bytes).unwrap();
+
+        assert!(read_all(&file).is_err());
+    }
+
+    #[test]
+    fn oversized_lengths_are_corrupt() {
+        let file = TempFile::new();
+        let mut bytes = MAGIC.to_vec();
+
+        bytes.extend(0i64.to_be_bytes());
+        bytes.push(0);
+        bytes.extend(0i64.to_be_bytes());
+        bytes.extend(i32::MAX.to_be_bytes());
+        bytes.extend(b"short");
+
+        write_raw(&file, &bytes);
+
+        assert!(matches!(
+            read_all(&file).unwrap_err().current_context(),
+            BackupFormatError::Corrupt(_)
+        ));
+    }
+
+    #[test]
+    fn bad_magic_is_rejected() {
+        let file = TempFile::new();
+
+        write_raw(&file, b"KCLIBAK9");
+        assert!(matches!(
+            read_all(&file).unwrap_err().current_context(),
+            BackupFormatError::InvalidMagic(_)
+        ));
+    }
+}
@@ -0,0 +1,202 @@
+use std::{collections::BTreeMap, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    config::clusters::rest::RestEndpoint,
+    error::io::RestError,
+    io::{
+        rest::{encode_segment, RestClient},
+        spec::deserialize_configs,
+    },
+};
+
+const CONTENT_TYPE: &str = "application/json";
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ConnectorState {
+    pub state: String,
+    pub worker_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trace: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TaskState {
+    pub id: i32,
+    pub state: String,
+    pub worker_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trace: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ConnectorStatus {
+    pub name: String,
+    pub connector: ConnectorState,
+    pub tasks: Vec<TaskState>,
+    #[serde(rename = "type", default)]
+    pub connector_type: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ConnectorTask {
+    pub connector: String,
+    pub task: i32,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ConnectorInfo {
+    pub name: String,
+    pub config: BTreeMap<String, String>,
+    #[serde(default)]
+    pub tasks: Vec<ConnectorTask>,
+    #[serde(rename = "type", default)]
+    pub connector_type: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ConnectorPlugin {
+    pub class: String,
+    #[serde(rename = "type")]
+    pub plugin_type: String,
+    #[serde(default)]
+    pub version: Option<String>,
+}
+
+/// A connector config as written by hand, either the bare config or wrapped
+/// the way `POST /connectors` expects it.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+pub enum ConnectorConfig {
+    Wrapped {
+        name: Option<String>,
+        #[serde(deserialize_with = "deserialize_configs")]
+        config: BTreeMap<String, String>,
+    },
+    Flat(#[serde(deserialize_with = "deserialize_configs")] BTreeMap<String, String>),
+}
+
+impl ConnectorConfig {
+    /// The connector name declared in the file, if any.
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            Self::Wrapped {
+                name: Some(name), ..
+            } => Some(name),
+            Self::Wrapped { config, .. } | Self::Flat(config) => {
+                config.get("name").map(String::as_str)
+            }
+        }
+    }
+
+    pub fn into_config(self) -> BTreeMap<String, String> {
+        match self {
+            Self::Wrapped { config, .. } | Self::Flat(config) => config,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct NewConnector<'a> {
+    name: &'a str,
+    config: &'a BTreeMap<String, String>,
+}
+
+#[derive(Deserialize)]
+struct ExpandedConnector {
+    status: ConnectorStatus,
+}
+
+pub struct ConnectClient(RestClient);
+
+impl ConnectClient {
+    pub fn new(endpoint: &RestEndpoint, timeout: Duration) -> Self {
+        Self(RestClient::new(endpoint, CONTENT_TYPE, timeout))
+    }
+
+    /// Statuses of every connector, fetched in a single request.
+    pub fn statuses(&self) -> error_stack::Result<Vec<ConnectorStatus>, RestError> {
+        self.0
+            .get::<BTreeMap<String, ExpandedConnector>>("/connectors?expand=status")
+            .map(|connectors| connectors.into_values().map(|c| c.status).collect())
+    }
+
+    pub fn status(&self, name: &str) -> error_stack::Result<ConnectorStatus, RestError> {
+        self.0
+            .get(&format!("/connectors/{}/status", encode_segment(name)))
+    }
+
+    pub fn create(
+        &self,
+        name: &str,
+        config: &BTreeMap<String, String>,
+    ) -> error_stack::Result<ConnectorInfo, RestError> {
+        self.0
+            .post("/connectors", Some(&NewConnector { name, config }))
+    }
+
+    /// Creates the connector if it does not exist yet.
+    pub fn update(
+        &self,
+        name: &str,
+        config: &BTreeMap<String, String>,
+    ) -> error_stack::Result<ConnectorInfo, RestError> {
+        self.0.put(
+            &format!("/connectors/{}/config", encode_segment(name)),
+            Some(config),
+        )
+    }
+
+    pub fn pause(&self, name: &str) -> error_stack::Result<(), RestError> {
+        self.0.put(
+            &format!("/connectors/{}/pause", encode_segment(name)),
+            None::<&()>,
+        )
+    }
+
+    pub fn resume(&self, name: &str) -> error_stack::Result<(), RestError> {
+        self.0.put(
+            &format!("/connectors/{}/resume", encode_segment(name)),
+            None::<&()>,
+        )
+    }
+
+    /// Workers only answer with the resulting status when tasks are included.
+    pub fn restart(
+        &self,
+        name: &str,
+        include_tasks: bool,
+        only_failed: bool,
+    ) -> error_stack::Result<Option<ConnectorStatus>, RestError> {
+        self.0.post(
+            &format!(
+                "/connectors/{}/restart?includeTasks={}&onlyFailed={}",
+                encode_segment(name),
+                include_tasks,
+                only_failed
+            ),
+            None::<&()>,
+        )
+    }
+
+    pub fn restart_task(&self, name: &str, task: i32) -> error_stack::Result<(), RestError> {
+        self.0.post(
+            &format!(
+                "/connectors/{}/tasks/{}/restart",
+                encode_segment(name),
+                task
+            ),
+            None::<&()>,
+        )
+    }
+
+    pub fn delete(&self, name: &str) -> error_stack::Result<(), RestError> {
+        self.0
+            .delete(&format!("/connectors/{}", encode_segment(name)))
+    }
+
+    pub fn plugins(&self) -> error_stack::Result<Vec<ConnectorPlugin>, RestError> {
+        self.0.get("/connector-plugins")
+    }
+}
@@ -1,8 +1,8 @@
-use std::{fmt::Display, str::FromStr};
+use std::{fmt::Display, path::Path, str::FromStr};
 
 use clap::{builder::PossibleValue, ValueEnum};
 use error_stack::ResultExt;
-use serde::Serialize;
+use serde::{de::DeserializeOwned, Serialize};
 
 use crate::error::io::InputError;
 
@@ -10,16 +10,39 @@ use crate::error::io::InputError;
 pub enum Input {
     #[default]
     Json,
+    Toml,
     Yaml,
 }
 
 impl Input {
+    /// Picks the format from a file extension, if it is a known one.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "json" => Some(Self::Json),
+            "toml" => Some(Self::Toml),
+            "yaml" | "yml" => Some(Self::Yaml),
+            _ => None,
+        }
+    }
+
+    pub fn read_from_str<T>(&self, value: &str) -> error_stack::Result<T, InputError>
+    where
+        T: DeserializeOwned,
+    {
+        match self {
+            Self::Json => serde_json::from_str(value).change_context(InputError::Deserialise),
+            Self::Toml => toml::from_str(value).change_context(InputError::Deserialise),
+            Self::Yaml => serde_yml::from_str(value).change_context(InputError::Deserialise),
+        }
+    }
+
     pub fn write_as_string<T>(&self, value: &T) -> error_stack::Result<String, InputError>
     where
         T: Serialize,
     {
         match self {
             Self::Json => serde_json::to_string(value).change_context(InputError::Deserialise),
+            Self::Toml => toml::to_string(value).change_context(InputError::Deserialise),
             Self::Yaml => serde_yml::to_string(value).change_context(InputError::Deserialise),
         }
     }
@@ -49,12 +72,13 @@ impl FromStr for Input {
 
 impl ValueEnum for Input {
     fn value_variants<'a>() -> &'a [Self] {
-        &[Input::Json, Input::Yaml]
+        &[Input::Json, Input::Toml, Input::Yaml]
     }
 
     fn to_possible_value(&self) -> Option<PossibleValue> {
         Some(match self {
             Input::Json => PossibleValue::new("json"),
+            Input::Toml => PossibleValue::new("toml"),
             Input::Yaml => PossibleValue::new("yaml"),
         })
     }
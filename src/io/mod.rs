@@ -1,3 +1,11 @@
+pub mod admin;
+pub mod backup;
+pub mod connect;
 pub mod input;
 pub mod output;
+pub mod protocol;
+pub mod rest;
+pub mod schema_registry;
+pub mod segment;
 pub mod serde;
+pub mod spec;
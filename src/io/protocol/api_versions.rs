@@ -0,0 +1,215 @@
+use serde::Serialize;
+
+use crate::error::io::ProtocolError;
+
+use super::{
+    codec::{Decoder, Encoder},
+    Request, CLIENT_ID,
+};
+
+const SUPPORTED_FEATURES_TAG: u64 = 0;
+const FINALIZED_FEATURES_EPOCH_TAG: u64 = 1;
+const FINALIZED_FEATURES_TAG: u64 = 2;
+
+/// Asks a broker for the API versions and feature levels it supports, along
+/// with the feature levels finalized for the cluster.
+pub struct ApiVersionsRequest;
+
+#[derive(Clone, Copy, Debug, Serialize)]
+pub struct ApiVersionRange {
+    pub api_key: i16,
+    pub min_version: i16,
+    pub max_version: i16,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct SupportedFeature {
+    pub name: String,
+    pub min_version: i16,
+    pub max_version: i16,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct FinalizedFeature {
+    pub name: String,
+    pub max_version_level: i16,
+    pub min_version_level: i16,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ApiVersionsResponse {
+    pub error_code: i16,
+    pub api_keys: Vec<ApiVersionRange>,
+    pub supported_features: Vec<SupportedFeature>,
+    pub finalized_features_epoch: Option<i64>,
+    pub finalized_features: Vec<FinalizedFeature>,
+}
+
+impl Request for ApiVersionsRequest {
+    const API_KEY: i16 = 18;
+    const API_VERSION: i16 = 3;
+    const FLEXIBLE: bool = true;
+    const FLEXIBLE_RESPONSE_HEADER: bool = false;
+
+    type Response = ApiVersionsResponse;
+
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder
+            .string(CLIENT_ID)
+            .string(env!("CARGO_PKG_VERSION"))
+            .tagged_fields();
+    }
+
+    fn decode(decoder: &mut Decoder<'_>) -> error_stack::Result<Self::Response, ProtocolError> {
+        let error_code = decoder.i16()?;
+
+        let mut response = ApiVersionsResponse {
+            error_code,
+            api_keys: Vec::new(),
+            supported_features: Vec::new(),
+            finalized_features_epoch: None,
+            finalized_features: Vec::new(),
+        };
+
+        // Brokers answer versions they do not support with a v0 body, which
+        // only carries the error code worth reading.
+        if error_code != 0 {
+            return Ok(response);
+        }
+
+        response.api_keys = decoder.array(|d| {
+            let range = ApiVersionRange {
+                api_key: d.i16()?,
+                min_version: d.i16()?,
+                max_version: d.i16()?,
+            };
+
+            d.tagged_fields()?;
+
+            Ok(range)
+        })?;
+
+        let _throttle_time_ms = decoder.i32()?;
+
+        for (tag, bytes) in decoder.tagged_fields()? {
+            let mut d = Decoder::new(bytes, true);
+
+            match tag {
+                SUPPORTED_FEATURES_TAG => {
+                    response.supported_features = d.array(|d| {
+                        let feature = SupportedFeature {
+                            name: d.string()?,
+                            min_version: d.i16()?,
+                            max_version: d.i16()?,
+                        };
+
+                        d.tagged_fields()?;
+
+                        Ok(feature)
+                    })?
+                }
+                FINALIZED_FEATURES_EPOCH_TAG => response.finalized_features_epoch = Some(d.i64()?),
+                FINALIZED_FEATURES_TAG => {
+                    response.finalized_features = d.array(|d| {
+                        let feature = FinalizedFeature {
+                            name: d.string()?,
+                            max_version_level: d.i16()?,
+                            min_version_level: d.i16()?,
+                        };
+
+                        d.tagged_fields()?;
+
+                        Ok(feature)
+                    })?
+                }
+                _ => {}
+            }
+        }
+
+        Ok(response)
+    }
+}
+
+pub fn api_name(api_key: i16) -> Option<&'static str> {
+    let name = match api_key {
+        0 => "Produce",
+        1 => "Fetch",
+        2 => "ListOffsets",
+        3 => "Metadata",
+        4 => "LeaderAndIsr",
+        5 => "StopReplica",
+        6 => "UpdateMetadata",
+        7 => "ControlledShutdown",
+        8 => "OffsetCommit",
+        9 => "OffsetFetch",
+        10 => "FindCoordinator",
+        11 => "JoinGroup",
+        12 => "Heartbeat",
+        13 => "LeaveGroup",
+        14 => "SyncGroup",
+        15 => "DescribeGroups",
+        16 => "ListGroups",
+        17 => "SaslHandshake",
+        18 => "ApiVersions",
+        19 => "CreateTopics",
+        20 => "DeleteTopics",
+        21 => "DeleteRecords",
+        22 => "InitProducerId",
+        23 => "OffsetForLeaderEpoch",
+        24 => "AddPartitionsToTxn",
+        25 => "AddOffsetsToTxn",
+        26 => "EndTxn",
+        27 => "WriteTxnMarkers",
+        28 => "TxnOffsetCommit",
+        29 => "DescribeAcls",
+        30 => "CreateAcls",
+        31 => "DeleteAcls",
+        32 => "DescribeConfigs",
+        33 => "AlterConfigs",
+        34 => "AlterReplicaLogDirs",
+        35 => "DescribeLogDirs",
+        36 => "SaslAuthenticate",
+        37 => "CreatePartitions",
+        38 => "CreateDelegationToken",
+        39 => "RenewDelegationToken",
+        40 => "ExpireDelegationToken",
+        41 => "DescribeDelegationToken",
+        42 => "DeleteGroups",
+        43 => "ElectLeaders",
+        44 => "IncrementalAlterConfigs",
+        45 => "AlterPartitionReassignments",
+        46 => "ListPartitionReassignments",
+        47 => "OffsetDelete",
+        48 => "DescribeClientQuotas",
+        49 => "AlterClientQuotas",
+        50 => "DescribeUserScramCredentials",
+        51 => "AlterUserScramCredentials",
+        52 => "Vote",
+        53 => "BeginQuorumEpoch",
+        54 => "EndQuorumEpoch",
+        55 => "DescribeQuorum",
+        56 => "AlterPartition",
+        57 => "UpdateFeatures",
+        58 => "Envelope",
+        59 => "FetchSnapshot",
+        60 => "DescribeCluster",
+        61 => "DescribeProducers",
+        62 => "BrokerRegistration",
+        63 => "BrokerHeartbeat",
+        64 => "UnregisterBroker",
+        65 => "DescribeTransactions",
+        66 => "ListTransactions",
+        67 => "AllocateProducerIds",
+        68 => "ConsumerGroupHeartbeat",
+        69 => "ConsumerGroupDescribe",
+        70 => "ControllerRegistration",
+        71 => "GetTelemetrySubscriptions",
+        72 => "PushTelemetry",
+        73 => "AssignReplicasToDirs",
+        74 => "ListClientMetricsResources",
+        75 => "DescribeTopicPartitions",
+        _ => return None,
+    };
+
+    Some(name)
+}
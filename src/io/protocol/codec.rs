@@ -0,0 +1,424 @@
+use error_stack::{Report, ResultExt};
+
+use crate::error::io::ProtocolError;
+
+pub(crate) struct Encoder {
+    buff: Vec<u8>,
+    flexible: bool,
+}
+
+impl Encoder {
+    pub(super) fn new(flexible: bool) -> Self {
+        Self {
+            buff: Vec::new(),
+            flexible,
+        }
+    }
+
+    pub(super) fn into_bytes(self) -> Vec<u8> {
+        self.buff
+    }
+
+    pub(crate) fn i8(&mut self, value: i8) -> &mut Self {
+        self.buff.extend_from_slice(&value.to_be_bytes());
+        self
+    }
+
+    pub(crate) fn i16(&mut self, value: i16) -> &mut Self {
+        self.buff.extend_from_slice(&value.to_be_bytes());
+        self
+    }
+
+    pub(crate) fn i32(&mut self, value: i32) -> &mut Self {
+        self.buff.extend_from_slice(&value.to_be_bytes());
+        self
+    }
+
+    pub(crate) fn i64(&mut self, value: i64) -> &mut Self {
+        self.buff.extend_from_slice(&value.to_be_bytes());
+        self
+    }
+
+    pub(crate) fn f64(&mut self, value: f64) -> &mut Self {
+        self.buff.extend_from_slice(&value.to_be_bytes());
+        self
+    }
+
+    pub(crate) fn bool(&mut self, value: bool) -> &mut Self {
+        self.buff.push(value as u8);
+        self
+    }
+
+    fn unsigned_varint(&mut self, mut value: u64) -> &mut Self {
+        while value >= 0x80 {
+            self.buff.push((value as u8 & 0x7f) | 0x80);
+            value >>= 7;
+        }
+
+        self.buff.push(value as u8);
+        self
+    }
+
+    fn length(&mut self, length: Option<usize>) -> &mut Self {
+        match (length, self.flexible) {
+            (None, true) => self.unsigned_varint(0),
+            (None, false) => self.i32(-1),
+            (Some(length), true) => self.unsigned_varint(length as u64 + 1),
+            (Some(length), false) => self.i32(length as i32),
+        }
+    }
+
+    pub(crate) fn string(&mut self, value: &str) -> &mut Self {
+        self.nullable_string(Some(value))
+    }
+
+    pub(crate) fn nullable_string(&mut self, value: Option<&str>) -> &mut Self {
+        match (value, self.flexible) {
+            (None, true) => self.unsigned_varint(0),
+            (None, false) => self.i16(-1),
+            (Some(value), true) => self.unsigned_varint(value.len() as u64 + 1),
+            (Some(value), false) => self.i16(value.len() as i16),
+        };
+
+        if let Some(value) = value {
+            self.buff.extend_from_slice(value.as_bytes());
+        }
+
+        self
+    }
+
+    pub(crate) fn bytes(&mut self, value: &[u8]) -> &mut Self {
+        self.length(Some(value.len()));
+        self.buff.extend_from_slice(value);
+        self
+    }
+
+    pub(crate) fn array<T>(
+        &mut self,
+        values: &[T],
+        mut write: impl FnMut(&mut Self, &T),
+    ) -> &mut Self {
+        self.nullable_array(Some(values), &mut write)
+    }
+
+    pub(crate) fn nullable_array<T>(
+        &mut self,
+        values: Option<&[T]>,
+        mut write: impl FnMut(&mut Self, &T),
+    ) -> &mut Self {
+        self.length(values.map(|v| v.len()));
+
+        for value in values.unwrap_or_default() {
+            write(self, value);
+        }
+
+        self
+    }
+
+    pub(crate) fn tagged_fields(&mut self) -> &mut Self {
+        if self.flexible {
+            self.unsigned_varint(0);
+        }
+
+        self
+    }
+}
+
+pub(crate) struct Decoder<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    flexible: bool,
+}
+
+impl<'a> Decoder<'a> {
+    pub(crate) fn new(bytes: &'a [u8], flexible: bool) -> Self {
+        Self {
+            bytes,
+            position: 0,
+            flexible,
+        }
+    }
+
+    pub(super) fn with_flexible(self, flexible: bool) -> Self {
+        Self { flexible, ..self }
+    }
+
+    fn take(&mut self, length: usize) -> error_stack::Result<&'a [u8], ProtocolError> {
+        if self.bytes.len() - self.position < length {
+            Err(Report::new(ProtocolError::Truncated)).attach_printable(format!(
+                "Wanted {} bytes at position {}, only {} remaining.",
+                length,
+                self.position,
+                self.bytes.len() - self.position
+            ))?
+        }
+
+        let slice = &self.bytes[self.position..self.position + length];
+
+        self.position += length;
+
+        Ok(slice)
+    }
+
+    fn take_array<const N: usize>(&mut self) -> error_stack::Result<[u8; N], ProtocolError> {
+        Ok(self
+            .take(N)?
+            .try_into()
+            .expect("Slice length checked by take."))
+    }
+
+    pub(crate) fn i16(&mut self) -> error_stack::Result<i16, ProtocolError> {
+        Ok(i16::from_be_bytes(self.take_array()?))
+    }
+
+    pub(crate) fn i32(&mut self) -> error_stack::Result<i32, ProtocolError> {
+        Ok(i32::from_be_bytes(self.take_array()?))
+    }
+
+    pub(crate) fn i64(&mut self) -> error_stack::Result<i64, ProtocolError> {
+        Ok(i64::from_be_bytes(self.take_array()?))
+    }
+
+    pub(crate) fn f64(&mut self) -> error_stack::Result<f64, ProtocolError> {
+        Ok(f64::from_be_bytes(self.take_array()?))
+    }
+
+    pub(crate) fn bool(&mut self) -> error_stack::Result<bool, ProtocolError> {
+        Ok(self.take_array::<1>()?[0] != 0)
+    }
+
+    fn unsigned_varint(&mut self) -> error_stack::Result<u64, ProtocolError> {
+        let mut value = 0u64;
+
+        for shift in (0..64).step_by(7) {
+            let byte = self.take_array::<1>()?[0];
+
+            value |= ((byte & 0x7f) as u64) << shift;
+
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+
+        Err(Report::new(ProtocolError::Varint))
+    }
+
+    fn compact_length(&mut self) -> error_stack::Result<Option<usize>, ProtocolError> {
+        match self.unsigned_varint()? {
+            0 => Ok(None),
+            length => Ok(Some(length as usize - 1)),
+        }
+    }
+
+    pub(crate) fn nullable_string(&mut self) -> error_stack::Result<Option<String>, ProtocolError> {
+        let length = if self.flexible {
+            self.compact_length()?
+        } else {
+            legacy_length(self.i16()? as i32)
+        };
+
+        match length {
+            None => Ok(None),
+            Some(length) => Ok(Some(
+                String::from_utf8_lossy(self.take(length)?).into_owned(),
+            )),
+        }
+    }
+
+    pub(crate) fn string(&mut self) -> error_stack::Result<String, ProtocolError> {
+        self.nullable_string()?
+            .ok_or(Report::new(ProtocolError::Decode("unexpected null string")))
+    }
+
+    pub(crate) fn nullable_bytes(
+        &mut self,
+    ) -> error_stack::Result<Option<&'a [u8]>, ProtocolError> {
+        let length = if self.flexible {
+            self.compact_length()?
+        } else {
+            legacy_length(self.i32()?)
+        };
+
+        length.map(|length| self.take(length)).transpose()
+    }
+
+    pub(crate) fn nullable_array<T>(
+        &mut self,
+        mut read: impl FnMut(&mut Self) -> error_stack::Result<T, ProtocolError>,
+    ) -> error_stack::Result<Option<Vec<T>>, ProtocolError> {
+        let length = if self.flexible {
+            self.compact_length()?
+        } else {
+            legacy_length(self.i32()?)
+        };
+
+        match length {
+            None => Ok(None),
+            Some(length) => (0..length)
+                .map(|_| read(self))
+                .collect::<Result<Vec<_>, _>>()
+                .map(Some),
+        }
+    }
+
+    pub(crate) fn array<T>(
+        &mut self,
+        read: impl FnMut(&mut Self) -> error_stack::Result<T, ProtocolError>,
+    ) -> error_stack::Result<Vec<T>, ProtocolError> {
+        Ok(self.nullable_array(read)?.unwrap_or_default())
+    }
+
+    /// Reads the tagged field section of a flexible struct, returning the raw
+    /// bytes of each field so callers can decode the tags they understand.
+    pub(crate) fn tagged_fields(
+        &mut self,
+    ) -> error_stack::Result<Vec<(u64, &'a [u8])>, ProtocolError> {
+        if !self.flexible {
+            return Ok(Vec::new());
+        }
+
+        (0..self.unsigned_varint()?)
+            .map(|_| {
+                let tag = self.unsigned_varint()?;
+                let size = self.unsigned_varint()? as usize;
+
+                Ok((tag, self.take(size)?))
+            })
+            .collect()
+    }
+}
+
+fn legacy_length(length: i32) -> Option<usize> {
+    (length >= 0).then_some(length as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Decoder, Encoder};
+    use crate::error::io::ProtocolError;
+
+    #[test]
+    fn varint_round_trips() {
+        for value in [0, 1, 0x7f, 0x80, 300, u32::MAX as u64, u64::MAX] {
+            let mut encoder = Encoder::new(true);
+            encoder.unsigned_varint(value);
+            let bytes = encoder.into_bytes();
+
+            let mut decoder = Decoder::new(&bytes, true);
+            assert_eq!(decoder.unsigned_varint().unwrap(), value);
+            assert_eq!(decoder.position, bytes.len());
+        }
+
+        let mut encoder = Encoder::new(true);
+        encoder.unsigned_varint(300);
+        assert_eq!(encoder.into_bytes(), [0xac, 0x02]);
+    }
+
+    #[test]
+    fn varint_longer_than_ten_bytes_is_rejected() {
+        let bytes = [0xff; 11];
+        let report = Decoder::new(&bytes, true).unsigned_varint().unwrap_err();
+
+        assert!(matches!(report.current_context(), ProtocolError::Varint));
+    }
+
+    #[test]
+    fn legacy_strings_use_i16_lengths() {
+        let mut encoder = Encoder::new(false);
+        encoder.string("kcli").nullable_string(None);
+        let bytes = encoder.into_bytes();
+
+        assert_eq!(bytes, [0, 4, b'k', b'c', b'l', b'i', 0xff, 0xff]);
+
+        let mut decoder = Decoder::new(&bytes, false);
+        assert_eq!(decoder.string().unwrap(), "kcli");
+        assert_eq!(decoder.nullable_string().unwrap(), None);
+    }
+
+    #[test]
+    fn compact_strings_use_length_plus_one() {
+        let mut encoder = Encoder::new(true);
+        encoder.string("").string("ab").nullable_string(None);
+        let bytes = encoder.into_bytes();
+
+        assert_eq!(bytes, [1, 3, b'a', b'b', 0]);
+
+        let mut decoder = Decoder::new(&bytes, true);
+        assert_eq!(decoder.string().unwrap(), "");
+        assert_eq!(decoder.string().unwrap(), "ab");
+        assert!(matches!(
+            decoder.string().unwrap_err().current_context(),
+            ProtocolError::Decode(_)
+        ));
+    }
+
+    #[test]
+    fn arrays_round_trip_in_both_encodings() {
+        for flexible in [false, true] {
+            let mut encoder = Encoder::new(flexible);
+            encoder
+                .array(&[1, 2, 3], |e, v| {
+                    e.i32(*v);
+                })
+                .nullable_array(None::<&[i32]>, |e, v| {
+                    e.i32(*v);
+                })
+                .bytes(b"xyz");
+            let bytes = encoder.into_bytes();
+
+            let mut decoder = Decoder::new(&bytes, flexible);
+            assert_eq!(decoder.array(|d| d.i32()).unwrap(), [1, 2, 3]);
+            assert_eq!(decoder.nullable_array(|d| d.i32()).unwrap(), None);
+            assert_eq!(decoder.nullable_bytes().unwrap(), Some(&b"xyz"[..]));
+            assert_eq!(decoder.position, bytes.len());
+        }
+    }
+
+    #[test]
+    fn primitives_are_big_endian() {
+        let mut encoder = Encoder::new(false);
+        encoder
+            .i8(-1)
+            .i16(0x0102)
+            .i32(-2)
+            .i64(1 << 40)
+            .f64(0.5)
+            .bool(true);
+        let bytes = encoder.into_bytes();
+
+        assert_eq!(&bytes[..3], [0xff, 0x01, 0x02]);
+
+        let mut decoder = Decoder::new(&bytes[1..], false);
+        assert_eq!(decoder.i16().unwrap(), 0x0102);
+        assert_eq!(decoder.i32().unwrap(), -2);
+        assert_eq!(decoder.i64().unwrap(), 1 << 40);
+        assert_eq!(decoder.f64().unwrap(), 0.5);
+        assert!(decoder.bool().unwrap());
+    }
+
+    #[test]
+    fn tagged_fields_are_returned_raw() {
+        let bytes = [2, 0, 1, 0xaa, 5, 2, 0xbb, 0xcc];
+        let mut decoder = Decoder::new(&bytes, true);
+
+        assert_eq!(
+            decoder.tagged_fields().unwrap(),
+            [(0, &[0xaa][..]), (5, &[0xbb, 0xcc][..])]
+        );
+        assert!(Decoder::new(&bytes, false)
+            .tagged_fields()
+            .unwrap()
+            .is_empty());
+
+        let mut encoder = Encoder::new(true);
+        encoder.tagged_fields();
+        assert_eq!(encoder.into_bytes(), [0]);
+    }
+
+    #[test]
+    fn truncated_input_is_reported() {
+        let report = Decoder::new(&[0, 5, b'a'], false).string().unwrap_err();
+
+        assert!(matches!(report.current_context(), ProtocolError::Truncated));
+    }
+}
@@ -0,0 +1,52 @@
+use serde::Serialize;
+
+use crate::error::io::ProtocolError;
+
+use super::{
+    codec::{Decoder, Encoder},
+    Request,
+};
+
+const TRANSACTION_KEY_TYPE: i8 = 1;
+
+/// Finds the broker coordinating the transactions of `transactional_id`.
+pub struct FindTransactionCoordinatorRequest<'a> {
+    pub transactional_id: &'a str,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct FindCoordinatorResponse {
+    pub error_code: i16,
+    pub error_message: Option<String>,
+    pub node_id: i32,
+}
+
+impl Request for FindTransactionCoordinatorRequest<'_> {
+    const API_KEY: i16 = 10;
+    const API_VERSION: i16 = 3;
+    const FLEXIBLE: bool = true;
+
+    type Response = FindCoordinatorResponse;
+
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder
+            .string(self.transactional_id)
+            .i8(TRANSACTION_KEY_TYPE)
+            .tagged_fields();
+    }
+
+    fn decode(decoder: &mut Decoder<'_>) -> error_stack::Result<Self::Response, ProtocolError> {
+        let _throttle_time_ms = decoder.i32()?;
+        let error_code = decoder.i16()?;
+        let error_message = decoder.nullable_string()?;
+        let node_id = decoder.i32()?;
+        let _host = decoder.string()?;
+        let _port = decoder.i32()?;
+
+        Ok(FindCoordinatorResponse {
+            error_code,
+            error_message,
+            node_id,
+        })
+    }
+}
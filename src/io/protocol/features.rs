@@ -0,0 +1,104 @@
+use std::{fmt::Display, time::Duration};
+
+use serde::Serialize;
+
+use crate::error::io::ProtocolError;
+
+use super::{
+    codec::{Decoder, Encoder},
+    Request,
+};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FeatureUpgradeType {
+    Upgrade,
+    SafeDowngrade,
+    UnsafeDowngrade,
+}
+
+impl FeatureUpgradeType {
+    fn code(&self) -> i8 {
+        match self {
+            Self::Upgrade => 1,
+            Self::SafeDowngrade => 2,
+            Self::UnsafeDowngrade => 3,
+        }
+    }
+}
+
+impl Display for FeatureUpgradeType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Upgrade => f.write_str("upgrade"),
+            Self::SafeDowngrade => f.write_str("safe downgrade"),
+            Self::UnsafeDowngrade => f.write_str("unsafe downgrade"),
+        }
+    }
+}
+
+/// Changes finalized feature levels. A level of 0 disables the feature.
+pub struct UpdateFeaturesRequest {
+    pub timeout: Duration,
+    pub updates: Vec<(String, i16, FeatureUpgradeType)>,
+    pub validate_only: bool,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct FeatureUpdateResult {
+    pub feature: String,
+    pub error_code: i16,
+    pub error_message: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct UpdateFeaturesResponse {
+    pub error_code: i16,
+    pub error_message: Option<String>,
+    pub results: Vec<FeatureUpdateResult>,
+}
+
+impl Request for UpdateFeaturesRequest {
+    const API_KEY: i16 = 57;
+    const API_VERSION: i16 = 1;
+    const FLEXIBLE: bool = true;
+
+    type Response = UpdateFeaturesResponse;
+
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder
+            .i32(self.timeout.as_millis() as i32)
+            .array(&self.updates, |e, (feature, level, upgrade_type)| {
+                e.string(feature)
+                    .i16(*level)
+                    .i8(upgrade_type.code())
+                    .tagged_fields();
+            })
+            .bool(self.validate_only)
+            .tagged_fields();
+    }
+
+    fn decode(decoder: &mut Decoder<'_>) -> error_stack::Result<Self::Response, ProtocolError> {
+        let _throttle_time_ms = decoder.i32()?;
+        let error_code = decoder.i16()?;
+        let error_message = decoder.nullable_string()?;
+
+        let results = decoder.array(|d| {
+            let result = FeatureUpdateResult {
+                feature: d.string()?,
+                error_code: d.i16()?,
+                error_message: d.nullable_string()?,
+            };
+
+            d.tagged_fields()?;
+
+            Ok(result)
+        })?;
+
+        Ok(UpdateFeaturesResponse {
+            error_code,
+            error_message,
+            results,
+        })
+    }
+}
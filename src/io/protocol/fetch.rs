@@ -0,0 +1,93 @@
+use std::collections::BTreeMap;
+
+use crate::error::io::ProtocolError;
+
+use super::{
+    codec::{Decoder, Encoder},
+    Request,
+};
+
+const CONSUMER_REPLICA_ID: i32 = -1;
+
+/// Fetches raw record batches, including control batches and the aborted
+/// transactions overlapping them, which librdkafka keeps to itself.
+pub struct FetchRequest {
+    /// Fetch offset of each partition, keyed by topic.
+    pub topics: BTreeMap<String, Vec<(i32, i64)>>,
+    pub partition_max_bytes: i32,
+    pub read_committed: bool,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct AbortedTransaction {
+    pub producer_id: i64,
+    pub first_offset: i64,
+}
+
+#[derive(Clone, Debug)]
+pub struct FetchedPartition {
+    pub topic: String,
+    pub partition: i32,
+    pub error_code: i16,
+    pub last_stable_offset: i64,
+    pub aborted_transactions: Vec<AbortedTransaction>,
+    pub records: Vec<u8>,
+}
+
+impl Request for FetchRequest {
+    const API_KEY: i16 = 1;
+    const API_VERSION: i16 = 4;
+    const FLEXIBLE: bool = false;
+
+    type Response = Vec<FetchedPartition>;
+
+    fn encode(&self, encoder: &mut Encoder) {
+        let topics = self.topics.iter().collect::<Vec<_>>();
+        let partitions = self.topics.values().map(Vec::len).sum::<usize>();
+
+        encoder
+            .i32(CONSUMER_REPLICA_ID)
+            .i32(0)
+            .i32(1)
+            .i32(self.partition_max_bytes.saturating_mul(partitions as i32))
+            .i8(self.read_committed as i8)
+            .array(&topics, |e, (topic, partitions)| {
+                e.string(topic).array(partitions, |e, (partition, offset)| {
+                    e.i32(*partition).i64(*offset).i32(self.partition_max_bytes);
+                });
+            });
+    }
+
+    fn decode(decoder: &mut Decoder<'_>) -> error_stack::Result<Self::Response, ProtocolError> {
+        let _throttle_time_ms = decoder.i32()?;
+
+        let topics = decoder.array(|d| {
+            let topic = d.string()?;
+
+            d.array(|d| {
+                let partition = d.i32()?;
+                let error_code = d.i16()?;
+                let _high_watermark = d.i64()?;
+                let last_stable_offset = d.i64()?;
+
+                Ok(FetchedPartition {
+                    topic: topic.clone(),
+                    partition,
+                    error_code,
+                    last_stable_offset,
+                    aborted_transactions: d
+                        .nullable_array(|d| {
+                            Ok(AbortedTransaction {
+                                producer_id: d.i64()?,
+                                first_offset: d.i64()?,
+                            })
+                        })?
+                        .unwrap_or_default(),
+                    records: d.nullable_bytes()?.map(<[u8]>::to_vec).unwrap_or_default(),
+                })
+            })
+        })?;
+
+        Ok(topics.into_iter().flatten().collect())
+    }
+}
@@ -0,0 +1,92 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use crate::error::io::ProtocolError;
+
+use super::{
+    codec::{Decoder, Encoder},
+    Request,
+};
+
+/// Topics of `None` describes every partition replica hosted by the broker.
+pub struct DescribeLogDirsRequest {
+    pub topics: Option<BTreeMap<String, Vec<i32>>>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct LogDirPartition {
+    pub topic: String,
+    pub partition: i32,
+    pub size: i64,
+    pub offset_lag: i64,
+    pub is_future: bool,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct LogDir {
+    pub error_code: i16,
+    pub log_dir: String,
+    pub partitions: Vec<LogDirPartition>,
+}
+
+impl Request for DescribeLogDirsRequest {
+    const API_KEY: i16 = 35;
+    const API_VERSION: i16 = 2;
+    const FLEXIBLE: bool = true;
+
+    type Response = Vec<LogDir>;
+
+    fn encode(&self, encoder: &mut Encoder) {
+        let topics = self.topics.as_ref().map(|t| t.iter().collect::<Vec<_>>());
+
+        encoder
+            .nullable_array(topics.as_deref(), |e, (topic, partitions)| {
+                e.string(topic)
+                    .array(partitions, |e, p| {
+                        e.i32(*p);
+                    })
+                    .tagged_fields();
+            })
+            .tagged_fields();
+    }
+
+    fn decode(decoder: &mut Decoder<'_>) -> error_stack::Result<Self::Response, ProtocolError> {
+        let _throttle_time_ms = decoder.i32()?;
+
+        decoder.array(|d| {
+            let error_code = d.i16()?;
+            let log_dir = d.string()?;
+
+            let partitions = d.array(|d| {
+                let topic = d.string()?;
+
+                let partitions = d.array(|d| {
+                    let partition = LogDirPartition {
+                        topic: topic.clone(),
+                        partition: d.i32()?,
+                        size: d.i64()?,
+                        offset_lag: d.i64()?,
+                        is_future: d.bool()?,
+                    };
+
+                    d.tagged_fields()?;
+
+                    Ok(partition)
+                })?;
+
+                d.tagged_fields()?;
+
+                Ok(partitions)
+            })?;
+
+            d.tagged_fields()?;
+
+            Ok(LogDir {
+                error_code,
+                log_dir,
+                partitions: partitions.into_iter().flatten().collect(),
+            })
+        })
+    }
+}
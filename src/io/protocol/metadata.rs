@@ -0,0 +1,53 @@
+use serde::Serialize;
+
+use crate::error::io::ProtocolError;
+
+use super::{
+    codec::{Decoder, Encoder},
+    Request,
+};
+
+/// Requests broker and controller metadata without any topics.
+pub struct MetadataRequest;
+
+#[derive(Clone, Debug, Serialize)]
+pub struct MetadataBroker {
+    pub node_id: i32,
+    pub host: String,
+    pub port: i32,
+    pub rack: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct MetadataResponse {
+    pub brokers: Vec<MetadataBroker>,
+    pub controller_id: i32,
+}
+
+impl Request for MetadataRequest {
+    const API_KEY: i16 = 3;
+    const API_VERSION: i16 = 1;
+    const FLEXIBLE: bool = false;
+
+    type Response = MetadataResponse;
+
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder.array::<String>(&[], |e, t| {
+            e.string(t);
+        });
+    }
+
+    fn decode(decoder: &mut Decoder<'_>) -> error_stack::Result<Self::Response, ProtocolError> {
+        Ok(MetadataResponse {
+            brokers: decoder.array(|d| {
+                Ok(MetadataBroker {
+                    node_id: d.i32()?,
+                    host: d.string()?,
+                    port: d.i32()?,
+                    rack: d.nullable_string()?,
+                })
+            })?,
+            controller_id: decoder.i32()?,
+        })
+    }
+}
@@ -0,0 +1,329 @@
+//! A small blocking client for Kafka protocol requests that librdkafka does not
+//! expose through its admin API.
+//!
+//! Only plaintext and SASL/PLAIN connections are supported. Requests are pinned
+//! to a single API version each, chosen to be widely supported by brokers.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    io::{Read, Write},
+    net::{TcpStream, ToSocketAddrs},
+    time::Duration,
+};
+
+use codec::{Decoder, Encoder};
+use error_stack::{Report, ResultExt};
+use metadata::MetadataRequest;
+use sasl::{SaslAuthenticateRequest, SaslHandshakeRequest};
+
+use crate::{
+    config::clusters::{auth::AuthType, ClusterConfig},
+    error::io::ProtocolError,
+};
+
+pub mod api_versions;
+mod codec;
+pub mod coordinator;
+pub mod features;
+pub mod fetch;
+pub mod log_dirs;
+pub mod metadata;
+pub mod quorum;
+pub mod quotas;
+pub mod reassignment;
+mod sasl;
+pub mod transactions;
+
+const CLIENT_ID: &str = "kcli";
+const SASL_PLAIN_MECHANISM: &str = "PLAIN";
+
+pub(crate) trait Request {
+    const API_KEY: i16;
+    const API_VERSION: i16;
+    const FLEXIBLE: bool;
+    // ApiVersions always answers with a v0 response header so clients can
+    // parse it before knowing what the broker supports.
+    const FLEXIBLE_RESPONSE_HEADER: bool = Self::FLEXIBLE;
+
+    type Response;
+
+    fn encode(&self, encoder: &mut Encoder);
+
+    fn decode(decoder: &mut Decoder<'_>) -> error_stack::Result<Self::Response, ProtocolError>;
+}
+
+pub struct BrokerConnection {
+    stream: TcpStream,
+    correlation_id: i32,
+}
+
+impl BrokerConnection {
+    pub fn open(
+        address: &str,
+        auth: Option<&AuthType>,
+        timeout: Duration,
+    ) -> error_stack::Result<Self, ProtocolError> {
+        let socket_addresses = address
+            .to_socket_addrs()
+            .change_context(ProtocolError::Connect(address.to_owned()))?;
+
+        let stream = socket_addresses
+            .into_iter()
+            .find_map(|a| TcpStream::connect_timeout(&a, timeout).ok())
+            .ok_or(Report::new(ProtocolError::Connect(address.to_owned())))?;
+
+        stream
+            .set_read_timeout(Some(timeout))
+            .and_then(|_| stream.set_write_timeout(Some(timeout)))
+            .change_context(ProtocolError::Connect(address.to_owned()))?;
+
+        let mut connection = Self {
+            stream,
+            correlation_id: 0,
+        };
+
+        match auth {
+            None | Some(AuthType::Plain) => (),
+            Some(AuthType::SaslPlain(auth)) => {
+                connection.authenticate_plain(auth.username(), auth.password())?
+            }
+            Some(AuthType::SaslSsl(_)) => {
+                Err(Report::new(ProtocolError::UnsupportedAuth("sasl_ssl")))?
+            }
+        }
+
+        Ok(connection)
+    }
+
+    fn authenticate_plain(
+        &mut self,
+        username: &str,
+        password: &str,
+    ) -> error_stack::Result<(), ProtocolError> {
+        let handshake = self.send(&SaslHandshakeRequest {
+            mechanism: SASL_PLAIN_MECHANISM.to_owned(),
+        })?;
+
+        if let Some(error) = error_string(handshake.error_code) {
+            Err(Report::new(ProtocolError::Authentication(error))).attach_printable(format!(
+                "Broker supports mechanisms: {}",
+                handshake.mechanisms.join(", ")
+            ))?
+        }
+
+        let authenticate = self.send(&SaslAuthenticateRequest {
+            auth_bytes: format!("\0{}\0{}", username, password).into_bytes(),
+        })?;
+
+        if let Some(error) = error_string(authenticate.error_code) {
+            Err(Report::new(ProtocolError::Authentication(
+                authenticate.error_message.unwrap_or(error),
+            )))?
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn send<R: Request>(
+        &mut self,
+        request: &R,
+    ) -> error_stack::Result<R::Response, ProtocolError> {
+        self.correlation_id = self.correlation_id.wrapping_add(1);
+
+        let mut encoder = Encoder::new(false);
+
+        encoder
+            .i16(R::API_KEY)
+            .i16(R::API_VERSION)
+            .i32(self.correlation_id)
+            .nullable_string(Some(CLIENT_ID));
+
+        let mut header = encoder.into_bytes();
+        let mut encoder = Encoder::new(R::FLEXIBLE);
+
+        encoder.tagged_fields();
+        request.encode(&mut encoder);
+
+        header.extend(encoder.into_bytes());
+
+        self.stream
+            .write_all(&(header.len() as i32).to_be_bytes())
+            .and_then(|_| self.stream.write_all(&header))
+            .change_context(ProtocolError::Io)?;
+
+        let mut size = [0; 4];
+
+        self.stream
+            .read_exact(&mut size)
+            .change_context(ProtocolError::Io)?;
+
+        let mut response = vec![0; i32::from_be_bytes(size).max(0) as usize];
+
+        self.stream
+            .read_exact(&mut response)
+            .change_context(ProtocolError::Io)?;
+
+        let mut decoder = Decoder::new(&response, R::FLEXIBLE_RESPONSE_HEADER);
+
+        if decoder.i32()? != self.correlation_id {
+            Err(Report::new(ProtocolError::Decode(
+                "response correlation id does not match request",
+            )))?
+        }
+
+        decoder.tagged_fields()?;
+
+        let mut decoder = decoder.with_flexible(R::FLEXIBLE);
+
+        R::decode(&mut decoder)
+            .attach_printable_lazy(|| format!("API key {} version {}", R::API_KEY, R::API_VERSION))
+    }
+}
+
+pub struct ProtocolClient {
+    auth: Option<AuthType>,
+    brokers: BTreeMap<i32, String>,
+    controller: i32,
+    connections: HashMap<i32, BrokerConnection>,
+    timeout: Duration,
+}
+
+impl ProtocolClient {
+    pub fn connect(
+        cluster_config: &ClusterConfig,
+        timeout: Duration,
+    ) -> error_stack::Result<Self, ProtocolError> {
+        let mut last_error = Report::new(ProtocolError::Connect(
+            cluster_config.bootstrap_servers.join(","),
+        ));
+
+        for server in &cluster_config.bootstrap_servers {
+            let metadata = BrokerConnection::open(server, cluster_config.auth(), timeout)
+                .and_then(|mut connection| connection.send(&MetadataRequest));
+
+            match metadata {
+                Ok(metadata) => {
+                    return Ok(Self {
+                        auth: cluster_config.auth().cloned(),
+                        brokers: metadata
+                            .brokers
+                            .into_iter()
+                            .map(|b| (b.node_id, format!("{}:{}", b.host, b.port)))
+                            .collect(),
+                        controller: metadata.controller_id,
+                        connections: HashMap::new(),
+                        timeout,
+                    })
+                }
+                Err(e) => last_error = e,
+            }
+        }
+
+        Err(last_error)
+    }
+
+    pub fn brokers(&self) -> &BTreeMap<i32, String> {
+        &self.brokers
+    }
+
+    pub(crate) fn send<R: Request>(
+        &mut self,
+        broker: i32,
+        request: &R,
+    ) -> error_stack::Result<R::Response, ProtocolError> {
+        if !self.connections.contains_key(&broker) {
+            let address = self
+                .brokers
+                .get(&broker)
+                .ok_or(Report::new(ProtocolError::UnknownBroker(broker)))?;
+
+            let connection = BrokerConnection::open(address, self.auth.as_ref(), self.timeout)?;
+
+            self.connections.insert(broker, connection);
+        }
+
+        let connection = self
+            .connections
+            .get_mut(&broker)
+            .expect("Connection inserted above.");
+
+        let response = connection.send(request);
+
+        // A failed exchange leaves the stream in an unknown state.
+        if response.is_err() {
+            self.connections.remove(&broker);
+        }
+
+        response
+    }
+
+    pub(crate) fn send_to_controller<R: Request>(
+        &mut self,
+        request: &R,
+    ) -> error_stack::Result<R::Response, ProtocolError> {
+        self.send(self.controller, request)
+    }
+}
+
+pub fn error_string(code: i16) -> Option<String> {
+    let name = match code {
+        0 => return None,
+        -1 => "UNKNOWN_SERVER_ERROR",
+        1 => "OFFSET_OUT_OF_RANGE",
+        2 => "CORRUPT_MESSAGE",
+        3 => "UNKNOWN_TOPIC_OR_PARTITION",
+        5 => "LEADER_NOT_AVAILABLE",
+        6 => "NOT_LEADER_OR_FOLLOWER",
+        7 => "REQUEST_TIMED_OUT",
+        8 => "BROKER_NOT_AVAILABLE",
+        9 => "REPLICA_NOT_AVAILABLE",
+        13 => "NETWORK_EXCEPTION",
+        14 => "COORDINATOR_LOAD_IN_PROGRESS",
+        15 => "COORDINATOR_NOT_AVAILABLE",
+        16 => "NOT_COORDINATOR",
+        17 => "INVALID_TOPIC_EXCEPTION",
+        19 => "NOT_ENOUGH_REPLICAS",
+        20 => "NOT_ENOUGH_REPLICAS_AFTER_APPEND",
+        29 => "TOPIC_AUTHORIZATION_FAILED",
+        31 => "CLUSTER_AUTHORIZATION_FAILED",
+        33 => "UNSUPPORTED_SASL_MECHANISM",
+        34 => "ILLEGAL_SASL_STATE",
+        35 => "UNSUPPORTED_VERSION",
+        37 => "INVALID_PARTITIONS",
+        38 => "INVALID_REPLICATION_FACTOR",
+        39 => "INVALID_REPLICA_ASSIGNMENT",
+        40 => "INVALID_CONFIG",
+        41 => "NOT_CONTROLLER",
+        42 => "INVALID_REQUEST",
+        44 => "POLICY_VIOLATION",
+        45 => "OUT_OF_ORDER_SEQUENCE_NUMBER",
+        47 => "INVALID_PRODUCER_EPOCH",
+        48 => "INVALID_TXN_STATE",
+        49 => "INVALID_PRODUCER_ID_MAPPING",
+        51 => "CONCURRENT_TRANSACTIONS",
+        53 => "TRANSACTIONAL_ID_AUTHORIZATION_FAILED",
+        56 => "KAFKA_STORAGE_ERROR",
+        57 => "LOG_DIR_NOT_FOUND",
+        58 => "SASL_AUTHENTICATION_FAILED",
+        59 => "UNKNOWN_PRODUCER_ID",
+        60 => "REASSIGNMENT_IN_PROGRESS",
+        73 => "TOPIC_DELETION_DISABLED",
+        80 => "PREFERRED_LEADER_NOT_AVAILABLE",
+        83 => "ELIGIBLE_LEADERS_NOT_AVAILABLE",
+        84 => "ELECTION_NOT_NEEDED",
+        85 => "NO_REASSIGNMENT_IN_PROGRESS",
+        87 => "INVALID_RECORD",
+        89 => "THROTTLING_QUOTA_EXCEEDED",
+        90 => "PRODUCER_FENCED",
+        91 => "RESOURCE_NOT_FOUND",
+        92 => "DUPLICATE_RESOURCE",
+        93 => "UNACCEPTABLE_CREDENTIAL",
+        95 => "INVALID_UPDATE_VERSION",
+        96 => "FEATURE_UPDATE_FAILED",
+        100 => "UNKNOWN_TOPIC_ID",
+        105 => "TRANSACTIONAL_ID_NOT_FOUND",
+        code => return Some(format!("ERROR_CODE_{}", code)),
+    };
+
+    Some(name.to_owned())
+}
@@ -0,0 +1,118 @@
+use serde::Serialize;
+
+use crate::error::io::ProtocolError;
+
+use super::{
+    codec::{Decoder, Encoder},
+    Request,
+};
+
+const METADATA_TOPIC: &str = "__cluster_metadata";
+const METADATA_PARTITION: i32 = 0;
+
+/// Describes the KRaft quorum replicating the cluster metadata log.
+pub struct DescribeQuorumRequest;
+
+#[derive(Clone, Debug, Serialize)]
+pub struct QuorumReplica {
+    pub replica_id: i32,
+    pub log_end_offset: i64,
+    pub last_fetch_timestamp: i64,
+    pub last_caught_up_timestamp: i64,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct QuorumDescription {
+    pub error_code: i16,
+    pub leader_id: i32,
+    pub leader_epoch: i32,
+    pub high_watermark: i64,
+    pub voters: Vec<QuorumReplica>,
+    pub observers: Vec<QuorumReplica>,
+}
+
+impl Request for DescribeQuorumRequest {
+    const API_KEY: i16 = 55;
+    const API_VERSION: i16 = 1;
+    const FLEXIBLE: bool = true;
+
+    type Response = QuorumDescription;
+
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder
+            .array(&[METADATA_TOPIC], |e, topic| {
+                e.string(topic)
+                    .array(&[METADATA_PARTITION], |e, partition| {
+                        e.i32(*partition).tagged_fields();
+                    })
+                    .tagged_fields();
+            })
+            .tagged_fields();
+    }
+
+    fn decode(decoder: &mut Decoder<'_>) -> error_stack::Result<Self::Response, ProtocolError> {
+        let error_code = decoder.i16()?;
+
+        let mut partitions = decoder.array(|d| {
+            let _topic = d.string()?;
+
+            let partitions = d.array(|d| {
+                let _partition = d.i32()?;
+                let error_code = d.i16()?;
+                let leader_id = d.i32()?;
+                let leader_epoch = d.i32()?;
+                let high_watermark = d.i64()?;
+                let voters = d.array(decode_replica)?;
+                let observers = d.array(decode_replica)?;
+
+                d.tagged_fields()?;
+
+                Ok(QuorumDescription {
+                    error_code,
+                    leader_id,
+                    leader_epoch,
+                    high_watermark,
+                    voters,
+                    observers,
+                })
+            })?;
+
+            d.tagged_fields()?;
+
+            Ok(partitions)
+        })?;
+
+        // Errors for the whole request come without any partitions.
+        Ok(partitions
+            .pop()
+            .and_then(|mut p| p.pop())
+            .map(|p| QuorumDescription {
+                error_code: match error_code {
+                    0 => p.error_code,
+                    _ => error_code,
+                },
+                ..p
+            })
+            .unwrap_or(QuorumDescription {
+                error_code,
+                leader_id: -1,
+                leader_epoch: -1,
+                high_watermark: -1,
+                voters: Vec::new(),
+                observers: Vec::new(),
+            }))
+    }
+}
+
+fn decode_replica(decoder: &mut Decoder<'_>) -> error_stack::Result<QuorumReplica, ProtocolError> {
+    let replica = QuorumReplica {
+        replica_id: decoder.i32()?,
+        log_end_offset: decoder.i64()?,
+        last_fetch_timestamp: decoder.i64()?,
+        last_caught_up_timestamp: decoder.i64()?,
+    };
+
+    decoder.tagged_fields()?;
+
+    Ok(replica)
+}
@@ -0,0 +1,156 @@
+use serde::Serialize;
+
+use crate::error::io::ProtocolError;
+
+use super::{
+    codec::{Decoder, Encoder},
+    Request,
+};
+
+/// Entity components as `(entity_type, entity_name)`, where a name of `None`
+/// addresses the default entity of that type.
+pub type QuotaEntity = Vec<(String, Option<String>)>;
+
+#[derive(Clone, Copy, Debug)]
+pub enum QuotaMatch<'a> {
+    Exact(&'a str),
+    Default,
+}
+
+impl QuotaMatch<'_> {
+    fn match_type(&self) -> i8 {
+        match self {
+            Self::Exact(_) => 0,
+            Self::Default => 1,
+        }
+    }
+
+    fn name(&self) -> Option<&str> {
+        match self {
+            Self::Exact(name) => Some(name),
+            Self::Default => None,
+        }
+    }
+}
+
+/// An empty component list with `strict` unset describes every quota entity.
+pub struct DescribeClientQuotasRequest<'a> {
+    pub components: Vec<(&'a str, QuotaMatch<'a>)>,
+    pub strict: bool,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct QuotaEntry {
+    pub entity: QuotaEntity,
+    pub values: Vec<(String, f64)>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct DescribeClientQuotasResponse {
+    pub error_code: i16,
+    pub error_message: Option<String>,
+    pub entries: Vec<QuotaEntry>,
+}
+
+fn encode_entity(encoder: &mut Encoder, entity: &QuotaEntity) {
+    encoder.array(entity, |e, (entity_type, entity_name)| {
+        e.string(entity_type)
+            .nullable_string(entity_name.as_deref());
+    });
+}
+
+fn decode_entity(decoder: &mut Decoder<'_>) -> error_stack::Result<QuotaEntity, ProtocolError> {
+    decoder.array(|d| Ok((d.string()?, d.nullable_string()?)))
+}
+
+impl Request for DescribeClientQuotasRequest<'_> {
+    const API_KEY: i16 = 48;
+    const API_VERSION: i16 = 0;
+    const FLEXIBLE: bool = false;
+
+    type Response = DescribeClientQuotasResponse;
+
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder
+            .array(&self.components, |e, (entity_type, matcher)| {
+                e.string(entity_type)
+                    .i8(matcher.match_type())
+                    .nullable_string(matcher.name());
+            })
+            .bool(self.strict);
+    }
+
+    fn decode(decoder: &mut Decoder<'_>) -> error_stack::Result<Self::Response, ProtocolError> {
+        let _throttle_time_ms = decoder.i32()?;
+        let error_code = decoder.i16()?;
+        let error_message = decoder.nullable_string()?;
+
+        let entries = decoder.array(|d| {
+            Ok(QuotaEntry {
+                entity: decode_entity(d)?,
+                values: d.array(|d| Ok((d.string()?, d.f64()?)))?,
+            })
+        })?;
+
+        Ok(DescribeClientQuotasResponse {
+            error_code,
+            error_message,
+            entries,
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum QuotaOp {
+    Set(f64),
+    Remove,
+}
+
+pub struct AlterClientQuotasRequest {
+    pub entries: Vec<(QuotaEntity, Vec<(String, QuotaOp)>)>,
+    pub validate_only: bool,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct AlterQuotaResult {
+    pub error_code: i16,
+    pub error_message: Option<String>,
+    pub entity: QuotaEntity,
+}
+
+impl Request for AlterClientQuotasRequest {
+    const API_KEY: i16 = 49;
+    const API_VERSION: i16 = 0;
+    const FLEXIBLE: bool = false;
+
+    type Response = Vec<AlterQuotaResult>;
+
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder
+            .array(&self.entries, |e, (entity, ops)| {
+                encode_entity(e, entity);
+
+                e.array(ops, |e, (key, op)| {
+                    let (value, remove) = match op {
+                        QuotaOp::Set(value) => (*value, false),
+                        QuotaOp::Remove => (0.0, true),
+                    };
+
+                    e.string(key).f64(value).bool(remove);
+                });
+            })
+            .bool(self.validate_only);
+    }
+
+    fn decode(decoder: &mut Decoder<'_>) -> error_stack::Result<Self::Response, ProtocolError> {
+        let _throttle_time_ms = decoder.i32()?;
+
+        decoder.array(|d| {
+            Ok(AlterQuotaResult {
+                error_code: d.i16()?,
+                error_message: d.nullable_string()?,
+                entity: decode_entity(d)?,
+            })
+        })
+    }
+}
@@ -0,0 +1,173 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use crate::error::io::ProtocolError;
+
+use super::{
+    codec::{Decoder, Encoder},
+    Request,
+};
+
+/// Target replicas by partition, where `None` cancels any in progress
+/// reassignment for the partition.
+pub type TargetReplicas = Vec<(i32, Option<Vec<i32>>)>;
+
+pub struct AlterPartitionReassignmentsRequest {
+    pub timeout_ms: i32,
+    pub topics: BTreeMap<String, TargetReplicas>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ReassignmentResult {
+    pub topic: String,
+    pub partition: i32,
+    pub error_code: i16,
+    pub error_message: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct AlterPartitionReassignmentsResponse {
+    pub error_code: i16,
+    pub error_message: Option<String>,
+    pub partitions: Vec<ReassignmentResult>,
+}
+
+impl Request for AlterPartitionReassignmentsRequest {
+    const API_KEY: i16 = 45;
+    const API_VERSION: i16 = 0;
+    const FLEXIBLE: bool = true;
+
+    type Response = AlterPartitionReassignmentsResponse;
+
+    fn encode(&self, encoder: &mut Encoder) {
+        let topics = self.topics.iter().collect::<Vec<_>>();
+
+        encoder
+            .i32(self.timeout_ms)
+            .array(&topics, |e, (topic, partitions)| {
+                e.string(topic)
+                    .array(partitions, |e, (partition, replicas)| {
+                        e.i32(*partition)
+                            .nullable_array(replicas.as_deref(), |e, r| {
+                                e.i32(*r);
+                            })
+                            .tagged_fields();
+                    })
+                    .tagged_fields();
+            })
+            .tagged_fields();
+    }
+
+    fn decode(decoder: &mut Decoder<'_>) -> error_stack::Result<Self::Response, ProtocolError> {
+        let _throttle_time_ms = decoder.i32()?;
+        let error_code = decoder.i16()?;
+        let error_message = decoder.nullable_string()?;
+
+        let partitions = decoder.array(|d| {
+            let topic = d.string()?;
+
+            let partitions = d.array(|d| {
+                let result = ReassignmentResult {
+                    topic: topic.clone(),
+                    partition: d.i32()?,
+                    error_code: d.i16()?,
+                    error_message: d.nullable_string()?,
+                };
+
+                d.tagged_fields()?;
+
+                Ok(result)
+            })?;
+
+            d.tagged_fields()?;
+
+            Ok(partitions)
+        })?;
+
+        Ok(AlterPartitionReassignmentsResponse {
+            error_code,
+            error_message,
+            partitions: partitions.into_iter().flatten().collect(),
+        })
+    }
+}
+
+/// Topics of `None` lists every in progress reassignment in the cluster.
+pub struct ListPartitionReassignmentsRequest {
+    pub timeout_ms: i32,
+    pub topics: Option<BTreeMap<String, Vec<i32>>>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct OngoingReassignment {
+    pub topic: String,
+    pub partition: i32,
+    pub replicas: Vec<i32>,
+    pub adding_replicas: Vec<i32>,
+    pub removing_replicas: Vec<i32>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ListPartitionReassignmentsResponse {
+    pub error_code: i16,
+    pub error_message: Option<String>,
+    pub reassignments: Vec<OngoingReassignment>,
+}
+
+impl Request for ListPartitionReassignmentsRequest {
+    const API_KEY: i16 = 46;
+    const API_VERSION: i16 = 0;
+    const FLEXIBLE: bool = true;
+
+    type Response = ListPartitionReassignmentsResponse;
+
+    fn encode(&self, encoder: &mut Encoder) {
+        let topics = self.topics.as_ref().map(|t| t.iter().collect::<Vec<_>>());
+
+        encoder
+            .i32(self.timeout_ms)
+            .nullable_array(topics.as_deref(), |e, (topic, partitions)| {
+                e.string(topic)
+                    .array(partitions, |e, p| {
+                        e.i32(*p);
+                    })
+                    .tagged_fields();
+            })
+            .tagged_fields();
+    }
+
+    fn decode(decoder: &mut Decoder<'_>) -> error_stack::Result<Self::Response, ProtocolError> {
+        let _throttle_time_ms = decoder.i32()?;
+        let error_code = decoder.i16()?;
+        let error_message = decoder.nullable_string()?;
+
+        let reassignments = decoder.array(|d| {
+            let topic = d.string()?;
+
+            let partitions = d.array(|d| {
+                let reassignment = OngoingReassignment {
+                    topic: topic.clone(),
+                    partition: d.i32()?,
+                    replicas: d.array(|d| d.i32())?,
+                    adding_replicas: d.array(|d| d.i32())?,
+                    removing_replicas: d.array(|d| d.i32())?,
+                };
+
+                d.tagged_fields()?;
+
+                Ok(reassignment)
+            })?;
+
+            d.tagged_fields()?;
+
+            Ok(partitions)
+        })?;
+
+        Ok(ListPartitionReassignmentsResponse {
+            error_code,
+            error_message,
+            reassignments: reassignments.into_iter().flatten().collect(),
+        })
+    }
+}
@@ -0,0 +1,62 @@
+use crate::error::io::ProtocolError;
+
+use super::{
+    codec::{Decoder, Encoder},
+    Request,
+};
+
+pub(super) struct SaslHandshakeRequest {
+    pub(super) mechanism: String,
+}
+
+pub(super) struct SaslHandshakeResponse {
+    pub(super) error_code: i16,
+    pub(super) mechanisms: Vec<String>,
+}
+
+impl Request for SaslHandshakeRequest {
+    const API_KEY: i16 = 17;
+    const API_VERSION: i16 = 1;
+    const FLEXIBLE: bool = false;
+
+    type Response = SaslHandshakeResponse;
+
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder.string(&self.mechanism);
+    }
+
+    fn decode(decoder: &mut Decoder<'_>) -> error_stack::Result<Self::Response, ProtocolError> {
+        Ok(SaslHandshakeResponse {
+            error_code: decoder.i16()?,
+            mechanisms: decoder.array(|d| d.string())?,
+        })
+    }
+}
+
+pub(super) struct SaslAuthenticateRequest {
+    pub(super) auth_bytes: Vec<u8>,
+}
+
+pub(super) struct SaslAuthenticateResponse {
+    pub(super) error_code: i16,
+    pub(super) error_message: Option<String>,
+}
+
+impl Request for SaslAuthenticateRequest {
+    const API_KEY: i16 = 36;
+    const API_VERSION: i16 = 0;
+    const FLEXIBLE: bool = false;
+
+    type Response = SaslAuthenticateResponse;
+
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder.bytes(&self.auth_bytes);
+    }
+
+    fn decode(decoder: &mut Decoder<'_>) -> error_stack::Result<Self::Response, ProtocolError> {
+        Ok(SaslAuthenticateResponse {
+            error_code: decoder.i16()?,
+            error_message: decoder.nullable_string()?,
+        })
+    }
+}
@@ -0,0 +1,315 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use crate::error::io::ProtocolError;
+
+use super::{
+    codec::{Decoder, Encoder},
+    Request,
+};
+
+/// Empty filters list every transaction the receiving broker coordinates.
+pub struct ListTransactionsRequest {
+    pub state_filters: Vec<String>,
+    pub producer_id_filters: Vec<i64>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct TransactionListing {
+    pub transactional_id: String,
+    pub producer_id: i64,
+    pub state: String,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ListTransactionsResponse {
+    pub error_code: i16,
+    pub unknown_state_filters: Vec<String>,
+    pub transactions: Vec<TransactionListing>,
+}
+
+impl Request for ListTransactionsRequest {
+    const API_KEY: i16 = 66;
+    const API_VERSION: i16 = 0;
+    const FLEXIBLE: bool = true;
+
+    type Response = ListTransactionsResponse;
+
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder
+            .array(&self.state_filters, |e, s| {
+                e.string(s);
+            })
+            .array(&self.producer_id_filters, |e, p| {
+                e.i64(*p);
+            })
+            .tagged_fields();
+    }
+
+    fn decode(decoder: &mut Decoder<'_>) -> error_stack::Result<Self::Response, ProtocolError> {
+        let _throttle_time_ms = decoder.i32()?;
+        let error_code = decoder.i16()?;
+        let unknown_state_filters = decoder.array(|d| d.string())?;
+
+        let transactions = decoder.array(|d| {
+            let listing = TransactionListing {
+                transactional_id: d.string()?,
+                producer_id: d.i64()?,
+                state: d.string()?,
+            };
+
+            d.tagged_fields()?;
+
+            Ok(listing)
+        })?;
+
+        Ok(ListTransactionsResponse {
+            error_code,
+            unknown_state_filters,
+            transactions,
+        })
+    }
+}
+
+/// Must be sent to the transaction coordinator of every id.
+pub struct DescribeTransactionsRequest {
+    pub transactional_ids: Vec<String>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct TransactionDescription {
+    pub error_code: i16,
+    pub transactional_id: String,
+    pub state: String,
+    pub timeout_ms: i32,
+    pub start_time_ms: i64,
+    pub producer_id: i64,
+    pub producer_epoch: i16,
+    pub partitions: Vec<(String, i32)>,
+}
+
+impl Request for DescribeTransactionsRequest {
+    const API_KEY: i16 = 65;
+    const API_VERSION: i16 = 0;
+    const FLEXIBLE: bool = true;
+
+    type Response = Vec<TransactionDescription>;
+
+    fn encode(&self, encoder: &mut Encoder) {
+        encoder
+            .array(&self.transactional_ids, |e, id| {
+                e.string(id);
+            })
+            .tagged_fields();
+    }
+
+    fn decode(decoder: &mut Decoder<'_>) -> error_stack::Result<Self::Response, ProtocolError> {
+        let _throttle_time_ms = decoder.i32()?;
+
+        let descriptions = decoder.array(|d| {
+            let error_code = d.i16()?;
+            let transactional_id = d.string()?;
+            let state = d.string()?;
+            let timeout_ms = d.i32()?;
+            let start_time_ms = d.i64()?;
+            let producer_id = d.i64()?;
+            let producer_epoch = d.i16()?;
+
+            let partitions = d.array(|d| {
+                let topic = d.string()?;
+                let partitions = d.array(|d| Ok((topic.clone(), d.i32()?)))?;
+
+                d.tagged_fields()?;
+
+                Ok(partitions)
+            })?;
+
+            d.tagged_fields()?;
+
+            Ok(TransactionDescription {
+                error_code,
+                transactional_id,
+                state,
+                timeout_ms,
+                start_time_ms,
+                producer_id,
+                producer_epoch,
+                partitions: partitions.into_iter().flatten().collect(),
+            })
+        })?;
+
+        Ok(descriptions)
+    }
+}
+
+/// Must be sent to the leader of every requested partition.
+pub struct DescribeProducersRequest {
+    pub topics: BTreeMap<String, Vec<i32>>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ActiveProducer {
+    pub producer_id: i64,
+    pub producer_epoch: i32,
+    pub last_sequence: i32,
+    pub last_timestamp: i64,
+    pub coordinator_epoch: i32,
+    pub current_txn_start_offset: i64,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct PartitionProducers {
+    pub topic: String,
+    pub partition: i32,
+    pub error_code: i16,
+    pub error_message: Option<String>,
+    pub producers: Vec<ActiveProducer>,
+}
+
+impl Request for DescribeProducersRequest {
+    const API_KEY: i16 = 61;
+    const API_VERSION: i16 = 0;
+    const FLEXIBLE: bool = true;
+
+    type Response = Vec<PartitionProducers>;
+
+    fn encode(&self, encoder: &mut Encoder) {
+        let topics = self.topics.iter().collect::<Vec<_>>();
+
+        encoder
+            .array(&topics, |e, (topic, partitions)| {
+                e.string(topic)
+                    .array(partitions, |e, p| {
+                        e.i32(*p);
+                    })
+                    .tagged_fields();
+            })
+            .tagged_fields();
+    }
+
+    fn decode(decoder: &mut Decoder<'_>) -> error_stack::Result<Self::Response, ProtocolError> {
+        let _throttle_time_ms = decoder.i32()?;
+
+        let topics = decoder.array(|d| {
+            let topic = d.string()?;
+
+            let partitions = d.array(|d| {
+                let partition = d.i32()?;
+                let error_code = d.i16()?;
+                let error_message = d.nullable_string()?;
+
+                let producers = d.array(|d| {
+                    let producer = ActiveProducer {
+                        producer_id: d.i64()?,
+                        producer_epoch: d.i32()?,
+                        last_sequence: d.i32()?,
+                        last_timestamp: d.i64()?,
+                        coordinator_epoch: d.i32()?,
+                        current_txn_start_offset: d.i64()?,
+                    };
+
+                    d.tagged_fields()?;
+
+                    Ok(producer)
+                })?;
+
+                d.tagged_fields()?;
+
+                Ok(PartitionProducers {
+                    topic: topic.clone(),
+                    partition,
+                    error_code,
+                    error_message,
+                    producers,
+                })
+            })?;
+
+            d.tagged_fields()?;
+
+            Ok(partitions)
+        })?;
+
+        Ok(topics.into_iter().flatten().collect())
+    }
+}
+
+/// Writes an abort marker for a single producer, as the transaction
+/// coordinator would when a transaction times out. Must be sent to the leader
+/// of every listed partition.
+pub struct WriteTxnMarkersRequest {
+    pub producer_id: i64,
+    pub producer_epoch: i16,
+    pub coordinator_epoch: i32,
+    pub topics: BTreeMap<String, Vec<i32>>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct MarkerResult {
+    pub topic: String,
+    pub partition: i32,
+    pub error_code: i16,
+}
+
+impl Request for WriteTxnMarkersRequest {
+    const API_KEY: i16 = 27;
+    const API_VERSION: i16 = 1;
+    const FLEXIBLE: bool = true;
+
+    type Response = Vec<MarkerResult>;
+
+    fn encode(&self, encoder: &mut Encoder) {
+        let topics = self.topics.iter().collect::<Vec<_>>();
+
+        encoder
+            .array(&[()], |e, _| {
+                e.i64(self.producer_id)
+                    .i16(self.producer_epoch)
+                    // Only abort markers are written, committing is left to
+                    // the coordinator.
+                    .bool(false)
+                    .array(&topics, |e, (topic, partitions)| {
+                        e.string(topic)
+                            .array(partitions, |e, p| {
+                                e.i32(*p);
+                            })
+                            .tagged_fields();
+                    })
+                    .i32(self.coordinator_epoch)
+                    .tagged_fields();
+            })
+            .tagged_fields();
+    }
+
+    fn decode(decoder: &mut Decoder<'_>) -> error_stack::Result<Self::Response, ProtocolError> {
+        let markers = decoder.array(|d| {
+            let _producer_id = d.i64()?;
+
+            let topics = d.array(|d| {
+                let topic = d.string()?;
+
+                let partitions = d.array(|d| {
+                    let result = MarkerResult {
+                        topic: topic.clone(),
+                        partition: d.i32()?,
+                        error_code: d.i16()?,
+                    };
+
+                    d.tagged_fields()?;
+
+                    Ok(result)
+                })?;
+
+                d.tagged_fields()?;
+
+                Ok(partitions)
+            })?;
+
+            d.tagged_fields()?;
+
+            Ok(topics.into_iter().flatten().collect::<Vec<_>>())
+        })?;
+
+        Ok(markers.into_iter().flatten().collect())
+    }
+}
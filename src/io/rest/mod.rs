@@ -0,0 +1,295 @@
+//! Blocking JSON client for the HTTP services deployed next to a cluster.
+
+use std::time::Duration;
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use error_stack::{Report, ResultExt};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use ureq::{Agent, AgentBuilder};
+
+use crate::{config::clusters::rest::RestEndpoint, error::io::RestError};
+
+pub struct RestClient {
+    agent: Agent,
+    url: String,
+    content_type: &'static str,
+    authorization: Option<String>,
+}
+
+/// Both the schema registry and Kafka Connect report failures this way.
+#[derive(Deserialize)]
+struct ErrorBody {
+    message: String,
+}
+
+impl RestClient {
+    pub fn new(endpoint: &RestEndpoint, content_type: &'static str, timeout: Duration) -> Self {
+        Self {
+            agent: AgentBuilder::new().timeout(timeout).build(),
+            url: endpoint.url.trim_end_matches('/').to_owned(),
+            content_type,
+            authorization: endpoint.auth.as_ref().map(|auth| {
+                format!(
+                    "Basic {}",
+                    STANDARD.encode(format!("{}:{}", auth.username(), auth.password()))
+                )
+            }),
+        }
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn get<T>(&self, path: &str) -> error_stack::Result<T, RestError>
+    where
+        T: DeserializeOwned,
+    {
+        self.call("GET", path, None::<&()>)
+    }
+
+    pub fn delete<T>(&self, path: &str) -> error_stack::Result<T, RestError>
+    where
+        T: DeserializeOwned,
+    {
+        self.call("DELETE", path, None::<&()>)
+    }
+
+    pub fn post<B, T>(&self, path: &str, body: Option<&B>) -> error_stack::Result<T, RestError>
+    where
+        B: Serialize,
+        T: DeserializeOwned,
+    {
+        self.call("POST", path, body)
+    }
+
+    pub fn put<B, T>(&self, path: &str, body: Option<&B>) -> error_stack::Result<T, RestError>
+    where
+        B: Serialize,
+        T: DeserializeOwned,
+    {
+        self.call("PUT", path, body)
+    }
+
+    /// Empty response bodies decode as `null`, so `()` and `Option` can be
+    /// used for endpoints that only answer with a status.
+    fn call<B, T>(
+        &self,
+        method: &str,
+        path: &str,
+        body: Option<&B>,
+    ) -> error_stack::Result<T, RestError>
+    where
+        B: Serialize,
+        T: DeserializeOwned,
+    {
+        let url = format!("{}{}", self.url, path);
+
+        let mut request = self
+            .agent
+            .request(method, &url)
+            .set("Accept", self.content_type);
+
+        if let Some(authorization) = &self.authorization {
+            request = request.set("Authorization", authorization);
+        }
+
+        let result = match body {
+            Some(body) => request
+                .set("Content-Type", self.content_type)
+                .send_json(body),
+            None => request.call(),
+        };
+
+        let response = match result {
+            Ok(response) => response,
+            Err(ureq::Error::Status(status, response)) => {
+                let body = response.into_string().unwrap_or_default();
+                let message = serde_json::from_str::<ErrorBody>(&body)
+                    .map(|e| e.message)
+                    .unwrap_or(body);
+
+                Err(Report::new(RestError::Status(url.clone(), status, message)))?
+            }
+            Err(e) => Err(Report::new(RestError::Transport(url.clone())).attach_printable(e))?,
+        };
+
+        let body = response
+            .into_string()
+            .change_context(RestError::Decode(url.clone()))?;
+
+        let body = match body.trim().is_empty() {
+            true => "null",
+            false => &body,
+        };
+
+        serde_json::from_str(body).change_context(RestError::Decode(url))
+    }
+}
+
+/// Percent-encodes a single path segment, such as a subject or connector name.
+pub fn encode_segment(segment: &str) -> String {
+    segment
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (b as char).to_string()
+            }
+            b => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+        time::Duration,
+    };
+
+    use serde::Deserialize;
+
+    use super::{encode_segment, RestClient};
+    use crate::{config::clusters::rest::RestEndpoint, error::io::RestError};
+
+    /// The request line, headers and body of a request sent to [`serve`].
+    pub(crate) struct Request {
+        pub line: String,
+        pub headers: Vec<String>,
+        pub body: String,
+    }
+
+    /// Answers a single request on a local port with `status` and `body`.
+    pub(crate) fn serve(status: u16, body: &'static str) -> (RestEndpoint, JoinHandle<Request>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+
+            let mut headers = Vec::new();
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).unwrap();
+                match header.trim_end() {
+                    "" => break,
+                    header => headers.push(header.to_owned()),
+                }
+            }
+
+            let length = headers
+                .iter()
+                .find_map(|h| {
+                    let (name, value) = h.split_once(':')?;
+                    name.eq_ignore_ascii_case("content-length")
+                        .then(|| value.trim().parse::<usize>().unwrap())
+                })
+                .unwrap_or_default();
+
+            let mut request_body = vec![0; length];
+            reader.read_exact(&mut request_body).unwrap();
+
+            write!(
+                reader.get_mut(),
+                "HTTP/1.1 {} Status\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+
+            Request {
+                line: line.trim_end().to_owned(),
+                headers,
+                body: String::from_utf8(request_body).unwrap(),
+            }
+        });
+
+        (RestEndpoint::new(url), handle)
+    }
+
+    fn client(endpoint: &RestEndpoint) -> RestClient {
+        RestClient::new(endpoint, "application/json", Duration::from_secs(5))
+    }
+
+    #[test]
+    fn encode_segment_escapes_reserved_bytes() {
+        assert_eq!(encode_segment("orders-value"), "orders-value");
+        assert_eq!(encode_segment("a.b_c~d"), "a.b_c~d");
+        assert_eq!(encode_segment("a/b c"), "a%2Fb%20c");
+        assert_eq!(encode_segment("topic?x=1&y"), "topic%3Fx%3D1%26y");
+        assert_eq!(encode_segment("é"), "%C3%A9");
+    }
+
+    #[test]
+    fn error_body_message_is_reported_in_status() {
+        let (endpoint, handle) = serve(
+            404,
+            r#"{"error_code":40401,"message":"Subject not found."}"#,
+        );
+        let report = client(&endpoint).get::<()>("/subjects/x").unwrap_err();
+        handle.join().unwrap();
+
+        match report.current_context() {
+            RestError::Status(url, status, message) => {
+                assert!(url.ends_with("/subjects/x"), "{}", url);
+                assert_eq!(*status, 404);
+                assert_eq!(message, "Subject not found.");
+            }
+            e => panic!("unexpected error: {:?}", e),
+        }
+    }
+
+    #[test]
+    fn error_body_without_message_is_reported_verbatim() {
+        let (endpoint, handle) = serve(500, "boom");
+        let report = client(&endpoint).get::<()>("/").unwrap_err();
+        handle.join().unwrap();
+
+        assert!(matches!(
+            report.current_context(),
+            RestError::Status(_, 500, message) if message == "boom"
+        ));
+    }
+
+    #[test]
+    fn empty_body_decodes_as_null() {
+        let (endpoint, handle) = serve(204, "");
+        client(&endpoint).delete::<()>("/connectors/x").unwrap();
+        handle.join().unwrap();
+
+        let (endpoint, handle) = serve(200, "  ");
+        let value = client(&endpoint).get::<Option<i32>>("/").unwrap();
+        handle.join().unwrap();
+
+        assert_eq!(value, None);
+    }
+
+    #[test]
+    fn post_sends_json_body() {
+        #[derive(Deserialize)]
+        struct Id {
+            id: i32,
+        }
+
+        let (endpoint, handle) = serve(200, r#"{"id":7}"#);
+        let id = client(&endpoint)
+            .post::<_, Id>("/things", Some(&vec![1, 2]))
+            .unwrap();
+        let request = handle.join().unwrap();
+
+        assert_eq!(id.id, 7);
+        assert_eq!(request.line, "POST /things HTTP/1.1");
+        assert_eq!(request.body, "[1,2]");
+        assert!(request
+            .headers
+            .iter()
+            .any(|h| h.eq_ignore_ascii_case("content-type: application/json")));
+    }
+}
@@ -0,0 +1,438 @@
+use std::{fmt::Display, str::FromStr, time::Duration};
+
+use clap::{builder::PossibleValue, ValueEnum};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    config::clusters::rest::RestEndpoint,
+    error::io::RestError,
+    io::rest::{encode_segment, RestClient},
+};
+
+const CONTENT_TYPE: &str = "application/vnd.schemaregistry.v1+json";
+
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum SchemaType {
+    #[default]
+    Avro,
+    Json,
+    Protobuf,
+}
+
+impl SchemaType {
+    /// Picks the type from a schema file extension, if it is a known one.
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension {
+            "avsc" => Some(Self::Avro),
+            "json" => Some(Self::Json),
+            "proto" => Some(Self::Protobuf),
+            _ => None,
+        }
+    }
+}
+
+impl Display for SchemaType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.to_possible_value()
+            .expect("no values are skipped")
+            .get_name()
+            .fmt(f)
+    }
+}
+
+impl FromStr for SchemaType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        for variant in Self::value_variants() {
+            if variant.to_possible_value().unwrap().matches(s, false) {
+                return Ok(*variant);
+            }
+        }
+        Err(format!("invalid variant: {s}"))
+    }
+}
+
+impl ValueEnum for SchemaType {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[Self::Avro, Self::Json, Self::Protobuf]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        Some(match self {
+            Self::Avro => PossibleValue::new("avro"),
+            Self::Json => PossibleValue::new("json"),
+            Self::Protobuf => PossibleValue::new("protobuf"),
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Compatibility {
+    None,
+    Backward,
+    BackwardTransitive,
+    Forward,
+    ForwardTransitive,
+    Full,
+    FullTransitive,
+}
+
+impl Display for Compatibility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.to_possible_value()
+            .expect("no values are skipped")
+            .get_name()
+            .fmt(f)
+    }
+}
+
+impl FromStr for Compatibility {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        for variant in Self::value_variants() {
+            if variant.to_possible_value().unwrap().matches(s, false) {
+                return Ok(*variant);
+            }
+        }
+        Err(format!("invalid variant: {s}"))
+    }
+}
+
+impl ValueEnum for Compatibility {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[
+            Self::None,
+            Self::Backward,
+            Self::BackwardTransitive,
+            Self::Forward,
+            Self::ForwardTransitive,
+            Self::Full,
+            Self::FullTransitive,
+        ]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        Some(match self {
+            Self::None => PossibleValue::new("none"),
+            Self::Backward => PossibleValue::new("backward"),
+            Self::BackwardTransitive => PossibleValue::new("backward_transitive"),
+            Self::Forward => PossibleValue::new("forward"),
+            Self::ForwardTransitive => PossibleValue::new("forward_transitive"),
+            Self::Full => PossibleValue::new("full"),
+            Self::FullTransitive => PossibleValue::new("full_transitive"),
+        })
+    }
+}
+
+/// A subject version, either a number or `latest`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum SchemaVersion {
+    #[default]
+    Latest,
+    Number(i32),
+}
+
+impl Display for SchemaVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Latest => f.write_str("latest"),
+            Self::Number(version) => version.fmt(f),
+        }
+    }
+}
+
+impl FromStr for SchemaVersion {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "latest" => Ok(Self::Latest),
+            s => s
+                .parse::<i32>()
+                .map(Self::Number)
+                .map_err(|_| format!("expected a version number or 'latest': {s}")),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SchemaReference {
+    pub name: String,
+    pub subject: String,
+    pub version: i32,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Schema {
+    pub subject: String,
+    pub version: i32,
+    pub id: i32,
+    #[serde(default)]
+    pub schema_type: SchemaType,
+    pub schema: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub references: Vec<SchemaReference>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewSchema {
+    pub schema: String,
+    pub schema_type: SchemaType,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub references: Vec<SchemaReference>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CompatibilityCheck {
+    pub is_compatible: bool,
+    #[serde(default)]
+    pub messages: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct RegisteredSchema {
+    id: i32,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ConfigResponse {
+    compatibility_level: Compatibility,
+}
+
+#[derive(Deserialize, Serialize)]
+struct ConfigUpdate {
+    compatibility: Compatibility,
+}
+
+pub struct SchemaRegistry(RestClient);
+
+impl SchemaRegistry {
+    pub fn new(endpoint: &RestEndpoint, timeout: Duration) -> Self {
+        Self(RestClient::new(endpoint, CONTENT_TYPE, timeout))
+    }
+
+    pub fn url(&self) -> &str {
+        self.0.url()
+    }
+
+    pub fn subjects(&self, deleted: bool) -> error_stack::Result<Vec<String>, RestError> {
+        self.0.get(&format!("/subjects?deleted={}", deleted))
+    }
+
+    pub fn versions(&self, subject: &str) -> error_stack::Result<Vec<i32>, RestError> {
+        self.0
+            .get(&format!("/subjects/{}/versions", encode_segment(subject)))
+    }
+
+    pub fn schema(
+        &self,
+        subject: &str,
+        version: SchemaVersion,
+    ) -> error_stack::Result<Schema, RestError> {
+        self.0.get(&format!(
+            "/subjects/{}/versions/{}",
+            encode_segment(subject),
+            version
+        ))
+    }
+
+    /// Returns the id of the schema, which is reused when the same schema is
+    /// already registered.
+    pub fn register(
+        &self,
+        subject: &str,
+        schema: &NewSchema,
+    ) -> error_stack::Result<i32, RestError> {
+        self.0
+            .post::<_, RegisteredSchema>(
+                &format!("/subjects/{}/versions", encode_segment(subject)),
+                Some(schema),
+            )
+            .map(|r| r.id)
+    }
+
+    /// Returns the deleted versions. Hard deletes only apply to versions that
+    /// were soft deleted before.
+    pub fn delete_subject(
+        &self,
+        subject: &str,
+        permanent: bool,
+    ) -> error_stack::Result<Vec<i32>, RestError> {
+        self.0.delete(&format!(
+            "/subjects/{}?permanent={}",
+            encode_segment(subject),
+            permanent
+        ))
+    }
+
+    pub fn delete_version(
+        &self,
+        subject: &str,
+        version: SchemaVersion,
+        permanent: bool,
+    ) -> error_stack::Result<i32, RestError> {
+        self.0.delete(&format!(
+            "/subjects/{}/versions/{}?permanent={}",
+            encode_segment(subject),
+            version,
+            permanent
+        ))
+    }
+
+    /// Subjects without their own level fall back to the global one.
+    pub fn compatibility(
+        &self,
+        subject: Option<&str>,
+    ) -> error_stack::Result<Compatibility, RestError> {
+        let path = match subject {
+            Some(subject) => format!("/config/{}?defaultToGlobal=true", encode_segment(subject)),
+            None => "/config".to_owned(),
+        };
+
+        self.0
+            .get::<ConfigResponse>(&path)
+            .map(|c| c.compatibility_level)
+    }
+
+    pub fn set_compatibility(
+        &self,
+        subject: Option<&str>,
+        compatibility: Compatibility,
+    ) -> error_stack::Result<Compatibility, RestError> {
+        let path = match subject {
+            Some(subject) => format!("/config/{}", encode_segment(subject)),
+            None => "/config".to_owned(),
+        };
+
+        self.0
+            .put::<_, ConfigUpdate>(&path, Some(&ConfigUpdate { compatibility }))
+            .map(|c| c.compatibility)
+    }
+
+    pub fn check(
+        &self,
+        subject: &str,
+        version: SchemaVersion,
+        schema: &NewSchema,
+    ) -> error_stack::Result<CompatibilityCheck, RestError> {
+        self.0.post(
+            &format!(
+                "/compatibility/subjects/{}/versions/{}?verbose=true",
+                encode_segment(subject),
+                version
+            ),
+            Some(schema),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{NewSchema, SchemaReference, SchemaRegistry, SchemaType, SchemaVersion};
+    use crate::io::rest::tests::serve;
+
+    fn registry(endpoint: &crate::config::clusters::rest::RestEndpoint) -> SchemaRegistry {
+        SchemaRegistry::new(endpoint, Duration::from_secs(5))
+    }
+
+    #[test]
+    fn check_posts_schema_to_verbose_compatibility_endpoint() {
+        let (endpoint, handle) = serve(
+            200,
+            r#"{"is_compatible":false,"messages":["READER_FIELD_MISSING_DEFAULT_VALUE"]}"#,
+        );
+        let schema = NewSchema {
+            schema: r#"{"type":"string"}"#.to_owned(),
+            schema_type: SchemaType::Avro,
+            references: vec![],
+        };
+
+        let check = registry(&endpoint)
+            .check("orders/value", SchemaVersion::Latest, &schema)
+            .unwrap();
+        let request = handle.join().unwrap();
+
+        assert!(!check.is_compatible);
+        assert_eq!(check.messages, ["READER_FIELD_MISSING_DEFAULT_VALUE"]);
+        assert_eq!(
+            request.line,
+            "POST /compatibility/subjects/orders%2Fvalue/versions/latest?verbose=true HTTP/1.1"
+        );
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&request.body).unwrap(),
+            serde_json::json!({ "schema": r#"{"type":"string"}"#, "schemaType": "AVRO" })
+        );
+        assert!(request.headers.iter().any(
+            |h| h.eq_ignore_ascii_case("content-type: application/vnd.schemaregistry.v1+json")
+        ));
+    }
+
+    #[test]
+    fn check_sends_references_and_version_number() {
+        let (endpoint, handle) = serve(200, r#"{"is_compatible":true}"#);
+        let schema = NewSchema {
+            schema: "syntax = \"proto3\";".to_owned(),
+            schema_type: SchemaType::Protobuf,
+            references: vec![SchemaReference {
+                name: "common.proto".to_owned(),
+                subject: "common".to_owned(),
+                version: 2,
+            }],
+        };
+
+        let check = registry(&endpoint)
+            .check("orders", SchemaVersion::Number(3), &schema)
+            .unwrap();
+        let request = handle.join().unwrap();
+
+        assert!(check.is_compatible);
+        assert!(check.messages.is_empty());
+        assert_eq!(
+            request.line,
+            "POST /compatibility/subjects/orders/versions/3?verbose=true HTTP/1.1"
+        );
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&request.body).unwrap(),
+            serde_json::json!({
+                "schema": "syntax = \"proto3\";",
+                "schemaType": "PROTOBUF",
+                "references": [{ "name": "common.proto", "subject": "common", "version": 2 }],
+            })
+        );
+    }
+
+    #[test]
+    fn schema_type_defaults_to_avro() {
+        let (endpoint, handle) = serve(
+            200,
+            r#"{"subject":"orders","version":1,"id":4,"schema":"\"string\""}"#,
+        );
+
+        let schema = registry(&endpoint)
+            .schema("orders", SchemaVersion::Latest)
+            .unwrap();
+        let request = handle.join().unwrap();
+
+        assert_eq!(schema.schema_type, SchemaType::Avro);
+        assert_eq!(
+            request.line,
+            "GET /subjects/orders/versions/latest HTTP/1.1"
+        );
+    }
+
+    #[test]
+    fn version_parses_latest_and_numbers() {
+        assert_eq!("latest".parse(), Ok(SchemaVersion::Latest));
+        assert_eq!("12".parse(), Ok(SchemaVersion::Number(12)));
+        assert!("twelve".parse::<SchemaVersion>().is_err());
+    }
+}
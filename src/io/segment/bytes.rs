@@ -0,0 +1,98 @@
+use error_stack::{Report, ResultExt};
+
+use crate::error::io::SegmentError;
+
+pub(super) struct ByteReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> ByteReader<'a> {
+    pub(super) fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    pub(super) fn remaining(&self) -> usize {
+        self.bytes.len() - self.position
+    }
+
+    pub(super) fn take(&mut self, length: usize) -> error_stack::Result<&'a [u8], SegmentError> {
+        if self.remaining() < length {
+            Err(Report::new(SegmentError::Truncated)).attach_printable(format!(
+                "Wanted {} bytes at position {}, only {} remaining.",
+                length,
+                self.position,
+                self.remaining()
+            ))?
+        }
+
+        let slice = &self.bytes[self.position..self.position + length];
+
+        self.position += length;
+
+        Ok(slice)
+    }
+
+    fn take_array<const N: usize>(&mut self) -> error_stack::Result<[u8; N], SegmentError> {
+        Ok(self
+            .take(N)?
+            .try_into()
+            .expect("Slice length checked by take."))
+    }
+
+    pub(super) fn i8(&mut self) -> error_stack::Result<i8, SegmentError> {
+        Ok(i8::from_be_bytes(self.take_array()?))
+    }
+
+    pub(super) fn i16(&mut self) -> error_stack::Result<i16, SegmentError> {
+        Ok(i16::from_be_bytes(self.take_array()?))
+    }
+
+    pub(super) fn i32(&mut self) -> error_stack::Result<i32, SegmentError> {
+        Ok(i32::from_be_bytes(self.take_array()?))
+    }
+
+    pub(super) fn u32(&mut self) -> error_stack::Result<u32, SegmentError> {
+        Ok(u32::from_be_bytes(self.take_array()?))
+    }
+
+    pub(super) fn i64(&mut self) -> error_stack::Result<i64, SegmentError> {
+        Ok(i64::from_be_bytes(self.take_array()?))
+    }
+
+    fn unsigned_varint(&mut self) -> error_stack::Result<u64, SegmentError> {
+        let mut value = 0u64;
+
+        for shift in (0..64).step_by(7) {
+            let byte = self.take_array::<1>()?[0];
+
+            value |= ((byte & 0x7f) as u64) << shift;
+
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+
+        Err(Report::new(SegmentError::Varint))
+    }
+
+    pub(super) fn varint(&mut self) -> error_stack::Result<i32, SegmentError> {
+        let value = self.unsigned_varint()?;
+
+        i32::try_from(((value >> 1) as i64) ^ -((value & 1) as i64))
+            .map_err(|_| Report::new(SegmentError::Varint))
+    }
+
+    pub(super) fn varlong(&mut self) -> error_stack::Result<i64, SegmentError> {
+        let value = self.unsigned_varint()?;
+
+        Ok(((value >> 1) as i64) ^ -((value & 1) as i64))
+    }
+
+    pub(super) fn varint_bytes(&mut self) -> error_stack::Result<Option<&'a [u8]>, SegmentError> {
+        match self.varint()? {
+            length if length < 0 => Ok(None),
+            length => self.take(length as usize).map(Some),
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use error_stack::{Report, ResultExt};
+use serde::Serialize;
+
+use crate::error::io::SegmentError;
+
+use super::bytes::ByteReader;
+
+const OFFSET_INDEX_ENTRY_SIZE: usize = 8;
+const TIME_INDEX_ENTRY_SIZE: usize = 12;
+
+#[derive(Clone, Copy, Debug, Serialize)]
+pub struct OffsetIndexEntry {
+    pub offset: i64,
+    pub position: usize,
+}
+
+#[derive(Clone, Copy, Debug, Serialize)]
+pub struct TimeIndexEntry {
+    pub timestamp: i64,
+    pub offset: i64,
+}
+
+fn check_entry_size(bytes: &[u8], entry_size: usize) -> error_stack::Result<(), SegmentError> {
+    if !bytes.len().is_multiple_of(entry_size) {
+        Err(Report::new(SegmentError::Corrupt(
+            "index size is not a multiple of its entry size",
+        )))
+        .attach_printable(format!(
+            "Index is {} bytes, entries are {} bytes.",
+            bytes.len(),
+            entry_size
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+// Index files are preallocated and zero filled, so the first all zero entry
+// after the head of the index marks its end.
+pub fn read_offset_index(
+    bytes: &[u8],
+    base_offset: i64,
+) -> error_stack::Result<Vec<OffsetIndexEntry>, SegmentError> {
+    check_entry_size(bytes, OFFSET_INDEX_ENTRY_SIZE)?;
+
+    let mut reader = ByteReader::new(bytes);
+    let mut entries = Vec::new();
+
+    while reader.remaining() > 0 {
+        let relative_offset = reader.i32()?;
+        let position = reader.i32()?;
+
+        if !entries.is_empty() && relative_offset == 0 && position == 0 {
+            break;
+        }
+
+        entries.push(OffsetIndexEntry {
+            offset: base_offset + relative_offset as i64,
+            position: position as usize,
+        });
+    }
+
+    Ok(entries)
+}
+
+pub fn read_time_index(
+    bytes: &[u8],
+    base_offset: i64,
+) -> error_stack::Result<Vec<TimeIndexEntry>, SegmentError> {
+    check_entry_size(bytes, TIME_INDEX_ENTRY_SIZE)?;
+
+    let mut reader = ByteReader::new(bytes);
+    let mut entries = Vec::new();
+
+    while reader.remaining() > 0 {
+        let timestamp = reader.i64()?;
+        let relative_offset = reader.i32()?;
+
+        if timestamp == 0 && relative_offset == 0 {
+            break;
+        }
+
+        entries.push(TimeIndexEntry {
+            timestamp,
+            offset: base_offset + relative_offset as i64,
+        });
+    }
+
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{read_offset_index, read_time_index};
+    use crate::error::io::SegmentError;
+
+    fn offset_entry(relative_offset: i32, position: i32) -> Vec<u8> {
+        [relative_offset.to_be_bytes(), position.to_be_bytes()].concat()
+    }
+
+    fn time_entry(timestamp: i64, relative_offset: i32) -> Vec<u8> {
+        [&timestamp.to_be_bytes()[..], &relative_offset.to_be_bytes()].concat()
+    }
+
+    #[test]
+    fn offset_index_stops_at_zero_fill() {
+        let bytes = [
+            offset_entry(0, 0),
+            offset_entry(10, 4096),
+            offset_entry(0, 0),
+            offset_entry(0, 0),
+        ]
+        .concat();
+
+        let entries = read_offset_index(&bytes, 500).unwrap();
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!((entries[0].offset, entries[0].position), (500, 0));
+        assert_eq!((entries[1].offset, entries[1].position), (510, 4096));
+    }
+
+    #[test]
+    fn time_index_stops_at_zero_fill() {
+        let bytes = [time_entry(1_000, 3), time_entry(0, 0)].concat();
+
+        let entries = read_time_index(&bytes, 500).unwrap();
+
+        assert_eq!(entries.len(), 1);
+        assert_eq!((entries[0].timestamp, entries[0].offset), (1_000, 503));
+        assert!(read_time_index(&time_entry(0, 0), 0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn partial_entries_are_rejected() {
+        let report = read_offset_index(&[0; 12], 0).unwrap_err();
+        assert!(matches!(report.current_context(), SegmentError::Corrupt(_)));
+
+        let report = read_time_index(&[0; 8], 0).unwrap_err();
+        assert!(matches!(report.current_context(), SegmentError::Corrupt(_)));
+    }
+}
@@ -0,0 +1,625 @@
+use std::{fmt::Display, io::Read};
+
+use bytes::ByteReader;
+use error_stack::{Report, ResultExt};
+use serde::Serialize;
+
+use crate::error::io::SegmentError;
+
+mod bytes;
+pub mod index;
+
+const BATCH_OVERHEAD: usize = 61;
+const LOG_OVERHEAD: usize = 12;
+const XERIAL_SNAPPY_MAGIC: &[u8] = &[0x82, b'S', b'N', b'A', b'P', b'P', b'Y', 0];
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Compression {
+    None,
+    Gzip,
+    Snappy,
+    Lz4,
+    Zstd,
+}
+
+impl Compression {
+    fn from_attributes(attributes: i16) -> error_stack::Result<Self, SegmentError> {
+        match attributes & 0x07 {
+            0 => Ok(Self::None),
+            1 => Ok(Self::Gzip),
+            2 => Ok(Self::Snappy),
+            3 => Ok(Self::Lz4),
+            4 => Ok(Self::Zstd),
+            codec => Err(Report::new(SegmentError::UnknownCompression(codec))),
+        }
+    }
+
+    fn decompress(&self, bytes: &[u8]) -> error_stack::Result<Vec<u8>, SegmentError> {
+        let mut buff = Vec::new();
+
+        match self {
+            Self::None => buff.extend_from_slice(bytes),
+            Self::Gzip => {
+                flate2::read::MultiGzDecoder::new(bytes)
+                    .read_to_end(&mut buff)
+                    .change_context(SegmentError::Decompress(*self))?;
+            }
+            Self::Snappy => {
+                if bytes.starts_with(XERIAL_SNAPPY_MAGIC) {
+                    let mut reader = ByteReader::new(&bytes[XERIAL_SNAPPY_MAGIC.len()..]);
+
+                    // Skip xerial version and compatible version.
+                    reader.take(8)?;
+
+                    while reader.remaining() > 0 {
+                        let length = reader.i32()?;
+                        let block = reader.take(length as usize)?;
+
+                        buff.extend(
+                            snap::raw::Decoder::new()
+                                .decompress_vec(block)
+                                .change_context(SegmentError::Decompress(*self))?,
+                        );
+                    }
+                } else {
+                    buff = snap::raw::Decoder::new()
+                        .decompress_vec(bytes)
+                        .change_context(SegmentError::Decompress(*self))?;
+                }
+            }
+            Self::Lz4 => {
+                lz4_flex::frame::FrameDecoder::new(bytes)
+                    .read_to_end(&mut buff)
+                    .change_context(SegmentError::Decompress(*self))?;
+            }
+            Self::Zstd => {
+                buff = zstd::stream::decode_all(bytes)
+                    .change_context(SegmentError::Decompress(*self))?;
+            }
+        }
+
+        Ok(buff)
+    }
+}
+
+impl Display for Compression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => f.write_str("none"),
+            Self::Gzip => f.write_str("gzip"),
+            Self::Snappy => f.write_str("snappy"),
+            Self::Lz4 => f.write_str("lz4"),
+            Self::Zstd => f.write_str("zstd"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+pub enum TimestampType {
+    CreateTime,
+    LogAppendTime,
+}
+
+impl Display for TimestampType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::CreateTime => f.write_str("CreateTime"),
+            Self::LogAppendTime => f.write_str("LogAppendTime"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct RecordBatchHeader {
+    pub base_offset: i64,
+    pub batch_length: i32,
+    pub partition_leader_epoch: i32,
+    pub magic: i8,
+    pub crc: u32,
+    pub attributes: i16,
+    pub last_offset_delta: i32,
+    pub base_timestamp: i64,
+    pub max_timestamp: i64,
+    pub producer_id: i64,
+    pub producer_epoch: i16,
+    pub base_sequence: i32,
+    pub record_count: i32,
+}
+
+impl RecordBatchHeader {
+    pub fn last_offset(&self) -> i64 {
+        self.base_offset + self.last_offset_delta as i64
+    }
+
+    pub fn last_sequence(&self) -> i32 {
+        if self.base_sequence < 0 {
+            self.base_sequence
+        } else {
+            self.base_sequence.wrapping_add(self.last_offset_delta)
+        }
+    }
+
+    pub fn compression(&self) -> error_stack::Result<Compression, SegmentError> {
+        Compression::from_attributes(self.attributes)
+    }
+
+    pub fn timestamp_type(&self) -> TimestampType {
+        if self.attributes & 0x08 == 0 {
+            TimestampType::CreateTime
+        } else {
+            TimestampType::LogAppendTime
+        }
+    }
+
+    pub fn is_transactional(&self) -> bool {
+        self.attributes & 0x10 != 0
+    }
+
+    pub fn is_control(&self) -> bool {
+        self.attributes & 0x20 != 0
+    }
+
+    pub fn has_delete_horizon(&self) -> bool {
+        self.attributes & 0x40 != 0
+    }
+
+    pub fn size(&self) -> usize {
+        LOG_OVERHEAD + self.batch_length as usize
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct RecordBatch<'a> {
+    pub position: usize,
+    pub header: RecordBatchHeader,
+    pub crc_valid: bool,
+    records: &'a [u8],
+}
+
+impl RecordBatch<'_> {
+    pub fn records(&self) -> error_stack::Result<Vec<Record>, SegmentError> {
+        let compression = self.header.compression()?;
+        let decompressed = compression.decompress(self.records)?;
+        let mut reader = ByteReader::new(&decompressed);
+
+        (0..self.header.record_count)
+            .map(|_| Record::read(&mut reader, &self.header))
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct Header {
+    pub key: String,
+    pub value: Option<Vec<u8>>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct Record {
+    pub offset: i64,
+    pub timestamp: i64,
+    pub sequence: i32,
+    pub key: Option<Vec<u8>>,
+    pub value: Option<Vec<u8>>,
+    pub headers: Vec<Header>,
+}
+
+impl Record {
+    fn read(
+        reader: &mut ByteReader,
+        header: &RecordBatchHeader,
+    ) -> error_stack::Result<Self, SegmentError> {
+        let length = reader.varint()?;
+        let mut reader = ByteReader::new(reader.take(length as usize)?);
+
+        let _attributes = reader.i8()?;
+        let timestamp_delta = reader.varlong()?;
+        let offset_delta = reader.varint()?;
+        let key = reader.varint_bytes()?.map(<[u8]>::to_vec);
+        let value = reader.varint_bytes()?.map(<[u8]>::to_vec);
+
+        let header_count = reader.varint()?;
+        let mut headers = Vec::new();
+
+        for _ in 0..header_count {
+            let key = reader
+                .varint_bytes()?
+                .ok_or(Report::new(SegmentError::Corrupt("null header key")))?;
+
+            headers.push(Header {
+                key: String::from_utf8_lossy(key).into_owned(),
+                value: reader.varint_bytes()?.map(<[u8]>::to_vec),
+            });
+        }
+
+        let sequence = if header.base_sequence < 0 {
+            header.base_sequence
+        } else {
+            header.base_sequence.wrapping_add(offset_delta)
+        };
+
+        Ok(Self {
+            offset: header.base_offset + offset_delta as i64,
+            timestamp: match header.timestamp_type() {
+                TimestampType::CreateTime => header.base_timestamp + timestamp_delta,
+                TimestampType::LogAppendTime => header.max_timestamp,
+            },
+            sequence,
+            key,
+            value,
+            headers,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+pub enum ControlRecordType {
+    Abort,
+    Commit,
+    Unknown(i16),
+}
+
+impl ControlRecordType {
+    pub fn from_key(key: &[u8]) -> error_stack::Result<Self, SegmentError> {
+        let mut reader = ByteReader::new(key);
+        let _version = reader.i16()?;
+
+        Ok(match reader.i16()? {
+            0 => Self::Abort,
+            1 => Self::Commit,
+            other => Self::Unknown(other),
+        })
+    }
+}
+
+impl Display for ControlRecordType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Abort => f.write_str("ABORT"),
+            Self::Commit => f.write_str("COMMIT"),
+            Self::Unknown(code) => f.write_str(&format!("UNKNOWN({})", code)),
+        }
+    }
+}
+
+pub struct SegmentReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> SegmentReader<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    pub fn batch_at(&self, position: usize) -> error_stack::Result<RecordBatch<'a>, SegmentError> {
+        let bytes = self
+            .bytes
+            .get(position..)
+            .ok_or(Report::new(SegmentError::Truncated))?;
+
+        let mut reader = ByteReader::new(bytes);
+
+        let base_offset = reader.i64()?;
+        let batch_length = reader.i32()?;
+        let partition_leader_epoch = reader.i32()?;
+        let magic = reader.i8()?;
+
+        if magic != 2 {
+            Err(Report::new(SegmentError::UnsupportedMagic(magic)))
+                .attach_printable(format!("Batch at position {}", position))?
+        }
+
+        if (batch_length as usize) < BATCH_OVERHEAD - LOG_OVERHEAD {
+            Err(Report::new(SegmentError::Corrupt(
+                "batch length below header size",
+            )))
+            .attach_printable(format!("Batch at position {}", position))?
+        }
+
+        let crc = reader.u32()?;
+        let crc_data = reader.take(batch_length as usize - 9)?;
+        let crc_valid = crc32c::crc32c(crc_data) == crc;
+
+        let mut reader = ByteReader::new(crc_data);
+
+        let header = RecordBatchHeader {
+            base_offset,
+            batch_length,
+            partition_leader_epoch,
+            magic,
+            crc,
+            attributes: reader.i16()?,
+            last_offset_delta: reader.i32()?,
+            base_timestamp: reader.i64()?,
+            max_timestamp: reader.i64()?,
+            producer_id: reader.i64()?,
+            producer_epoch: reader.i16()?,
+            base_sequence: reader.i32()?,
+            record_count: reader.i32()?,
+        };
+
+        Ok(RecordBatch {
+            position,
+            header,
+            crc_valid,
+            records: reader.take(reader.remaining())?,
+        })
+    }
+}
+
+impl<'a> Iterator for SegmentReader<'a> {
+    type Item = error_stack::Result<RecordBatch<'a>, SegmentError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.bytes.len() - self.position < LOG_OVERHEAD {
+            return None;
+        }
+
+        match self.batch_at(self.position) {
+            Ok(batch) => {
+                self.position += batch.header.size();
+
+                Some(Ok(batch))
+            }
+            Err(e) => {
+                self.position = self.bytes.len();
+
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::io::Write;
+
+    use flate2::{write::GzEncoder, Compression as GzLevel};
+
+    use super::{ControlRecordType, SegmentReader, TimestampType};
+    use crate::error::io::SegmentError;
+
+    fn varlong(buff: &mut Vec<u8>, value: i64) {
+        let mut value = ((value << 1) ^ (value >> 63)) as u64;
+
+        while value >= 0x80 {
+            buff.push((value as u8 & 0x7f) | 0x80);
+            value >>= 7;
+        }
+
+        buff.push(value as u8);
+    }
+
+    fn varint_bytes(buff: &mut Vec<u8>, bytes: Option<&[u8]>) {
+        match bytes {
+            None => varlong(buff, -1),
+            Some(bytes) => {
+                varlong(buff, bytes.len() as i64);
+                buff.extend_from_slice(bytes);
+            }
+        }
+    }
+
+    pub(crate) struct TestRecord<'a> {
+        pub timestamp_delta: i64,
+        pub key: Option<&'a [u8]>,
+        pub value: Option<&'a [u8]>,
+        pub headers: &'a [(&'a str, Option<&'a [u8]>)],
+    }
+
+    fn record(offset_delta: i32, record: &TestRecord) -> Vec<u8> {
+        let mut body = vec![0];
+
+        varlong(&mut body, record.timestamp_delta);
+        varlong(&mut body, offset_delta as i64);
+        varint_bytes(&mut body, record.key);
+        varint_bytes(&mut body, record.value);
+        varlong(&mut body, record.headers.len() as i64);
+
+        for (key, value) in record.headers {
+            varint_bytes(&mut body, Some(key.as_bytes()));
+            varint_bytes(&mut body, *value);
+        }
+
+        let mut buff = Vec::new();
+        varlong(&mut buff, body.len() as i64);
+        buff.extend(body);
+        buff
+    }
+
+    fn batch(
+        base_offset: i64,
+        attributes: i16,
+        base_sequence: i32,
+        records: &[TestRecord],
+    ) -> Vec<u8> {
+        producer_batch(base_offset, attributes, 42, base_sequence, records)
+    }
+
+    /// Encodes a v2 record batch with a valid crc.
+    pub(crate) fn producer_batch(
+        base_offset: i64,
+        attributes: i16,
+        producer_id: i64,
+        base_sequence: i32,
+        records: &[TestRecord],
+    ) -> Vec<u8> {
+        let mut encoded = records
+            .iter()
+            .enumerate()
+            .flat_map(|(delta, r)| record(delta as i32, r))
+            .collect::<Vec<_>>();
+
+        if attributes & 0x07 == 1 {
+            let mut encoder = GzEncoder::new(Vec::new(), GzLevel::default());
+            encoder.write_all(&encoded).unwrap();
+            encoded = encoder.finish().unwrap();
+        }
+
+        let mut crc_data = Vec::new();
+        crc_data.extend(attributes.to_be_bytes());
+        crc_data.extend((records.len() as i32 - 1).to_be_bytes());
+        crc_data.extend(1_000i64.to_be_bytes());
+        crc_data.extend(2_000i64.to_be_bytes());
+        crc_data.extend(producer_id.to_be_bytes());
+        crc_data.extend(3i16.to_be_bytes());
+        crc_data.extend(base_sequence.to_be_bytes());
+        crc_data.extend((records.len() as i32).to_be_bytes());
+        crc_data.extend(encoded);
+
+        let mut buff = Vec::new();
+        buff.extend(base_offset.to_be_bytes());
+        buff.extend((crc_data.len() as i32 + 9).to_be_bytes());
+        buff.extend(7i32.to_be_bytes());
+        buff.push(2);
+        buff.extend(crc32c::crc32c(&crc_data).to_be_bytes());
+        buff.extend(crc_data);
+        buff
+    }
+
+    const RECORDS: &[TestRecord] = &[
+        TestRecord {
+            timestamp_delta: 0,
+            key: Some(b"k"),
+            value: Some(b"first"),
+            headers: &[("trace", Some(b"abc")), ("empty", None)],
+        },
+        TestRecord {
+            timestamp_delta: 5,
+            key: None,
+            value: None,
+            headers: &[],
+        },
+    ];
+
+    #[test]
+    fn batch_header_and_records_are_decoded() {
+        let segment = batch(100, 0, 10, RECORDS);
+        let batch = SegmentReader::new(&segment).batch_at(0).unwrap();
+
+        assert!(batch.crc_valid);
+        assert_eq!(batch.header.base_offset, 100);
+        assert_eq!(batch.header.last_offset(), 101);
+        assert_eq!(batch.header.last_sequence(), 11);
+        assert_eq!(batch.header.partition_leader_epoch, 7);
+        assert_eq!(batch.header.producer_id, 42);
+        assert_eq!(batch.header.size(), segment.len());
+        assert_eq!(batch.header.timestamp_type(), TimestampType::CreateTime);
+
+        let records = batch.records().unwrap();
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].offset, 100);
+        assert_eq!(records[0].timestamp, 1_000);
+        assert_eq!(records[0].sequence, 10);
+        assert_eq!(records[0].key.as_deref(), Some(&b"k"[..]));
+        assert_eq!(records[0].value.as_deref(), Some(&b"first"[..]));
+        assert_eq!(records[0].headers.len(), 2);
+        assert_eq!(records[0].headers[0].key, "trace");
+        assert_eq!(records[0].headers[0].value.as_deref(), Some(&b"abc"[..]));
+        assert_eq!(records[0].headers[1].value, None);
+
+        assert_eq!(records[1].offset, 101);
+        assert_eq!(records[1].timestamp, 1_005);
+        assert_eq!(records[1].sequence, 11);
+        assert_eq!(records[1].key, None);
+        assert_eq!(records[1].value, None);
+    }
+
+    #[test]
+    fn log_append_time_uses_max_timestamp() {
+        let segment = batch(0, 0x08, -1, RECORDS);
+        let batch = SegmentReader::new(&segment).batch_at(0).unwrap();
+        let records = batch.records().unwrap();
+
+        assert_eq!(batch.header.last_sequence(), -1);
+        assert!(records
+            .iter()
+            .all(|r| r.timestamp == 2_000 && r.sequence == -1));
+    }
+
+    #[test]
+    fn gzip_records_are_decompressed() {
+        let segment = batch(0, 1, 0, RECORDS);
+        let batch = SegmentReader::new(&segment).batch_at(0).unwrap();
+        let records = batch.records().unwrap();
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].value.as_deref(), Some(&b"first"[..]));
+    }
+
+    #[test]
+    fn unknown_compression_only_fails_when_decompressing() {
+        let segment = batch(0, 7, 0, RECORDS);
+        let batch = SegmentReader::new(&segment).batch_at(0).unwrap();
+
+        assert!(batch.crc_valid);
+        assert!(matches!(
+            batch.header.compression().unwrap_err().current_context(),
+            SegmentError::UnknownCompression(7)
+        ));
+        assert!(matches!(
+            batch.records().unwrap_err().current_context(),
+            SegmentError::UnknownCompression(7)
+        ));
+    }
+
+    #[test]
+    fn crc_mismatch_is_flagged() {
+        let mut segment = batch(0, 0, 0, RECORDS);
+        let last = segment.len() - 1;
+        segment[last] ^= 0xff;
+
+        assert!(!SegmentReader::new(&segment).batch_at(0).unwrap().crc_valid);
+    }
+
+    #[test]
+    fn reader_walks_batches_and_stops_after_errors() {
+        let mut segment = batch(0, 0, 0, RECORDS);
+        let second = segment.len();
+        segment.extend(batch(2, 0, 2, RECORDS));
+        segment.extend(&batch(4, 0, 4, RECORDS)[..20]);
+
+        let batches = SegmentReader::new(&segment).collect::<Vec<_>>();
+
+        assert_eq!(batches.len(), 3);
+        assert_eq!(batches[0].as_ref().unwrap().header.base_offset, 0);
+        assert_eq!(batches[1].as_ref().unwrap().position, second);
+        assert!(matches!(
+            batches[2].as_ref().unwrap_err().current_context(),
+            SegmentError::Truncated
+        ));
+    }
+
+    #[test]
+    fn unsupported_magic_is_rejected() {
+        let mut segment = batch(0, 0, 0, RECORDS);
+        segment[16] = 1;
+
+        let report = SegmentReader::new(&segment).batch_at(0).unwrap_err();
+
+        assert!(matches!(
+            report.current_context(),
+            SegmentError::UnsupportedMagic(1)
+        ));
+    }
+
+    #[test]
+    fn control_record_keys_are_decoded() {
+        assert_eq!(
+            ControlRecordType::from_key(&[0, 0, 0, 0]).unwrap(),
+            ControlRecordType::Abort
+        );
+        assert_eq!(
+            ControlRecordType::from_key(&[0, 0, 0, 1]).unwrap(),
+            ControlRecordType::Commit
+        );
+        assert_eq!(
+            ControlRecordType::from_key(&[0, 0, 0, 9]).unwrap(),
+            ControlRecordType::Unknown(9)
+        );
+        assert!(ControlRecordType::from_key(&[0, 0]).is_err());
+    }
+}
@@ -20,6 +20,14 @@ pub enum Serde {
 }
 
 impl Serde {
+    /// Whether records can be converted to and from strings with this serde.
+    pub fn is_supported(&self) -> bool {
+        matches!(
+            self,
+            Serde::String | Serde::Bytes | Serde::Int | Serde::Json
+        )
+    }
+
     pub fn deserialise_into_string(
         &self,
         bytes: Vec<u8>,
@@ -38,7 +46,29 @@ impl Serde {
                 &serde_json::from_slice::<Value>(&bytes).change_context(SerdeError::Deserialise)?,
             )
             .change_context(SerdeError::Deserialise),
-            _ => todo!("Not yet implemented"),
+            _ => Err(Report::new(SerdeError::Deserialise))
+                .attach_printable(format!("Unsupported serde: {:?}", self)),
+        }
+    }
+
+    pub fn serialise_from_string(&self, value: &str) -> error_stack::Result<Vec<u8>, SerdeError> {
+        match self {
+            Serde::String => Ok(value.as_bytes().to_vec()),
+            Serde::Bytes => serde_json::from_str::<Vec<u8>>(value)
+                .change_context(SerdeError::Serialise)
+                .attach_printable("Expected bytes formatted as '[1, 2, 3]'"),
+            Serde::Int => Ok(value
+                .trim()
+                .parse::<i32>()
+                .change_context(SerdeError::Serialise)?
+                .to_be_bytes()
+                .to_vec()),
+            Serde::Json => serde_json::to_vec(
+                &serde_json::from_str::<Value>(value).change_context(SerdeError::Serialise)?,
+            )
+            .change_context(SerdeError::Serialise),
+            serde => Err(Report::new(SerdeError::Serialise))
+                .attach_printable(format!("unsupported serde: {}", serde)),
         }
     }
 }
@@ -0,0 +1,277 @@
+use std::{collections::BTreeMap, fmt::Display, fs, path::Path, time::Duration};
+
+use error_stack::{Report, ResultExt};
+use rdkafka::{client::Client, ClientContext};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+use crate::{
+    error::io::SpecError,
+    io::{
+        admin::{
+            acl::{AclOperation, AclPermission, TopicAcl},
+            describe_configs, ConfigResource, ConfigSource,
+        },
+        input::Input,
+    },
+};
+
+/// Declared state of a topic. Configs only hold values overriding the
+/// broker defaults.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct TopicSpec {
+    pub partitions: i32,
+    pub replication_factor: i32,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_configs",
+        skip_serializing_if = "BTreeMap::is_empty"
+    )]
+    pub configs: BTreeMap<String, String>,
+    /// Literal ACLs on the topic, left unmanaged when omitted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub acls: Option<Vec<AclSpec>>,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct AclSpec {
+    pub principal: String,
+    #[serde(default = "any_host")]
+    pub host: String,
+    pub operation: AclOperation,
+    #[serde(default)]
+    pub permission: AclPermission,
+}
+
+impl AclSpec {
+    pub fn topic_acl(&self, topic: &str) -> TopicAcl {
+        TopicAcl {
+            topic: topic.to_owned(),
+            principal: self.principal.clone(),
+            host: self.host.clone(),
+            operation: self.operation,
+            permission: self.permission,
+        }
+    }
+}
+
+fn any_host() -> String {
+    "*".to_owned()
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ClusterSpec {
+    #[serde(default)]
+    pub topics: BTreeMap<String, TopicSpec>,
+}
+
+/// Spec files are hand written, so numbers and booleans are accepted as
+/// config values alongside strings.
+pub fn deserialize_configs<'de, D>(deserializer: D) -> Result<BTreeMap<String, String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(BTreeMap::<String, Value>::deserialize(deserializer)?
+        .into_iter()
+        .map(|(name, value)| match value {
+            Value::String(value) => (name, value),
+            value => (name, value.to_string()),
+        })
+        .collect())
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SpecField {
+    Partitions,
+    ReplicationFactor,
+    Config(String),
+}
+
+impl Display for SpecField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Partitions => f.write_str("partitions"),
+            Self::ReplicationFactor => f.write_str("replication_factor"),
+            Self::Config(name) => write!(f, "configs.{}", name),
+        }
+    }
+}
+
+/// A field differing between two specs, `None` meaning a config is left at
+/// its default.
+#[derive(Clone, Debug, Serialize)]
+pub struct FieldChange {
+    pub field: SpecField,
+    pub from: Option<String>,
+    pub to: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TopicChange {
+    Added(TopicSpec),
+    Removed(TopicSpec),
+    Changed(Vec<FieldChange>),
+}
+
+impl ClusterSpec {
+    pub fn read(path: &Path) -> error_stack::Result<Self, SpecError> {
+        let file = path.display().to_string();
+
+        let input = Input::from_path(path)
+            .ok_or(Report::new(SpecError::UnknownFormat(file.clone())))
+            .attach_printable("Spec files need a .json, .toml, .yaml or .yml extension.")?;
+
+        let contents =
+            fs::read_to_string(path).change_context(SpecError::ReadFile(file.clone()))?;
+
+        input
+            .read_from_str(&contents)
+            .change_context(SpecError::Parse(file))
+    }
+
+    /// Describes the topics of a cluster accepted by `is_match`.
+    pub fn from_cluster<C>(
+        client: &Client<C>,
+        is_match: impl Fn(&str) -> bool,
+        timeout: Duration,
+    ) -> error_stack::Result<Self, SpecError>
+    where
+        C: ClientContext,
+    {
+        let metadata = client
+            .fetch_metadata(None, timeout)
+            .change_context(SpecError::Metadata)?;
+
+        let mut topics = metadata
+            .topics()
+            .iter()
+            .filter(|t| t.error().is_none() && is_match(t.name()))
+            .map(|t| {
+                (
+                    t.name().to_owned(),
+                    TopicSpec {
+                        partitions: t.partitions().len() as i32,
+                        replication_factor: t
+                            .partitions()
+                            .first()
+                            .map(|p| p.replicas().len() as i32)
+                            .unwrap_or_default(),
+                        configs: BTreeMap::new(),
+                        acls: None,
+                    },
+                )
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        if topics.is_empty() {
+            return Ok(Self { topics });
+        }
+
+        let resources = topics
+            .keys()
+            .map(|t| ConfigResource::Topic(t.clone()))
+            .collect::<Vec<_>>();
+
+        for description in describe_configs(client, &resources, timeout)
+            .change_context(SpecError::DescribeConfigs)?
+        {
+            let ConfigResource::Topic(topic) = description.resource else {
+                continue;
+            };
+
+            if let Some(error) = description.error {
+                Err(Report::new(SpecError::Rejected(topic.clone(), error)))?
+            }
+
+            if let Some(spec) = topics.get_mut(&topic) {
+                spec.configs = description
+                    .entries
+                    .into_iter()
+                    .filter(|e| e.source == ConfigSource::DynamicTopic)
+                    .filter_map(|e| e.value.map(|v| (e.name, v)))
+                    .collect();
+            }
+        }
+
+        Ok(Self { topics })
+    }
+
+    pub fn retain(&mut self, is_match: impl Fn(&str) -> bool) {
+        self.topics.retain(|topic, _| is_match(topic));
+    }
+
+    /// Changes needed to get from this spec to `to`, keyed by topic.
+    pub fn diff(&self, to: &ClusterSpec) -> BTreeMap<String, TopicChange> {
+        let mut changes = BTreeMap::new();
+
+        for (topic, from) in &self.topics {
+            match to.topics.get(topic) {
+                None => {
+                    changes.insert(topic.clone(), TopicChange::Removed(from.clone()));
+                }
+                Some(to) => {
+                    let fields = from.diff(to);
+
+                    if !fields.is_empty() {
+                        changes.insert(topic.clone(), TopicChange::Changed(fields));
+                    }
+                }
+            }
+        }
+
+        for (topic, to) in &to.topics {
+            if !self.topics.contains_key(topic) {
+                changes.insert(topic.clone(), TopicChange::Added(to.clone()));
+            }
+        }
+
+        changes
+    }
+}
+
+impl TopicSpec {
+    /// ACLs are left out, clusters are read without them.
+    fn diff(&self, to: &TopicSpec) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+
+        if self.partitions != to.partitions {
+            changes.push(FieldChange {
+                field: SpecField::Partitions,
+                from: Some(self.partitions.to_string()),
+                to: Some(to.partitions.to_string()),
+            });
+        }
+
+        if self.replication_factor != to.replication_factor {
+            changes.push(FieldChange {
+                field: SpecField::ReplicationFactor,
+                from: Some(self.replication_factor.to_string()),
+                to: Some(to.replication_factor.to_string()),
+            });
+        }
+
+        let mut names = self
+            .configs
+            .keys()
+            .chain(to.configs.keys())
+            .collect::<Vec<_>>();
+        names.sort();
+        names.dedup();
+
+        for name in names {
+            let (from, to) = (self.configs.get(name), to.configs.get(name));
+
+            if from != to {
+                changes.push(FieldChange {
+                    field: SpecField::Config(name.clone()),
+                    from: from.cloned(),
+                    to: to.cloned(),
+                });
+            }
+        }
+
+        changes
+    }
+}